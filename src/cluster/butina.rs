@@ -0,0 +1,152 @@
+use super::Cluster;
+use crate::fingerprint::{tanimoto, Features};
+
+/// Returns the Taylor-Butina clusters of fingerprints, largest first.
+/// Neighbors are pairs with a Tanimoto similarity of at least threshold.
+/// The unassigned fingerprint with the most neighbors, lowest index on
+/// ties, becomes a centroid and takes its unassigned neighbors as members,
+/// until every fingerprint is assigned. Neighbor counts aren't updated as
+/// fingerprints are assigned.
+///
+/// Memory is linear in the number of fingerprints, at the cost of
+/// comparing each centroid to every unassigned fingerprint a second time.
+pub fn butina<T: Features>(fingerprints: &[T], threshold: f64) -> Vec<Cluster> {
+    let neighbors = |index: usize| {
+        let first = &fingerprints[index];
+
+        move |other: &usize| {
+            *other != index
+                && tanimoto(first, &fingerprints[*other]) >= threshold
+        }
+    };
+    let mut counts = vec![0; fingerprints.len()];
+
+    for index in 0..fingerprints.len() {
+        let is_neighbor = neighbors(index);
+
+        for other in index + 1..fingerprints.len() {
+            if is_neighbor(&other) {
+                counts[index] += 1;
+                counts[other] += 1;
+            }
+        }
+    }
+
+    let mut order = (0..fingerprints.len()).collect::<Vec<_>>();
+    let mut assigned = vec![false; fingerprints.len()];
+    let mut result = Vec::new();
+
+    order.sort_by_key(|index| std::cmp::Reverse(counts[*index]));
+
+    for centroid in order {
+        if assigned[centroid] {
+            continue;
+        }
+
+        let mut members = (0..fingerprints.len())
+            .filter(|index| !assigned[*index])
+            .filter(neighbors(centroid))
+            .collect::<Vec<_>>();
+
+        members.push(centroid);
+        members.sort();
+
+        for member in &members {
+            assigned[*member] = true;
+        }
+
+        result.push(Cluster { centroid, members })
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::BitVec;
+    use pretty_assertions::assert_eq;
+
+    fn bits(ones: &[usize]) -> BitVec {
+        let mut result = BitVec::new(16);
+
+        for one in ones {
+            result.set(*one, true)
+        }
+
+        result
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(butina::<BitVec>(&[], 0.5), vec![])
+    }
+
+    #[test]
+    fn singletons() {
+        let fingerprints = [bits(&[0]), bits(&[1])];
+
+        assert_eq!(
+            butina(&fingerprints, 0.5),
+            vec![
+                Cluster {
+                    centroid: 0,
+                    members: vec![0]
+                },
+                Cluster {
+                    centroid: 1,
+                    members: vec![1]
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn largest_first() {
+        let fingerprints = [
+            bits(&[8, 9]),
+            bits(&[0, 1, 2]),
+            bits(&[0, 1, 2, 3]),
+            bits(&[0, 1, 2, 4]),
+            bits(&[8, 9, 10]),
+        ];
+
+        assert_eq!(
+            butina(&fingerprints, 0.6),
+            vec![
+                Cluster {
+                    centroid: 1,
+                    members: vec![1, 2, 3]
+                },
+                Cluster {
+                    centroid: 0,
+                    members: vec![0, 4]
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn members_leave_later_centroids() {
+        let fingerprints = [
+            bits(&[0, 1]),
+            bits(&[0, 1, 2]),
+            bits(&[0, 1, 2, 3]),
+            bits(&[0, 1, 2, 3, 4]),
+        ];
+
+        assert_eq!(
+            butina(&fingerprints, 0.65),
+            vec![
+                Cluster {
+                    centroid: 1,
+                    members: vec![0, 1, 2]
+                },
+                Cluster {
+                    centroid: 3,
+                    members: vec![3]
+                }
+            ]
+        )
+    }
+}
@@ -0,0 +1,7 @@
+/// A group of fingerprints found by butina, given as indexes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Cluster {
+    pub centroid: usize,
+    /// All members including the centroid, ascending.
+    pub members: Vec<usize>,
+}
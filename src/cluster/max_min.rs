@@ -0,0 +1,175 @@
+use super::MaxMinOptions;
+use crate::fingerprint::{tanimoto, Features};
+
+/// Returns the indexes of up to options.count diverse fingerprints in the
+/// order picked. The first pick is drawn from seed. Each further pick is
+/// the fingerprint farthest, by Tanimoto distance, from its nearest pick,
+/// lowest index on ties.
+///
+/// Memory is linear in the number of fingerprints, keeping only each
+/// candidate's distance to its nearest pick.
+pub fn max_min<T: Features>(
+    fingerprints: &[T],
+    options: &MaxMinOptions,
+) -> Vec<usize> {
+    let mut candidates = (0..fingerprints.len())
+        .filter(|index| !options.exclude.contains(index))
+        .collect::<Vec<_>>();
+    let mut result = Vec::new();
+
+    if candidates.is_empty() || options.count == 0 {
+        return result;
+    }
+
+    let first = splitmix(options.seed) % candidates.len() as u64;
+    let mut pick = candidates.swap_remove(first as usize);
+    let mut distances = vec![f64::INFINITY; candidates.len()];
+
+    result.push(pick);
+
+    while result.len() < options.count && !candidates.is_empty() {
+        for (candidate, distance) in candidates.iter().zip(&mut distances) {
+            let similarity =
+                tanimoto(&fingerprints[pick], &fingerprints[*candidate]);
+
+            *distance = distance.min(1. - similarity)
+        }
+
+        let best = (0..candidates.len())
+            .max_by(|first, second| {
+                distances[*first]
+                    .total_cmp(&distances[*second])
+                    .then(candidates[*second].cmp(&candidates[*first]))
+            })
+            .expect("candidate");
+
+        pick = candidates.swap_remove(best);
+        distances.swap_remove(best);
+        result.push(pick)
+    }
+
+    result
+}
+
+/// The SplitMix64 generator, so that picks don't change between releases.
+fn splitmix(seed: u64) -> u64 {
+    let mut result = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+    result = (result ^ (result >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    result = (result ^ (result >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+    result ^ (result >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::BitVec;
+    use pretty_assertions::assert_eq;
+    use std::collections::BTreeSet;
+
+    fn bits(ones: &[usize]) -> BitVec {
+        let mut result = BitVec::new(16);
+
+        for one in ones {
+            result.set(*one, true)
+        }
+
+        result
+    }
+
+    fn fingerprints() -> Vec<BitVec> {
+        vec![
+            bits(&[0, 1, 2]),
+            bits(&[0, 1, 3]),
+            bits(&[8, 9, 10]),
+            bits(&[0, 1, 2, 3]),
+            bits(&[8, 9, 11]),
+            bits(&[4, 5]),
+        ]
+    }
+
+    fn options(count: usize, seed: u64, exclude: &[usize]) -> MaxMinOptions {
+        MaxMinOptions {
+            count,
+            seed,
+            exclude: exclude.iter().copied().collect(),
+        }
+    }
+
+    #[test]
+    fn splitmix_reference() {
+        assert_eq!(splitmix(0), 0xe220_a839_7b1d_cdaf)
+    }
+
+    #[test]
+    fn count_zero() {
+        assert_eq!(max_min(&fingerprints(), &options(0, 0, &[])), vec![])
+    }
+
+    #[test]
+    fn all_excluded() {
+        let exclude = (0..6).collect::<Vec<_>>();
+
+        assert_eq!(max_min(&fingerprints(), &options(3, 0, &exclude)), vec![])
+    }
+
+    #[test]
+    fn same_seed() {
+        let fingerprints = fingerprints();
+
+        assert_eq!(
+            max_min(&fingerprints, &options(4, 42, &[])),
+            max_min(&fingerprints, &options(4, 42, &[]))
+        )
+    }
+
+    #[test]
+    fn seed_chooses_first() {
+        let firsts = (0..20)
+            .map(|seed| max_min(&fingerprints(), &options(1, seed, &[]))[0])
+            .collect::<BTreeSet<_>>();
+
+        assert!(firsts.len() > 1)
+    }
+
+    #[test]
+    fn diverse() {
+        let fingerprints = fingerprints();
+
+        for seed in 0..20 {
+            let picks = max_min(&fingerprints, &options(3, seed, &[]))
+                .into_iter()
+                .map(|pick| match pick {
+                    0 | 1 | 3 => 'a',
+                    2 | 4 => 'b',
+                    _ => 'c',
+                })
+                .collect::<BTreeSet<_>>();
+
+            assert_eq!(picks, BTreeSet::from(['a', 'b', 'c']))
+        }
+    }
+
+    #[test]
+    fn lowest_index_on_ties() {
+        let fingerprints = [bits(&[0]), bits(&[1]), bits(&[2]), bits(&[3])];
+
+        for seed in 0..20 {
+            let picks = max_min(&fingerprints, &options(2, seed, &[]));
+            let lowest = (0..4).find(|index| *index != picks[0]).unwrap();
+
+            assert_eq!(picks[1], lowest)
+        }
+    }
+
+    #[test]
+    fn exclude() {
+        let picks = max_min(&fingerprints(), &options(6, 3, &[2, 5]));
+        let mut sorted = picks.clone();
+
+        sorted.sort();
+
+        assert_eq!(sorted, vec![0, 1, 3, 4])
+    }
+}
@@ -0,0 +1,12 @@
+use std::collections::BTreeSet;
+
+/// Controls max_min.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct MaxMinOptions {
+    /// The most fingerprints to pick.
+    pub count: usize,
+    /// Chooses the first pick. The same seed gives the same picks.
+    pub seed: u64,
+    /// Indexes never picked.
+    pub exclude: BTreeSet<usize>,
+}
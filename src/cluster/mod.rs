@@ -0,0 +1,10 @@
+mod butina;
+#[allow(clippy::module_inception)]
+mod cluster;
+mod max_min;
+mod max_min_options;
+
+pub use butina::butina;
+pub use cluster::Cluster;
+pub use max_min::max_min;
+pub use max_min_options::MaxMinOptions;
@@ -0,0 +1,352 @@
+use super::Descriptors;
+use crate::{
+    feature::Element,
+    graph::{components, ring_bonds, Atom, Hybridization},
+    mass::average_mass,
+    search::BondClass,
+    validate::kekule_partners,
+};
+
+/// Returns the descriptors of atoms.
+///
+/// - A rotatable bond is a single bond off any ring joining two atoms with
+///   other heavy neighbors, neither on a triple bond.
+/// - Unsaturation is the number of rings plus pi bonds, counting two for a
+///   triple bond and one for each double bond of a Kekulé structure of
+///   aromatic atoms. Charges and counterions don't change it.
+/// - Lipinski allows one violation of weight at most 500 (failing when
+///   unknown), at most 5 donors, and at most 10 acceptors. The logP limit
+///   is not applied.
+/// - Veber requires at most 10 rotatable bonds and at most 12 donors and
+///   acceptors together.
+pub fn describe(atoms: &[Atom]) -> Descriptors {
+    let ring = ring_bonds(atoms);
+    let (bonds, fragments) = components(atoms, |_, _| true);
+    let rings = bonds + fragments - atoms.len();
+    let (aromatic_bonds, aromatic_fragments) =
+        components(atoms, |sid, bond| {
+            BondClass::new(atoms, sid, bond) == BondClass::Aromatic
+        });
+    let elements = atoms
+        .iter()
+        .map(|atom| atom.kind.element())
+        .collect::<Vec<_>>();
+    let is_heavy = |index: usize| elements[index] != Some(Element::H);
+    let carbons = (0..atoms.len())
+        .filter(|index| elements[*index] == Some(Element::C))
+        .collect::<Vec<_>>();
+    let sp3 = carbons
+        .iter()
+        .filter(|index| {
+            atoms[**index].hybridization() == Some(Hybridization::Sp3)
+        })
+        .count();
+    let polar = (0..atoms.len())
+        .filter(|index| {
+            matches!(elements[*index], Some(Element::N) | Some(Element::O))
+        })
+        .collect::<Vec<_>>();
+    let donors = polar
+        .iter()
+        .filter(|index| {
+            let atom = &atoms[**index];
+
+            atom.hydrogens() > 0
+                || atom.bonds.iter().any(|bond| !is_heavy(bond.tid))
+        })
+        .count();
+    let acceptors = polar.len();
+    let rotatable_bonds = atoms
+        .iter()
+        .enumerate()
+        .flat_map(|(sid, atom)| {
+            atom.bonds
+                .iter()
+                .enumerate()
+                .map(move |(position, bond)| (sid, position, bond))
+        })
+        .filter(|(sid, position, bond)| {
+            let ends = [*sid, bond.tid];
+
+            *sid < bond.tid
+                && !ring[*sid][*position]
+                && BondClass::new(atoms, *sid, bond) == BondClass::Single
+                && ends.iter().all(|end| {
+                    is_heavy(*end)
+                        && heavy_neighbors(atoms, *end, &is_heavy) > 1
+                        && !has_triple_bond(atoms, *end)
+                })
+        })
+        .count();
+    let molecular_weight = average_mass(atoms).ok();
+    let violations = [
+        molecular_weight.is_none_or(|weight| weight > 500.),
+        donors > 5,
+        acceptors > 10,
+    ]
+    .iter()
+    .filter(|violation| **violation)
+    .count();
+
+    Descriptors {
+        heavy_atoms: (0..atoms.len()).filter(|index| is_heavy(*index)).count(),
+        heteroatoms: elements
+            .iter()
+            .flatten()
+            .filter(|element| !matches!(element, Element::C | Element::H))
+            .count(),
+        rings,
+        aromatic_rings: aromatic_bonds + aromatic_fragments - atoms.len(),
+        rotatable_bonds,
+        hydrogen_bond_donors: donors,
+        hydrogen_bond_acceptors: acceptors,
+        formal_charge: atoms.iter().map(|atom| atom.charge() as i32).sum(),
+        fraction_sp3: if carbons.is_empty() {
+            0.
+        } else {
+            sp3 as f64 / carbons.len() as f64
+        },
+        unsaturation: unsaturation(atoms, rings),
+        molecular_weight,
+        lipinski: violations <= 1,
+        veber: rotatable_bonds <= 10 && donors + acceptors <= 12,
+    }
+}
+
+fn heavy_neighbors<F>(atoms: &[Atom], index: usize, is_heavy: &F) -> usize
+where
+    F: Fn(usize) -> bool,
+{
+    atoms[index]
+        .bonds
+        .iter()
+        .filter(|bond| is_heavy(bond.tid))
+        .count()
+}
+
+fn has_triple_bond(atoms: &[Atom], index: usize) -> bool {
+    atoms[index]
+        .bonds
+        .iter()
+        .any(|bond| BondClass::new(atoms, index, bond) == BondClass::Triple)
+}
+
+fn unsaturation(atoms: &[Atom], rings: usize) -> f64 {
+    let partners = kekule_partners(atoms);
+    let pi_bonds = atoms
+        .iter()
+        .enumerate()
+        .flat_map(|(sid, atom)| atom.bonds.iter().map(move |bond| (sid, bond)))
+        .filter(|(sid, bond)| *sid < bond.tid)
+        .map(|(sid, bond)| match BondClass::new(atoms, sid, bond) {
+            BondClass::Single => 0,
+            BondClass::Double => 1,
+            BondClass::Triple => 2,
+            BondClass::Aromatic => usize::from(partners[sid] == Some(bond.tid)),
+        })
+        .sum::<usize>();
+
+    (rings + pi_bonds) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn describe_of(string: &str) -> Descriptors {
+        describe(&atoms_of(string))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            describe_of(""),
+            Descriptors {
+                heavy_atoms: 0,
+                heteroatoms: 0,
+                rings: 0,
+                aromatic_rings: 0,
+                rotatable_bonds: 0,
+                hydrogen_bond_donors: 0,
+                hydrogen_bond_acceptors: 0,
+                formal_charge: 0,
+                fraction_sp3: 0.,
+                unsaturation: 0.,
+                molecular_weight: Some(0.),
+                lipinski: true,
+                veber: true,
+            }
+        )
+    }
+
+    #[test]
+    fn ethanol() {
+        let descriptors = describe_of("CCO");
+
+        assert_eq!(
+            Descriptors {
+                molecular_weight: None,
+                ..descriptors
+            },
+            Descriptors {
+                heavy_atoms: 3,
+                heteroatoms: 1,
+                rings: 0,
+                aromatic_rings: 0,
+                rotatable_bonds: 0,
+                hydrogen_bond_donors: 1,
+                hydrogen_bond_acceptors: 1,
+                formal_charge: 0,
+                fraction_sp3: 1.,
+                unsaturation: 0.,
+                molecular_weight: None,
+                lipinski: true,
+                veber: true,
+            }
+        )
+    }
+
+    #[test]
+    fn benzoic_acid() {
+        let descriptors = describe_of("c1ccccc1C(=O)O");
+
+        assert_eq!(
+            (
+                descriptors.heavy_atoms,
+                descriptors.heteroatoms,
+                descriptors.rings,
+                descriptors.aromatic_rings,
+                descriptors.rotatable_bonds,
+                descriptors.hydrogen_bond_donors,
+                descriptors.hydrogen_bond_acceptors,
+                descriptors.fraction_sp3,
+                descriptors.unsaturation,
+            ),
+            (9, 2, 1, 1, 1, 1, 2, 0., 5.)
+        )
+    }
+
+    #[test]
+    fn molecular_weight() {
+        let weight = describe_of("CCO").molecular_weight.unwrap();
+
+        assert_eq!(format!("{:.3}", weight), "46.069")
+    }
+
+    #[test]
+    fn naphthalene_rings() {
+        let descriptors = describe_of("c1ccc2ccccc2c1");
+
+        assert_eq!((descriptors.rings, descriptors.aromatic_rings), (2, 2))
+    }
+
+    #[test]
+    fn rotatable_bonds() {
+        assert_eq!(describe_of("CCCC").rotatable_bonds, 1)
+    }
+
+    #[test]
+    fn rotatable_bonds_triple() {
+        assert_eq!(describe_of("CCC#CCC").rotatable_bonds, 0)
+    }
+
+    #[test]
+    fn rotatable_bonds_ring() {
+        assert_eq!(describe_of("C1CCCCC1CC").rotatable_bonds, 1)
+    }
+
+    #[test]
+    fn rotatable_bonds_explicit_hydrogen() {
+        assert_eq!(describe_of("[H]CC[H]").rotatable_bonds, 0)
+    }
+
+    #[test]
+    fn explicit_hydrogen() {
+        let descriptors = describe_of("[H]OC");
+
+        assert_eq!(
+            (descriptors.heavy_atoms, descriptors.hydrogen_bond_donors),
+            (2, 1)
+        )
+    }
+
+    #[test]
+    fn formal_charge() {
+        assert_eq!(describe_of("C[N+](C)(C)C.[Cl-].[Cl-]").formal_charge, -1)
+    }
+
+    #[test]
+    fn fraction_sp3() {
+        assert_eq!(describe_of("CC=C").fraction_sp3, 1. / 3.)
+    }
+
+    #[test]
+    fn salt_unsaturation() {
+        assert_eq!(describe_of("CC(=O)[O-].[NH4+]").unsaturation, 1.)
+    }
+
+    #[test]
+    fn sodium_salt_unsaturation() {
+        assert_eq!(
+            describe_of("CC(=O)[O-].[Na+]").unsaturation,
+            describe_of("CC(=O)O").unsaturation
+        )
+    }
+
+    #[test]
+    fn charged_unsaturation() {
+        assert_eq!(describe_of("C[N+](C)(C)C").unsaturation, 0.)
+    }
+
+    #[test]
+    fn aromatic_unsaturation() {
+        assert_eq!(describe_of("c1ccc2ccccc2c1").unsaturation, 7.)
+    }
+
+    #[test]
+    fn triple_bond_unsaturation() {
+        assert_eq!(describe_of("CC#N").unsaturation, 2.)
+    }
+
+    #[test]
+    fn star() {
+        let descriptors = describe_of("*CC");
+
+        assert_eq!(
+            (
+                descriptors.heavy_atoms,
+                descriptors.heteroatoms,
+                descriptors.molecular_weight,
+                descriptors.lipinski
+            ),
+            (3, 0, None, true)
+        )
+    }
+
+    #[test]
+    fn lipinski_failure() {
+        let descriptors = describe_of(&format!("OC{}CO", "C(O)".repeat(9)));
+
+        assert_eq!(
+            (
+                descriptors.hydrogen_bond_donors,
+                descriptors.hydrogen_bond_acceptors,
+                descriptors.lipinski,
+                descriptors.veber
+            ),
+            (11, 11, false, false)
+        )
+    }
+
+    #[test]
+    fn veber_rotatable_bonds() {
+        let descriptors = describe_of(&"C".repeat(14));
+
+        assert_eq!(
+            (descriptors.rotatable_bonds, descriptors.veber),
+            (11, false)
+        )
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+
+/// Descriptors computed by describe.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Descriptors {
+    /// Atoms other than hydrogen, counting Star atoms.
+    pub heavy_atoms: usize,
+    /// Atoms other than carbon and hydrogen, not counting Star atoms.
+    pub heteroatoms: usize,
+    /// Independent cycles, the size of a smallest set of smallest rings.
+    pub rings: usize,
+    /// Independent cycles of aromatic bonds.
+    pub aromatic_rings: usize,
+    pub rotatable_bonds: usize,
+    /// Nitrogen and oxygen atoms with at least one hydrogen.
+    pub hydrogen_bond_donors: usize,
+    /// Nitrogen and oxygen atoms.
+    pub hydrogen_bond_acceptors: usize,
+    pub formal_charge: i32,
+    /// Sp3 carbons over carbons, 0 without carbons.
+    pub fraction_sp3: f64,
+    /// Rings plus pi bonds.
+    pub unsaturation: f64,
+    /// Average mass in daltons, None if unknown.
+    pub molecular_weight: Option<f64>,
+    pub lipinski: bool,
+    pub veber: bool,
+}
+
+impl Descriptors {
+    /// Returns descriptors by field name, with true as 1 and false as 0.
+    /// An unknown molecular_weight is left out.
+    pub fn to_map(&self) -> BTreeMap<&'static str, f64> {
+        let mut result = BTreeMap::from([
+            ("heavy_atoms", self.heavy_atoms as f64),
+            ("heteroatoms", self.heteroatoms as f64),
+            ("rings", self.rings as f64),
+            ("aromatic_rings", self.aromatic_rings as f64),
+            ("rotatable_bonds", self.rotatable_bonds as f64),
+            ("hydrogen_bond_donors", self.hydrogen_bond_donors as f64),
+            (
+                "hydrogen_bond_acceptors",
+                self.hydrogen_bond_acceptors as f64,
+            ),
+            ("formal_charge", self.formal_charge as f64),
+            ("fraction_sp3", self.fraction_sp3),
+            ("unsaturation", self.unsaturation),
+            ("lipinski", self.lipinski as u8 as f64),
+            ("veber", self.veber as u8 as f64),
+        ]);
+
+        if let Some(weight) = self.molecular_weight {
+            result.insert("molecular_weight", weight);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn descriptors(molecular_weight: Option<f64>) -> Descriptors {
+        Descriptors {
+            heavy_atoms: 3,
+            heteroatoms: 1,
+            rings: 0,
+            aromatic_rings: 0,
+            rotatable_bonds: 0,
+            hydrogen_bond_donors: 1,
+            hydrogen_bond_acceptors: 1,
+            formal_charge: -1,
+            fraction_sp3: 1.,
+            unsaturation: 0.,
+            molecular_weight,
+            lipinski: true,
+            veber: false,
+        }
+    }
+
+    #[test]
+    fn to_map() {
+        let map = descriptors(Some(46.)).to_map();
+
+        assert_eq!(
+            (
+                map.len(),
+                map["formal_charge"],
+                map["lipinski"],
+                map["veber"],
+                map["molecular_weight"]
+            ),
+            (13, -1., 1., 0., 46.)
+        )
+    }
+
+    #[test]
+    fn to_map_unknown_weight() {
+        assert_eq!(descriptors(None).to_map().get("molecular_weight"), None)
+    }
+}
@@ -0,0 +1,5 @@
+mod describe;
+mod descriptors;
+
+pub use describe::describe;
+pub use descriptors::Descriptors;
@@ -1,21 +1,19 @@
 use std::{fmt, fmt::Write};
 
-use super::{AtomParity, Bracket, Element, Selection, Shortcut, Symbol};
+use super::{
+    AtomParity, Bracket, DefaultValenceModel, Element, Selection, Shortcut,
+    Symbol, ValenceError, ValenceModel,
+};
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Default)]
 pub enum AtomKind {
+    #[default]
     Star,
     Shortcut(Shortcut),
     Selection(Selection),
     Bracket(Bracket),
 }
 
-impl Default for AtomKind {
-    fn default() -> Self {
-        Self::Star
-    }
-}
-
 impl fmt::Display for AtomKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -46,26 +44,67 @@ impl AtomKind {
         }
     }
 
-    /// Returns subvalence, as defined in the working paper.
-    pub fn subvalence(&self, valence: u8) -> u8 {
-        let element: Element = match self {
-            Self::Star => return 0,
-            Self::Shortcut(shortcut) => shortcut.into(),
-            Self::Selection(selection) => selection.into(),
+    /// Returns the element, or None for Star and bracketed Star.
+    pub fn element(&self) -> Option<Element> {
+        match self {
+            Self::Star => None,
+            Self::Shortcut(shortcut) => Some(shortcut.into()),
+            Self::Selection(selection) => Some(selection.into()),
             Self::Bracket(bracket) => match &bracket.symbol {
-                Symbol::Star => return 0,
-                Symbol::Element(element) => element.clone(),
-                Symbol::Selection(selection) => selection.into(),
+                Symbol::Star => None,
+                Symbol::Element(element) => Some(element.clone()),
+                Symbol::Selection(selection) => Some(selection.into()),
             },
-        };
+        }
+    }
 
-        for default_valence in element.default_valences() {
-            if default_valence >= &valence {
-                return default_valence - valence;
+    /// Returns true for Selection variants and Bracket variants with a
+    /// selected symbol.
+    pub fn is_selected(&self) -> bool {
+        match self {
+            Self::Selection(_) => true,
+            Self::Bracket(bracket) => {
+                matches!(bracket.symbol, Symbol::Selection(_))
             }
+            _ => false,
         }
+    }
+
+    /// Returns subvalence, as defined in the working paper. Supervalent
+    /// atoms and atoms with impossible charges return 0.
+    pub fn subvalence(&self, valence: u8) -> u8 {
+        self.checked_subvalence(valence, &DefaultValenceModel)
+            .unwrap_or(0)
+    }
+
+    /// Returns subvalence, or an error if model rejects the atom. Shortcut
+    /// and Selection variants use the default valences of the working paper,
+    /// falling back to model only when valence exceeds all of them. Bracket
+    /// variants are evaluated by model with their charge.
+    pub fn checked_subvalence(
+        &self,
+        valence: u8,
+        model: &impl ValenceModel,
+    ) -> Result<u8, ValenceError> {
+        let element = match self.element() {
+            Some(element) => element,
+            None => return Ok(0),
+        };
+
+        match self {
+            Self::Bracket(bracket) => {
+                model.subvalence(&element, bracket.charge(), valence)
+            }
+            _ => {
+                for default_valence in element.default_valences() {
+                    if default_valence >= &valence {
+                        return Ok(default_valence - valence);
+                    }
+                }
 
-        0
+                model.subvalence(&element, 0, valence).map(|_| 0)
+            }
+        }
     }
 
     pub fn virtual_hydrogens(&self) -> u8 {
@@ -146,8 +185,69 @@ mod invert_configuration {
     }
 }
 
+#[cfg(test)]
+mod element {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn star() {
+        let kind = AtomKind::Star;
+
+        assert_eq!(kind.element(), None)
+    }
+
+    #[test]
+    fn shortcut() {
+        let kind = AtomKind::Shortcut(Shortcut::Cl);
+
+        assert_eq!(kind.element(), Some(Element::Cl))
+    }
+
+    #[test]
+    fn bracket_selection() {
+        let kind = AtomKind::Bracket(Bracket {
+            symbol: Symbol::Selection(Selection::N),
+            ..Default::default()
+        });
+
+        assert_eq!(kind.element(), Some(Element::N))
+    }
+}
+
+#[cfg(test)]
+mod is_selected {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn shortcut() {
+        let kind = AtomKind::Shortcut(Shortcut::C);
+
+        assert_eq!(kind.is_selected(), false)
+    }
+
+    #[test]
+    fn selection() {
+        let kind = AtomKind::Selection(Selection::C);
+
+        assert_eq!(kind.is_selected(), true)
+    }
+
+    #[test]
+    fn bracket_selection() {
+        let kind = AtomKind::Bracket(Bracket {
+            symbol: Symbol::Selection(Selection::N),
+            ..Default::default()
+        });
+
+        assert_eq!(kind.is_selected(), true)
+    }
+}
+
 #[cfg(test)]
 mod self_subvalence {
+    use crate::feature::Charge;
     use pretty_assertions::assert_eq;
 
     use super::*;
@@ -237,4 +337,93 @@ mod self_subvalence {
 
         assert_eq!(kind.subvalence(4), 1)
     }
+
+    #[test]
+    fn bracket_cation_homovalent() {
+        let kind = AtomKind::Bracket(Bracket {
+            symbol: Symbol::Element(Element::N),
+            charge: Some(Charge::Plus),
+            ..Default::default()
+        });
+
+        assert_eq!(kind.subvalence(4), 0)
+    }
+
+    #[test]
+    fn bracket_anion_subvalent() {
+        let kind = AtomKind::Bracket(Bracket {
+            symbol: Symbol::Element(Element::O),
+            charge: Some(Charge::Minus),
+            ..Default::default()
+        });
+
+        assert_eq!(kind.subvalence(0), 1)
+    }
+}
+
+#[cfg(test)]
+mod checked_subvalence {
+    use crate::feature::Charge;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn star() {
+        let kind = AtomKind::Star;
+
+        assert_eq!(kind.checked_subvalence(9, &DefaultValenceModel), Ok(0))
+    }
+
+    #[test]
+    fn shortcut_default_valence() {
+        let kind = AtomKind::Shortcut(Shortcut::N);
+
+        assert_eq!(kind.checked_subvalence(4, &DefaultValenceModel), Ok(1))
+    }
+
+    #[test]
+    fn shortcut_expanded_octet() {
+        let kind = AtomKind::Shortcut(Shortcut::Cl);
+
+        assert_eq!(kind.checked_subvalence(4, &DefaultValenceModel), Ok(0))
+    }
+
+    #[test]
+    fn shortcut_supervalent() {
+        let kind = AtomKind::Shortcut(Shortcut::C);
+
+        assert_eq!(
+            kind.checked_subvalence(5, &DefaultValenceModel),
+            Err(ValenceError::Supervalent)
+        )
+    }
+
+    #[test]
+    fn bracket_cation_supervalent() {
+        let kind = AtomKind::Bracket(Bracket {
+            symbol: Symbol::Element(Element::N),
+            charge: Some(Charge::Plus),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            kind.checked_subvalence(5, &DefaultValenceModel),
+            Err(ValenceError::Supervalent)
+        )
+    }
+
+    #[test]
+    fn bracket_impossible_charge() {
+        let kind = AtomKind::Bracket(Bracket {
+            symbol: Symbol::Element(Element::C),
+            charge: Some(Charge::Plus5),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            kind.checked_subvalence(0, &DefaultValenceModel),
+            Err(ValenceError::ImpossibleCharge)
+        )
+    }
 }
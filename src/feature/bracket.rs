@@ -18,6 +18,13 @@ impl Bracket {
             None => 0,
         }
     }
+
+    pub fn charge(&self) -> i8 {
+        match &self.charge {
+            Some(charge) => charge.into(),
+            None => 0,
+        }
+    }
 }
 
 impl fmt::Display for Bracket {
@@ -71,6 +78,31 @@ mod hydrogens {
     }
 }
 
+#[cfg(test)]
+mod charge {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn none() {
+        let bracket = Bracket {
+            ..Default::default()
+        };
+
+        assert_eq!(bracket.charge(), 0)
+    }
+
+    #[test]
+    fn some() {
+        let bracket = Bracket {
+            charge: Some(Charge::Minus),
+            ..Default::default()
+        };
+
+        assert_eq!(bracket.charge(), -1)
+    }
+}
+
 #[cfg(test)]
 mod to_string {
     use crate::feature::Element;
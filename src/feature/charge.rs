@@ -50,6 +50,31 @@ impl Charge {
     }
 }
 
+impl From<&Charge> for i8 {
+    fn from(value: &Charge) -> Self {
+        match value {
+            Charge::Minus9 => -9,
+            Charge::Minus8 => -8,
+            Charge::Minus7 => -7,
+            Charge::Minus6 => -6,
+            Charge::Minus5 => -5,
+            Charge::Minus4 => -4,
+            Charge::Minus3 => -3,
+            Charge::Minus2 => -2,
+            Charge::Minus1 | Charge::Minus => -1,
+            Charge::Plus | Charge::Plus1 => 1,
+            Charge::Plus2 => 2,
+            Charge::Plus3 => 3,
+            Charge::Plus4 => 4,
+            Charge::Plus5 => 5,
+            Charge::Plus6 => 6,
+            Charge::Plus7 => 7,
+            Charge::Plus8 => 8,
+            Charge::Plus9 => 9,
+        }
+    }
+}
+
 impl fmt::Display for Charge {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(match self {
@@ -76,3 +101,19 @@ impl fmt::Display for Charge {
         })
     }
 }
+
+#[cfg(test)]
+mod into_i8 {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn minus() {
+        assert_eq!(i8::from(&Charge::Minus), -1)
+    }
+
+    #[test]
+    fn plus3() {
+        assert_eq!(i8::from(&Charge::Plus3), 3)
+    }
+}
@@ -1,9 +1,12 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
 
-use super::{Selection, Shortcut};
+use super::{
+    periodic_table::{Entry, ELEMENTS, TABLE},
+    ElementError, Nuclide, Selection, Shortcut,
+};
 
 #[rustfmt::skip]
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub enum Element {
 //  0   1   2   3   4   5   6   7   8   9
         H,  He, Li, Be, B,  C,  N,  O,  F,  // 0
@@ -48,6 +51,91 @@ impl Element {
             _ => &EMPTY,
         }
     }
+
+    pub fn atomic_number(&self) -> u8 {
+        self.clone() as u8 + 1
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.entry().name
+    }
+
+    pub fn period(&self) -> u8 {
+        self.entry().period
+    }
+
+    /// Returns the IUPAC group, 1 through 18. Returns None for lanthanides
+    /// and actinides.
+    pub fn group(&self) -> Option<u8> {
+        self.entry().group
+    }
+
+    /// Returns the number of valence electrons of main-group elements.
+    pub fn valence_electrons(&self) -> Option<u8> {
+        match (self, self.group()?) {
+            (Self::He, _) => Some(2),
+            (_, group @ 1..=2) => Some(group),
+            (_, group @ 13..=18) => Some(group - 10),
+            _ => None,
+        }
+    }
+
+    /// Returns the standard atomic weight in daltons. Elements lacking one
+    /// return the mass of the isotope IUPAC lists in its place, such as
+    /// technetium-98.
+    pub fn atomic_weight(&self) -> f64 {
+        self.entry().atomic_weight
+    }
+
+    /// Returns the mass of the most abundant isotope, or of the isotope
+    /// used by atomic_weight for elements without natural abundance.
+    pub fn monoisotopic_mass(&self) -> f64 {
+        self.entry().monoisotopic_mass
+    }
+
+    /// Returns known isotopes in order of mass number. These include all
+    /// naturally occurring isotopes and common radioisotopes.
+    pub fn isotopes(&self) -> &'static [Nuclide] {
+        self.entry().isotopes
+    }
+
+    /// Returns the isotope with mass_number, if known.
+    pub fn isotope(&self, mass_number: u16) -> Option<&'static Nuclide> {
+        self.isotopes()
+            .iter()
+            .find(|nuclide| nuclide.mass_number == mass_number)
+    }
+
+    fn entry(&self) -> &'static Entry {
+        &TABLE[self.clone() as usize]
+    }
+}
+
+impl TryFrom<u8> for Element {
+    type Error = ElementError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value.checked_sub(1) {
+            Some(index) if (index as usize) < ELEMENTS.len() => {
+                Ok(ELEMENTS[index as usize].clone())
+            }
+            _ => Err(ElementError::UnknownAtomicNumber(value)),
+        }
+    }
+}
+
+impl FromStr for Element {
+    type Err = ElementError;
+
+    fn from_str(symbol: &str) -> Result<Self, Self::Err> {
+        match ELEMENTS
+            .iter()
+            .find(|element| element.to_string() == symbol)
+        {
+            Some(element) => Ok(element.clone()),
+            None => Err(ElementError::UnknownSymbol(symbol.to_string())),
+        }
+    }
 }
 
 impl std::convert::From<&Shortcut> for Element {
@@ -106,7 +194,7 @@ impl fmt::Display for Element {
             Element::Cm => "Cm",
             Element::Co => "Co",
             Element::Cr => "Cr",
-            Element::Cs => "Ac",
+            Element::Cs => "Cs",
             Element::Cu => "Cu",
             Element::Dy => "Dy",
             Element::Er => "Er",
@@ -210,3 +298,334 @@ mod default_valence {
         assert_eq!(element.default_valences(), [3].as_ref())
     }
 }
+
+#[cfg(test)]
+mod atomic_number {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn hydrogen() {
+        assert_eq!(Element::H.atomic_number(), 1)
+    }
+
+    #[test]
+    fn rutherfordium() {
+        assert_eq!(Element::Rf.atomic_number(), 104)
+    }
+
+    #[test]
+    fn round_trip() {
+        for number in 1..=104 {
+            let element = Element::try_from(number).unwrap();
+
+            assert_eq!(element.atomic_number(), number)
+        }
+    }
+}
+
+#[cfg(test)]
+mod try_from {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn zero() {
+        assert_eq!(
+            Element::try_from(0),
+            Err(ElementError::UnknownAtomicNumber(0))
+        )
+    }
+
+    #[test]
+    fn carbon() {
+        assert_eq!(Element::try_from(6), Ok(Element::C))
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            Element::try_from(105),
+            Err(ElementError::UnknownAtomicNumber(105))
+        )
+    }
+}
+
+#[cfg(test)]
+mod from_str {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn unknown() {
+        assert_eq!(
+            "Xx".parse::<Element>(),
+            Err(ElementError::UnknownSymbol("Xx".to_string()))
+        )
+    }
+
+    #[test]
+    fn lowercase() {
+        assert_eq!(
+            "cl".parse::<Element>(),
+            Err(ElementError::UnknownSymbol("cl".to_string()))
+        )
+    }
+
+    #[test]
+    fn chlorine() {
+        assert_eq!("Cl".parse::<Element>(), Ok(Element::Cl))
+    }
+
+    #[test]
+    fn round_trip() {
+        for number in 1..=104 {
+            let element = Element::try_from(number).unwrap();
+
+            assert_eq!(element.to_string().parse::<Element>(), Ok(element))
+        }
+    }
+}
+
+#[cfg(test)]
+mod name {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn carbon() {
+        assert_eq!(Element::C.name(), "Carbon")
+    }
+
+    #[test]
+    fn tungsten() {
+        assert_eq!(Element::W.name(), "Tungsten")
+    }
+}
+
+#[cfg(test)]
+mod period {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn helium() {
+        assert_eq!(Element::He.period(), 1)
+    }
+
+    #[test]
+    fn potassium() {
+        assert_eq!(Element::K.period(), 4)
+    }
+
+    #[test]
+    fn uranium() {
+        assert_eq!(Element::U.period(), 7)
+    }
+}
+
+#[cfg(test)]
+mod group {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn hydrogen() {
+        assert_eq!(Element::H.group(), Some(1))
+    }
+
+    #[test]
+    fn helium() {
+        assert_eq!(Element::He.group(), Some(18))
+    }
+
+    #[test]
+    fn aluminium() {
+        assert_eq!(Element::Al.group(), Some(13))
+    }
+
+    #[test]
+    fn iron() {
+        assert_eq!(Element::Fe.group(), Some(8))
+    }
+
+    #[test]
+    fn hafnium() {
+        assert_eq!(Element::Hf.group(), Some(4))
+    }
+
+    #[test]
+    fn lanthanide() {
+        assert_eq!(Element::Gd.group(), None)
+    }
+
+    #[test]
+    fn radon() {
+        assert_eq!(Element::Rn.group(), Some(18))
+    }
+
+    #[test]
+    fn rutherfordium() {
+        assert_eq!(Element::Rf.group(), Some(4))
+    }
+}
+
+#[cfg(test)]
+mod valence_electrons {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn helium() {
+        assert_eq!(Element::He.valence_electrons(), Some(2))
+    }
+
+    #[test]
+    fn magnesium() {
+        assert_eq!(Element::Mg.valence_electrons(), Some(2))
+    }
+
+    #[test]
+    fn chlorine() {
+        assert_eq!(Element::Cl.valence_electrons(), Some(7))
+    }
+
+    #[test]
+    fn transition_metal() {
+        assert_eq!(Element::Zn.valence_electrons(), None)
+    }
+}
+
+#[cfg(test)]
+mod atomic_weight {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn carbon() {
+        assert_eq!(Element::C.atomic_weight(), 12.011)
+    }
+
+    #[test]
+    fn technetium() {
+        assert_eq!(Element::Tc.atomic_weight().round(), 98.)
+    }
+
+    #[test]
+    fn consistent_with_abundances() {
+        for number in 1..=104 {
+            let element = Element::try_from(number).unwrap();
+            let isotopes = element.isotopes();
+            let total = isotopes.iter().map(|i| i.abundance).sum::<f64>();
+
+            if total == 0. {
+                continue;
+            }
+
+            let average =
+                isotopes.iter().map(|i| i.mass * i.abundance).sum::<f64>()
+                    / total;
+
+            assert!(
+                (average - element.atomic_weight()).abs() < 0.05,
+                "{}",
+                element
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod monoisotopic_mass {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn carbon() {
+        assert_eq!(Element::C.monoisotopic_mass(), 12.)
+    }
+
+    #[test]
+    fn bromine() {
+        assert_eq!(Element::Br.monoisotopic_mass(), 78.9183376)
+    }
+
+    #[test]
+    fn radium() {
+        assert_eq!(Element::Ra.monoisotopic_mass(), 226.0254103)
+    }
+}
+
+#[cfg(test)]
+mod isotopes {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn chlorine() {
+        let mass_numbers = Element::Cl
+            .isotopes()
+            .iter()
+            .map(|nuclide| nuclide.mass_number)
+            .collect::<Vec<_>>();
+
+        assert_eq!(mass_numbers, vec![35, 36, 37])
+    }
+
+    #[test]
+    fn abundances_sum_to_one() {
+        for number in 1..=104 {
+            let element = Element::try_from(number).unwrap();
+            let total = element
+                .isotopes()
+                .iter()
+                .map(|nuclide| nuclide.abundance)
+                .sum::<f64>();
+
+            assert!(total == 0. || (total - 1.).abs() < 1e-3, "{}", element)
+        }
+    }
+}
+
+#[cfg(test)]
+mod isotope {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn unknown() {
+        assert_eq!(Element::C.isotope(99), None)
+    }
+
+    #[test]
+    fn deuterium() {
+        assert_eq!(
+            Element::H.isotope(2),
+            Some(&Nuclide {
+                mass_number: 2,
+                mass: 2.01410177812,
+                abundance: 0.000115
+            })
+        )
+    }
+}
+
+#[cfg(test)]
+mod to_string {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn cesium() {
+        assert_eq!(Element::Cs.to_string(), "Cs")
+    }
+
+    #[test]
+    fn round_trip() {
+        for number in 1..=104 {
+            let element = Element::try_from(number).unwrap();
+
+            assert_eq!(element.to_string().parse::<Element>(), Ok(element))
+        }
+    }
+}
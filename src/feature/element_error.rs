@@ -0,0 +1,5 @@
+#[derive(Debug, PartialEq, Clone)]
+pub enum ElementError {
+    UnknownAtomicNumber(u8),
+    UnknownSymbol(String),
+}
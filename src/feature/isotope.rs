@@ -1,6 +1,6 @@
 use std;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub struct Isotope(u16);
 
 impl Isotope {
@@ -13,8 +13,35 @@ impl Isotope {
     }
 }
 
+impl From<&Isotope> for u16 {
+    fn from(value: &Isotope) -> Self {
+        value.0
+    }
+}
+
 impl std::fmt::Display for Isotope {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.0.fmt(f)
     }
 }
+
+#[cfg(test)]
+mod new {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn zero() {
+        assert_eq!(Isotope::new(0), None)
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(Isotope::new(1000), None)
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(Isotope::new(13).as_ref().map(u16::from), Some(13))
+    }
+}
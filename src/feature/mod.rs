@@ -5,10 +5,15 @@ mod bracket;
 mod bridge;
 mod charge;
 mod element;
+mod element_error;
 mod isotope;
+mod nuclide;
+mod periodic_table;
 mod selection;
 mod shortcut;
 mod symbol;
+mod valence_error;
+mod valence_model;
 mod virtual_hydrogen;
 
 pub use atom_kind::AtomKind;
@@ -18,8 +23,12 @@ pub use bracket::Bracket;
 pub use bridge::Bridge;
 pub use charge::Charge;
 pub use element::Element;
+pub use element_error::ElementError;
 pub use isotope::Isotope;
+pub use nuclide::Nuclide;
 pub use selection::Selection;
 pub use shortcut::Shortcut;
 pub use symbol::Symbol;
+pub use valence_error::ValenceError;
+pub use valence_model::{DefaultValenceModel, ValenceModel};
 pub use virtual_hydrogen::VirtualHydrogen;
@@ -0,0 +1,8 @@
+/// An isotope of an element. Mass is given in daltons and abundance as a
+/// mole fraction of the naturally occurring element.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Nuclide {
+    pub mass_number: u16,
+    pub mass: f64,
+    pub abundance: f64,
+}
@@ -0,0 +1,1347 @@
+use super::{Element, Nuclide};
+
+pub(super) struct Entry {
+    pub name: &'static str,
+    pub period: u8,
+    pub group: Option<u8>,
+    pub atomic_weight: f64,
+    pub monoisotopic_mass: f64,
+    pub isotopes: &'static [Nuclide],
+}
+
+#[rustfmt::skip]
+pub(super) const ELEMENTS: [Element; 104] = [
+    Element::H, Element::He, Element::Li, Element::Be, Element::B, Element::C,
+    Element::N, Element::O, Element::F, Element::Ne, Element::Na, Element::Mg,
+    Element::Al, Element::Si, Element::P, Element::S, Element::Cl, Element::Ar,
+    Element::K, Element::Ca, Element::Sc, Element::Ti, Element::V, Element::Cr,
+    Element::Mn, Element::Fe, Element::Co, Element::Ni, Element::Cu, Element::Zn,
+    Element::Ga, Element::Ge, Element::As, Element::Se, Element::Br, Element::Kr,
+    Element::Rb, Element::Sr, Element::Y, Element::Zr, Element::Nb, Element::Mo,
+    Element::Tc, Element::Ru, Element::Rh, Element::Pd, Element::Ag, Element::Cd,
+    Element::In, Element::Sn, Element::Sb, Element::Te, Element::I, Element::Xe,
+    Element::Cs, Element::Ba, Element::La, Element::Ce, Element::Pr, Element::Nd,
+    Element::Pm, Element::Sm, Element::Eu, Element::Gd, Element::Tb, Element::Dy,
+    Element::Ho, Element::Er, Element::Tm, Element::Yb, Element::Lu, Element::Hf,
+    Element::Ta, Element::W, Element::Re, Element::Os, Element::Ir, Element::Pt,
+    Element::Au, Element::Hg, Element::Tl, Element::Pb, Element::Bi, Element::Po,
+    Element::At, Element::Rn, Element::Fr, Element::Ra, Element::Ac, Element::Th,
+    Element::Pa, Element::U, Element::Np, Element::Pu, Element::Am, Element::Cm,
+    Element::Bk, Element::Cf, Element::Es, Element::Fm, Element::Md, Element::No,
+    Element::Lr, Element::Rf,
+];
+
+/// Rows ordered by atomic number. Atomic weights are IUPAC standard or
+/// conventional values. Masses are in daltons and abundances are mole
+/// fractions, with zero for isotopes that do not occur naturally.
+#[rustfmt::skip]
+pub(super) const TABLE: [Entry; 104] = [
+    Entry {
+        name: "Hydrogen",
+        period: 1,
+        group: Some(1),
+        atomic_weight: 1.008,
+        monoisotopic_mass: 1.00782503223,
+        isotopes: &[
+            Nuclide { mass_number: 1, mass: 1.00782503223, abundance: 0.999885 },
+            Nuclide { mass_number: 2, mass: 2.01410177812, abundance: 0.000115 },
+            Nuclide { mass_number: 3, mass: 3.0160492779, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Helium",
+        period: 1,
+        group: Some(18),
+        atomic_weight: 4.002602,
+        monoisotopic_mass: 4.00260325413,
+        isotopes: &[
+            Nuclide { mass_number: 3, mass: 3.0160293201, abundance: 1.34e-06 },
+            Nuclide { mass_number: 4, mass: 4.00260325413, abundance: 0.99999866 },
+        ],
+    },
+    Entry {
+        name: "Lithium",
+        period: 2,
+        group: Some(1),
+        atomic_weight: 6.94,
+        monoisotopic_mass: 7.0160034366,
+        isotopes: &[
+            Nuclide { mass_number: 6, mass: 6.0151228874, abundance: 0.0759 },
+            Nuclide { mass_number: 7, mass: 7.0160034366, abundance: 0.9241 },
+        ],
+    },
+    Entry {
+        name: "Beryllium",
+        period: 2,
+        group: Some(2),
+        atomic_weight: 9.0121831,
+        monoisotopic_mass: 9.012183065,
+        isotopes: &[
+            Nuclide { mass_number: 9, mass: 9.012183065, abundance: 1.0 },
+            Nuclide { mass_number: 10, mass: 10.013534695, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Boron",
+        period: 2,
+        group: Some(13),
+        atomic_weight: 10.81,
+        monoisotopic_mass: 11.00930536,
+        isotopes: &[
+            Nuclide { mass_number: 10, mass: 10.01293695, abundance: 0.199 },
+            Nuclide { mass_number: 11, mass: 11.00930536, abundance: 0.801 },
+        ],
+    },
+    Entry {
+        name: "Carbon",
+        period: 2,
+        group: Some(14),
+        atomic_weight: 12.011,
+        monoisotopic_mass: 12.0,
+        isotopes: &[
+            Nuclide { mass_number: 11, mass: 11.0114336, abundance: 0.0 },
+            Nuclide { mass_number: 12, mass: 12.0, abundance: 0.9893 },
+            Nuclide { mass_number: 13, mass: 13.00335483507, abundance: 0.0107 },
+            Nuclide { mass_number: 14, mass: 14.0032419884, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Nitrogen",
+        period: 2,
+        group: Some(15),
+        atomic_weight: 14.007,
+        monoisotopic_mass: 14.00307400443,
+        isotopes: &[
+            Nuclide { mass_number: 13, mass: 13.00573861, abundance: 0.0 },
+            Nuclide { mass_number: 14, mass: 14.00307400443, abundance: 0.99636 },
+            Nuclide { mass_number: 15, mass: 15.00010889888, abundance: 0.00364 },
+        ],
+    },
+    Entry {
+        name: "Oxygen",
+        period: 2,
+        group: Some(16),
+        atomic_weight: 15.999,
+        monoisotopic_mass: 15.99491461957,
+        isotopes: &[
+            Nuclide { mass_number: 15, mass: 15.0030656, abundance: 0.0 },
+            Nuclide { mass_number: 16, mass: 15.99491461957, abundance: 0.99757 },
+            Nuclide { mass_number: 17, mass: 16.9991317565, abundance: 0.00038 },
+            Nuclide { mass_number: 18, mass: 17.99915961286, abundance: 0.00205 },
+        ],
+    },
+    Entry {
+        name: "Fluorine",
+        period: 2,
+        group: Some(17),
+        atomic_weight: 18.998403163,
+        monoisotopic_mass: 18.99840316273,
+        isotopes: &[
+            Nuclide { mass_number: 18, mass: 18.000938, abundance: 0.0 },
+            Nuclide { mass_number: 19, mass: 18.99840316273, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Neon",
+        period: 2,
+        group: Some(18),
+        atomic_weight: 20.1797,
+        monoisotopic_mass: 19.9924401762,
+        isotopes: &[
+            Nuclide { mass_number: 20, mass: 19.9924401762, abundance: 0.9048 },
+            Nuclide { mass_number: 21, mass: 20.993846685, abundance: 0.0027 },
+            Nuclide { mass_number: 22, mass: 21.991385114, abundance: 0.0925 },
+        ],
+    },
+    Entry {
+        name: "Sodium",
+        period: 3,
+        group: Some(1),
+        atomic_weight: 22.98976928,
+        monoisotopic_mass: 22.989769282,
+        isotopes: &[
+            Nuclide { mass_number: 22, mass: 21.9944364, abundance: 0.0 },
+            Nuclide { mass_number: 23, mass: 22.989769282, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Magnesium",
+        period: 3,
+        group: Some(2),
+        atomic_weight: 24.305,
+        monoisotopic_mass: 23.985041697,
+        isotopes: &[
+            Nuclide { mass_number: 24, mass: 23.985041697, abundance: 0.7899 },
+            Nuclide { mass_number: 25, mass: 24.985836976, abundance: 0.1 },
+            Nuclide { mass_number: 26, mass: 25.982592968, abundance: 0.1101 },
+        ],
+    },
+    Entry {
+        name: "Aluminium",
+        period: 3,
+        group: Some(13),
+        atomic_weight: 26.9815384,
+        monoisotopic_mass: 26.98153853,
+        isotopes: &[
+            Nuclide { mass_number: 27, mass: 26.98153853, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Silicon",
+        period: 3,
+        group: Some(14),
+        atomic_weight: 28.085,
+        monoisotopic_mass: 27.97692653465,
+        isotopes: &[
+            Nuclide { mass_number: 28, mass: 27.97692653465, abundance: 0.92223 },
+            Nuclide { mass_number: 29, mass: 28.9764946649, abundance: 0.04685 },
+            Nuclide { mass_number: 30, mass: 29.973770136, abundance: 0.03092 },
+        ],
+    },
+    Entry {
+        name: "Phosphorus",
+        period: 3,
+        group: Some(15),
+        atomic_weight: 30.973761998,
+        monoisotopic_mass: 30.97376199842,
+        isotopes: &[
+            Nuclide { mass_number: 31, mass: 30.97376199842, abundance: 1.0 },
+            Nuclide { mass_number: 32, mass: 31.97390764, abundance: 0.0 },
+            Nuclide { mass_number: 33, mass: 32.9717257, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Sulfur",
+        period: 3,
+        group: Some(16),
+        atomic_weight: 32.06,
+        monoisotopic_mass: 31.9720711744,
+        isotopes: &[
+            Nuclide { mass_number: 32, mass: 31.9720711744, abundance: 0.9499 },
+            Nuclide { mass_number: 33, mass: 32.9714589098, abundance: 0.0075 },
+            Nuclide { mass_number: 34, mass: 33.967867004, abundance: 0.0425 },
+            Nuclide { mass_number: 35, mass: 34.96903231, abundance: 0.0 },
+            Nuclide { mass_number: 36, mass: 35.96708071, abundance: 0.0001 },
+        ],
+    },
+    Entry {
+        name: "Chlorine",
+        period: 3,
+        group: Some(17),
+        atomic_weight: 35.45,
+        monoisotopic_mass: 34.968852682,
+        isotopes: &[
+            Nuclide { mass_number: 35, mass: 34.968852682, abundance: 0.7576 },
+            Nuclide { mass_number: 36, mass: 35.96830698, abundance: 0.0 },
+            Nuclide { mass_number: 37, mass: 36.965902602, abundance: 0.2424 },
+        ],
+    },
+    Entry {
+        name: "Argon",
+        period: 3,
+        group: Some(18),
+        atomic_weight: 39.948,
+        monoisotopic_mass: 39.9623831237,
+        isotopes: &[
+            Nuclide { mass_number: 36, mass: 35.967545105, abundance: 0.003336 },
+            Nuclide { mass_number: 38, mass: 37.96273211, abundance: 0.000629 },
+            Nuclide { mass_number: 40, mass: 39.9623831237, abundance: 0.996035 },
+        ],
+    },
+    Entry {
+        name: "Potassium",
+        period: 4,
+        group: Some(1),
+        atomic_weight: 39.0983,
+        monoisotopic_mass: 38.9637064864,
+        isotopes: &[
+            Nuclide { mass_number: 39, mass: 38.9637064864, abundance: 0.932581 },
+            Nuclide { mass_number: 40, mass: 39.963998166, abundance: 0.000117 },
+            Nuclide { mass_number: 41, mass: 40.9618252579, abundance: 0.067302 },
+        ],
+    },
+    Entry {
+        name: "Calcium",
+        period: 4,
+        group: Some(2),
+        atomic_weight: 40.078,
+        monoisotopic_mass: 39.962590863,
+        isotopes: &[
+            Nuclide { mass_number: 40, mass: 39.962590863, abundance: 0.96941 },
+            Nuclide { mass_number: 42, mass: 41.95861783, abundance: 0.00647 },
+            Nuclide { mass_number: 43, mass: 42.95876644, abundance: 0.00135 },
+            Nuclide { mass_number: 44, mass: 43.95548156, abundance: 0.02086 },
+            Nuclide { mass_number: 45, mass: 44.9561862, abundance: 0.0 },
+            Nuclide { mass_number: 46, mass: 45.953689, abundance: 4e-05 },
+            Nuclide { mass_number: 48, mass: 47.95252276, abundance: 0.00187 },
+        ],
+    },
+    Entry {
+        name: "Scandium",
+        period: 4,
+        group: Some(3),
+        atomic_weight: 44.955908,
+        monoisotopic_mass: 44.95590828,
+        isotopes: &[
+            Nuclide { mass_number: 45, mass: 44.95590828, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Titanium",
+        period: 4,
+        group: Some(4),
+        atomic_weight: 47.867,
+        monoisotopic_mass: 47.94794198,
+        isotopes: &[
+            Nuclide { mass_number: 46, mass: 45.95262772, abundance: 0.0825 },
+            Nuclide { mass_number: 47, mass: 46.95175879, abundance: 0.0744 },
+            Nuclide { mass_number: 48, mass: 47.94794198, abundance: 0.7372 },
+            Nuclide { mass_number: 49, mass: 48.94786568, abundance: 0.0541 },
+            Nuclide { mass_number: 50, mass: 49.94478689, abundance: 0.0518 },
+        ],
+    },
+    Entry {
+        name: "Vanadium",
+        period: 4,
+        group: Some(5),
+        atomic_weight: 50.9415,
+        monoisotopic_mass: 50.94395704,
+        isotopes: &[
+            Nuclide { mass_number: 50, mass: 49.94715601, abundance: 0.0025 },
+            Nuclide { mass_number: 51, mass: 50.94395704, abundance: 0.9975 },
+        ],
+    },
+    Entry {
+        name: "Chromium",
+        period: 4,
+        group: Some(6),
+        atomic_weight: 51.9961,
+        monoisotopic_mass: 51.94050623,
+        isotopes: &[
+            Nuclide { mass_number: 50, mass: 49.94604183, abundance: 0.04345 },
+            Nuclide { mass_number: 51, mass: 50.9447641, abundance: 0.0 },
+            Nuclide { mass_number: 52, mass: 51.94050623, abundance: 0.83789 },
+            Nuclide { mass_number: 53, mass: 52.94064815, abundance: 0.09501 },
+            Nuclide { mass_number: 54, mass: 53.93887916, abundance: 0.02365 },
+        ],
+    },
+    Entry {
+        name: "Manganese",
+        period: 4,
+        group: Some(7),
+        atomic_weight: 54.938044,
+        monoisotopic_mass: 54.93804391,
+        isotopes: &[
+            Nuclide { mass_number: 55, mass: 54.93804391, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Iron",
+        period: 4,
+        group: Some(8),
+        atomic_weight: 55.845,
+        monoisotopic_mass: 55.93493633,
+        isotopes: &[
+            Nuclide { mass_number: 54, mass: 53.93960899, abundance: 0.05845 },
+            Nuclide { mass_number: 56, mass: 55.93493633, abundance: 0.91754 },
+            Nuclide { mass_number: 57, mass: 56.93539284, abundance: 0.02119 },
+            Nuclide { mass_number: 58, mass: 57.93327443, abundance: 0.00282 },
+            Nuclide { mass_number: 59, mass: 58.9348745, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Cobalt",
+        period: 4,
+        group: Some(9),
+        atomic_weight: 58.933194,
+        monoisotopic_mass: 58.93319429,
+        isotopes: &[
+            Nuclide { mass_number: 59, mass: 58.93319429, abundance: 1.0 },
+            Nuclide { mass_number: 60, mass: 59.9338163, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Nickel",
+        period: 4,
+        group: Some(10),
+        atomic_weight: 58.6934,
+        monoisotopic_mass: 57.93534241,
+        isotopes: &[
+            Nuclide { mass_number: 58, mass: 57.93534241, abundance: 0.68077 },
+            Nuclide { mass_number: 60, mass: 59.93078588, abundance: 0.26223 },
+            Nuclide { mass_number: 61, mass: 60.93105557, abundance: 0.011399 },
+            Nuclide { mass_number: 62, mass: 61.92834537, abundance: 0.036346 },
+            Nuclide { mass_number: 64, mass: 63.92796682, abundance: 0.009255 },
+        ],
+    },
+    Entry {
+        name: "Copper",
+        period: 4,
+        group: Some(11),
+        atomic_weight: 63.546,
+        monoisotopic_mass: 62.92959772,
+        isotopes: &[
+            Nuclide { mass_number: 63, mass: 62.92959772, abundance: 0.6915 },
+            Nuclide { mass_number: 64, mass: 63.9297642, abundance: 0.0 },
+            Nuclide { mass_number: 65, mass: 64.9277897, abundance: 0.3085 },
+        ],
+    },
+    Entry {
+        name: "Zinc",
+        period: 4,
+        group: Some(12),
+        atomic_weight: 65.38,
+        monoisotopic_mass: 63.92914201,
+        isotopes: &[
+            Nuclide { mass_number: 64, mass: 63.92914201, abundance: 0.4917 },
+            Nuclide { mass_number: 66, mass: 65.92603381, abundance: 0.2773 },
+            Nuclide { mass_number: 67, mass: 66.92712775, abundance: 0.0404 },
+            Nuclide { mass_number: 68, mass: 67.92484455, abundance: 0.1845 },
+            Nuclide { mass_number: 70, mass: 69.9253192, abundance: 0.0061 },
+        ],
+    },
+    Entry {
+        name: "Gallium",
+        period: 4,
+        group: Some(13),
+        atomic_weight: 69.723,
+        monoisotopic_mass: 68.9255735,
+        isotopes: &[
+            Nuclide { mass_number: 67, mass: 66.9282025, abundance: 0.0 },
+            Nuclide { mass_number: 68, mass: 67.9279805, abundance: 0.0 },
+            Nuclide { mass_number: 69, mass: 68.9255735, abundance: 0.60108 },
+            Nuclide { mass_number: 71, mass: 70.92470258, abundance: 0.39892 },
+        ],
+    },
+    Entry {
+        name: "Germanium",
+        period: 4,
+        group: Some(14),
+        atomic_weight: 72.63,
+        monoisotopic_mass: 73.921177761,
+        isotopes: &[
+            Nuclide { mass_number: 70, mass: 69.92424875, abundance: 0.2057 },
+            Nuclide { mass_number: 72, mass: 71.922075826, abundance: 0.2745 },
+            Nuclide { mass_number: 73, mass: 72.923458956, abundance: 0.0775 },
+            Nuclide { mass_number: 74, mass: 73.921177761, abundance: 0.365 },
+            Nuclide { mass_number: 76, mass: 75.921402726, abundance: 0.0773 },
+        ],
+    },
+    Entry {
+        name: "Arsenic",
+        period: 4,
+        group: Some(15),
+        atomic_weight: 74.921595,
+        monoisotopic_mass: 74.92159457,
+        isotopes: &[
+            Nuclide { mass_number: 75, mass: 74.92159457, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Selenium",
+        period: 4,
+        group: Some(16),
+        atomic_weight: 78.971,
+        monoisotopic_mass: 79.9165218,
+        isotopes: &[
+            Nuclide { mass_number: 74, mass: 73.922475934, abundance: 0.0089 },
+            Nuclide { mass_number: 76, mass: 75.919213704, abundance: 0.0937 },
+            Nuclide { mass_number: 77, mass: 76.919914154, abundance: 0.0763 },
+            Nuclide { mass_number: 78, mass: 77.91730928, abundance: 0.2377 },
+            Nuclide { mass_number: 80, mass: 79.9165218, abundance: 0.4961 },
+            Nuclide { mass_number: 82, mass: 81.9166995, abundance: 0.0873 },
+        ],
+    },
+    Entry {
+        name: "Bromine",
+        period: 4,
+        group: Some(17),
+        atomic_weight: 79.904,
+        monoisotopic_mass: 78.9183376,
+        isotopes: &[
+            Nuclide { mass_number: 79, mass: 78.9183376, abundance: 0.5069 },
+            Nuclide { mass_number: 81, mass: 80.9162897, abundance: 0.4931 },
+        ],
+    },
+    Entry {
+        name: "Krypton",
+        period: 4,
+        group: Some(18),
+        atomic_weight: 83.798,
+        monoisotopic_mass: 83.9114977282,
+        isotopes: &[
+            Nuclide { mass_number: 78, mass: 77.92036494, abundance: 0.00355 },
+            Nuclide { mass_number: 80, mass: 79.91637808, abundance: 0.02286 },
+            Nuclide { mass_number: 82, mass: 81.91348273, abundance: 0.11593 },
+            Nuclide { mass_number: 83, mass: 82.91412716, abundance: 0.115 },
+            Nuclide { mass_number: 84, mass: 83.9114977282, abundance: 0.56987 },
+            Nuclide { mass_number: 86, mass: 85.9106106269, abundance: 0.17279 },
+        ],
+    },
+    Entry {
+        name: "Rubidium",
+        period: 5,
+        group: Some(1),
+        atomic_weight: 85.4678,
+        monoisotopic_mass: 84.9117897379,
+        isotopes: &[
+            Nuclide { mass_number: 82, mass: 81.9182086, abundance: 0.0 },
+            Nuclide { mass_number: 85, mass: 84.9117897379, abundance: 0.7217 },
+            Nuclide { mass_number: 87, mass: 86.909180531, abundance: 0.2783 },
+        ],
+    },
+    Entry {
+        name: "Strontium",
+        period: 5,
+        group: Some(2),
+        atomic_weight: 87.62,
+        monoisotopic_mass: 87.9056125,
+        isotopes: &[
+            Nuclide { mass_number: 84, mass: 83.9134191, abundance: 0.0056 },
+            Nuclide { mass_number: 86, mass: 85.9092606, abundance: 0.0986 },
+            Nuclide { mass_number: 87, mass: 86.9088775, abundance: 0.07 },
+            Nuclide { mass_number: 88, mass: 87.9056125, abundance: 0.8258 },
+            Nuclide { mass_number: 89, mass: 88.9074507, abundance: 0.0 },
+            Nuclide { mass_number: 90, mass: 89.9077279, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Yttrium",
+        period: 5,
+        group: Some(3),
+        atomic_weight: 88.90584,
+        monoisotopic_mass: 88.9058403,
+        isotopes: &[
+            Nuclide { mass_number: 89, mass: 88.9058403, abundance: 1.0 },
+            Nuclide { mass_number: 90, mass: 89.9071439, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Zirconium",
+        period: 5,
+        group: Some(4),
+        atomic_weight: 91.224,
+        monoisotopic_mass: 89.9046977,
+        isotopes: &[
+            Nuclide { mass_number: 89, mass: 88.9088814, abundance: 0.0 },
+            Nuclide { mass_number: 90, mass: 89.9046977, abundance: 0.5145 },
+            Nuclide { mass_number: 91, mass: 90.9056396, abundance: 0.1122 },
+            Nuclide { mass_number: 92, mass: 91.9050347, abundance: 0.1715 },
+            Nuclide { mass_number: 94, mass: 93.9063108, abundance: 0.1738 },
+            Nuclide { mass_number: 96, mass: 95.9082714, abundance: 0.028 },
+        ],
+    },
+    Entry {
+        name: "Niobium",
+        period: 5,
+        group: Some(5),
+        atomic_weight: 92.90637,
+        monoisotopic_mass: 92.906373,
+        isotopes: &[
+            Nuclide { mass_number: 93, mass: 92.906373, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Molybdenum",
+        period: 5,
+        group: Some(6),
+        atomic_weight: 95.95,
+        monoisotopic_mass: 97.90540482,
+        isotopes: &[
+            Nuclide { mass_number: 92, mass: 91.90680796, abundance: 0.1453 },
+            Nuclide { mass_number: 94, mass: 93.9050849, abundance: 0.0915 },
+            Nuclide { mass_number: 95, mass: 94.90583877, abundance: 0.1584 },
+            Nuclide { mass_number: 96, mass: 95.90467612, abundance: 0.1667 },
+            Nuclide { mass_number: 97, mass: 96.90601812, abundance: 0.096 },
+            Nuclide { mass_number: 98, mass: 97.90540482, abundance: 0.2439 },
+            Nuclide { mass_number: 100, mass: 99.9074718, abundance: 0.0982 },
+        ],
+    },
+    Entry {
+        name: "Technetium",
+        period: 5,
+        group: Some(7),
+        atomic_weight: 97.9072124,
+        monoisotopic_mass: 97.9072124,
+        isotopes: &[
+            Nuclide { mass_number: 97, mass: 96.9063667, abundance: 0.0 },
+            Nuclide { mass_number: 98, mass: 97.9072124, abundance: 0.0 },
+            Nuclide { mass_number: 99, mass: 98.9062508, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Ruthenium",
+        period: 5,
+        group: Some(8),
+        atomic_weight: 101.07,
+        monoisotopic_mass: 101.9043441,
+        isotopes: &[
+            Nuclide { mass_number: 96, mass: 95.90759025, abundance: 0.0554 },
+            Nuclide { mass_number: 98, mass: 97.9052868, abundance: 0.0187 },
+            Nuclide { mass_number: 99, mass: 98.9059341, abundance: 0.1276 },
+            Nuclide { mass_number: 100, mass: 99.9042143, abundance: 0.126 },
+            Nuclide { mass_number: 101, mass: 100.9055769, abundance: 0.1706 },
+            Nuclide { mass_number: 102, mass: 101.9043441, abundance: 0.3155 },
+            Nuclide { mass_number: 104, mass: 103.9054275, abundance: 0.1862 },
+        ],
+    },
+    Entry {
+        name: "Rhodium",
+        period: 5,
+        group: Some(9),
+        atomic_weight: 102.9055,
+        monoisotopic_mass: 102.905498,
+        isotopes: &[
+            Nuclide { mass_number: 103, mass: 102.905498, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Palladium",
+        period: 5,
+        group: Some(10),
+        atomic_weight: 106.42,
+        monoisotopic_mass: 105.9034804,
+        isotopes: &[
+            Nuclide { mass_number: 102, mass: 101.9056022, abundance: 0.0102 },
+            Nuclide { mass_number: 104, mass: 103.9040305, abundance: 0.1114 },
+            Nuclide { mass_number: 105, mass: 104.9050796, abundance: 0.2233 },
+            Nuclide { mass_number: 106, mass: 105.9034804, abundance: 0.2733 },
+            Nuclide { mass_number: 108, mass: 107.9038916, abundance: 0.2646 },
+            Nuclide { mass_number: 110, mass: 109.9051722, abundance: 0.1172 },
+        ],
+    },
+    Entry {
+        name: "Silver",
+        period: 5,
+        group: Some(11),
+        atomic_weight: 107.8682,
+        monoisotopic_mass: 106.9050916,
+        isotopes: &[
+            Nuclide { mass_number: 107, mass: 106.9050916, abundance: 0.51839 },
+            Nuclide { mass_number: 109, mass: 108.9047553, abundance: 0.48161 },
+        ],
+    },
+    Entry {
+        name: "Cadmium",
+        period: 5,
+        group: Some(12),
+        atomic_weight: 112.414,
+        monoisotopic_mass: 113.90336509,
+        isotopes: &[
+            Nuclide { mass_number: 106, mass: 105.9064599, abundance: 0.0125 },
+            Nuclide { mass_number: 108, mass: 107.9041834, abundance: 0.0089 },
+            Nuclide { mass_number: 110, mass: 109.90300661, abundance: 0.1249 },
+            Nuclide { mass_number: 111, mass: 110.90418287, abundance: 0.128 },
+            Nuclide { mass_number: 112, mass: 111.90276287, abundance: 0.2413 },
+            Nuclide { mass_number: 113, mass: 112.90440813, abundance: 0.1222 },
+            Nuclide { mass_number: 114, mass: 113.90336509, abundance: 0.2873 },
+            Nuclide { mass_number: 116, mass: 115.90476315, abundance: 0.0749 },
+        ],
+    },
+    Entry {
+        name: "Indium",
+        period: 5,
+        group: Some(13),
+        atomic_weight: 114.818,
+        monoisotopic_mass: 114.903878776,
+        isotopes: &[
+            Nuclide { mass_number: 111, mass: 110.9051085, abundance: 0.0 },
+            Nuclide { mass_number: 113, mass: 112.90406184, abundance: 0.0429 },
+            Nuclide { mass_number: 115, mass: 114.903878776, abundance: 0.9571 },
+        ],
+    },
+    Entry {
+        name: "Tin",
+        period: 5,
+        group: Some(14),
+        atomic_weight: 118.71,
+        monoisotopic_mass: 119.90220163,
+        isotopes: &[
+            Nuclide { mass_number: 112, mass: 111.90482387, abundance: 0.0097 },
+            Nuclide { mass_number: 114, mass: 113.9027827, abundance: 0.0066 },
+            Nuclide { mass_number: 115, mass: 114.903344699, abundance: 0.0034 },
+            Nuclide { mass_number: 116, mass: 115.9017428, abundance: 0.1454 },
+            Nuclide { mass_number: 117, mass: 116.90295398, abundance: 0.0768 },
+            Nuclide { mass_number: 118, mass: 117.90160657, abundance: 0.2422 },
+            Nuclide { mass_number: 119, mass: 118.90331117, abundance: 0.0859 },
+            Nuclide { mass_number: 120, mass: 119.90220163, abundance: 0.3258 },
+            Nuclide { mass_number: 122, mass: 121.9034438, abundance: 0.0463 },
+            Nuclide { mass_number: 124, mass: 123.9052766, abundance: 0.0579 },
+        ],
+    },
+    Entry {
+        name: "Antimony",
+        period: 5,
+        group: Some(15),
+        atomic_weight: 121.76,
+        monoisotopic_mass: 120.903812,
+        isotopes: &[
+            Nuclide { mass_number: 121, mass: 120.903812, abundance: 0.5721 },
+            Nuclide { mass_number: 123, mass: 122.9042132, abundance: 0.4279 },
+        ],
+    },
+    Entry {
+        name: "Tellurium",
+        period: 5,
+        group: Some(16),
+        atomic_weight: 127.6,
+        monoisotopic_mass: 129.906222748,
+        isotopes: &[
+            Nuclide { mass_number: 120, mass: 119.9040593, abundance: 0.0009 },
+            Nuclide { mass_number: 122, mass: 121.9030435, abundance: 0.0255 },
+            Nuclide { mass_number: 123, mass: 122.9042698, abundance: 0.0089 },
+            Nuclide { mass_number: 124, mass: 123.9028171, abundance: 0.0474 },
+            Nuclide { mass_number: 125, mass: 124.9044299, abundance: 0.0707 },
+            Nuclide { mass_number: 126, mass: 125.9033109, abundance: 0.1884 },
+            Nuclide { mass_number: 128, mass: 127.90446128, abundance: 0.3174 },
+            Nuclide { mass_number: 130, mass: 129.906222748, abundance: 0.3408 },
+        ],
+    },
+    Entry {
+        name: "Iodine",
+        period: 5,
+        group: Some(17),
+        atomic_weight: 126.90447,
+        monoisotopic_mass: 126.9044719,
+        isotopes: &[
+            Nuclide { mass_number: 123, mass: 122.9055898, abundance: 0.0 },
+            Nuclide { mass_number: 124, mass: 123.906209, abundance: 0.0 },
+            Nuclide { mass_number: 125, mass: 124.9046294, abundance: 0.0 },
+            Nuclide { mass_number: 127, mass: 126.9044719, abundance: 1.0 },
+            Nuclide { mass_number: 129, mass: 128.9049837, abundance: 0.0 },
+            Nuclide { mass_number: 131, mass: 130.9061263, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Xenon",
+        period: 5,
+        group: Some(18),
+        atomic_weight: 131.293,
+        monoisotopic_mass: 131.9041550856,
+        isotopes: &[
+            Nuclide { mass_number: 124, mass: 123.905892, abundance: 0.000952 },
+            Nuclide { mass_number: 126, mass: 125.9042983, abundance: 0.00089 },
+            Nuclide { mass_number: 128, mass: 127.903531, abundance: 0.019102 },
+            Nuclide { mass_number: 129, mass: 128.9047808611, abundance: 0.264006 },
+            Nuclide { mass_number: 130, mass: 129.903509349, abundance: 0.04071 },
+            Nuclide { mass_number: 131, mass: 130.90508406, abundance: 0.212324 },
+            Nuclide { mass_number: 132, mass: 131.9041550856, abundance: 0.269086 },
+            Nuclide { mass_number: 134, mass: 133.90539466, abundance: 0.104357 },
+            Nuclide { mass_number: 136, mass: 135.907214484, abundance: 0.088573 },
+        ],
+    },
+    Entry {
+        name: "Caesium",
+        period: 6,
+        group: Some(1),
+        atomic_weight: 132.90545196,
+        monoisotopic_mass: 132.905451961,
+        isotopes: &[
+            Nuclide { mass_number: 133, mass: 132.905451961, abundance: 1.0 },
+            Nuclide { mass_number: 137, mass: 136.9070895, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Barium",
+        period: 6,
+        group: Some(2),
+        atomic_weight: 137.327,
+        monoisotopic_mass: 137.905247,
+        isotopes: &[
+            Nuclide { mass_number: 130, mass: 129.9063207, abundance: 0.00106 },
+            Nuclide { mass_number: 132, mass: 131.9050611, abundance: 0.00101 },
+            Nuclide { mass_number: 134, mass: 133.90450818, abundance: 0.02417 },
+            Nuclide { mass_number: 135, mass: 134.90568838, abundance: 0.06592 },
+            Nuclide { mass_number: 136, mass: 135.90457573, abundance: 0.07854 },
+            Nuclide { mass_number: 137, mass: 136.90582714, abundance: 0.11232 },
+            Nuclide { mass_number: 138, mass: 137.905247, abundance: 0.71698 },
+        ],
+    },
+    Entry {
+        name: "Lanthanum",
+        period: 6,
+        group: None,
+        atomic_weight: 138.90547,
+        monoisotopic_mass: 138.9063563,
+        isotopes: &[
+            Nuclide { mass_number: 138, mass: 137.9071149, abundance: 0.0008881 },
+            Nuclide { mass_number: 139, mass: 138.9063563, abundance: 0.9991119 },
+        ],
+    },
+    Entry {
+        name: "Cerium",
+        period: 6,
+        group: None,
+        atomic_weight: 140.116,
+        monoisotopic_mass: 139.9054431,
+        isotopes: &[
+            Nuclide { mass_number: 136, mass: 135.90712921, abundance: 0.00185 },
+            Nuclide { mass_number: 138, mass: 137.905991, abundance: 0.00251 },
+            Nuclide { mass_number: 140, mass: 139.9054431, abundance: 0.8845 },
+            Nuclide { mass_number: 142, mass: 141.9092504, abundance: 0.11114 },
+        ],
+    },
+    Entry {
+        name: "Praseodymium",
+        period: 6,
+        group: None,
+        atomic_weight: 140.90766,
+        monoisotopic_mass: 140.9076576,
+        isotopes: &[
+            Nuclide { mass_number: 141, mass: 140.9076576, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Neodymium",
+        period: 6,
+        group: None,
+        atomic_weight: 144.242,
+        monoisotopic_mass: 141.907729,
+        isotopes: &[
+            Nuclide { mass_number: 142, mass: 141.907729, abundance: 0.27152 },
+            Nuclide { mass_number: 143, mass: 142.90982, abundance: 0.12174 },
+            Nuclide { mass_number: 144, mass: 143.910093, abundance: 0.23798 },
+            Nuclide { mass_number: 145, mass: 144.9125793, abundance: 0.08293 },
+            Nuclide { mass_number: 146, mass: 145.9131226, abundance: 0.17189 },
+            Nuclide { mass_number: 148, mass: 147.9168993, abundance: 0.05756 },
+            Nuclide { mass_number: 150, mass: 149.9209022, abundance: 0.05638 },
+        ],
+    },
+    Entry {
+        name: "Promethium",
+        period: 6,
+        group: None,
+        atomic_weight: 144.9127559,
+        monoisotopic_mass: 144.9127559,
+        isotopes: &[
+            Nuclide { mass_number: 145, mass: 144.9127559, abundance: 0.0 },
+            Nuclide { mass_number: 147, mass: 146.915145, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Samarium",
+        period: 6,
+        group: None,
+        atomic_weight: 150.36,
+        monoisotopic_mass: 151.9197397,
+        isotopes: &[
+            Nuclide { mass_number: 144, mass: 143.9120065, abundance: 0.0307 },
+            Nuclide { mass_number: 147, mass: 146.9149044, abundance: 0.1499 },
+            Nuclide { mass_number: 148, mass: 147.9148292, abundance: 0.1124 },
+            Nuclide { mass_number: 149, mass: 148.9171921, abundance: 0.1382 },
+            Nuclide { mass_number: 150, mass: 149.9172829, abundance: 0.0738 },
+            Nuclide { mass_number: 152, mass: 151.9197397, abundance: 0.2675 },
+            Nuclide { mass_number: 154, mass: 153.9222169, abundance: 0.2275 },
+        ],
+    },
+    Entry {
+        name: "Europium",
+        period: 6,
+        group: None,
+        atomic_weight: 151.964,
+        monoisotopic_mass: 152.921238,
+        isotopes: &[
+            Nuclide { mass_number: 151, mass: 150.9198578, abundance: 0.4781 },
+            Nuclide { mass_number: 153, mass: 152.921238, abundance: 0.5219 },
+        ],
+    },
+    Entry {
+        name: "Gadolinium",
+        period: 6,
+        group: None,
+        atomic_weight: 157.25,
+        monoisotopic_mass: 157.9241123,
+        isotopes: &[
+            Nuclide { mass_number: 152, mass: 151.9197995, abundance: 0.002 },
+            Nuclide { mass_number: 154, mass: 153.9208741, abundance: 0.0218 },
+            Nuclide { mass_number: 155, mass: 154.9226305, abundance: 0.148 },
+            Nuclide { mass_number: 156, mass: 155.9221312, abundance: 0.2047 },
+            Nuclide { mass_number: 157, mass: 156.9239686, abundance: 0.1565 },
+            Nuclide { mass_number: 158, mass: 157.9241123, abundance: 0.2484 },
+            Nuclide { mass_number: 160, mass: 159.9270624, abundance: 0.2186 },
+        ],
+    },
+    Entry {
+        name: "Terbium",
+        period: 6,
+        group: None,
+        atomic_weight: 158.92535,
+        monoisotopic_mass: 158.9253547,
+        isotopes: &[
+            Nuclide { mass_number: 159, mass: 158.9253547, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Dysprosium",
+        period: 6,
+        group: None,
+        atomic_weight: 162.5,
+        monoisotopic_mass: 163.9291819,
+        isotopes: &[
+            Nuclide { mass_number: 156, mass: 155.9242847, abundance: 0.00056 },
+            Nuclide { mass_number: 158, mass: 157.9244159, abundance: 0.00095 },
+            Nuclide { mass_number: 160, mass: 159.9252046, abundance: 0.02329 },
+            Nuclide { mass_number: 161, mass: 160.9269405, abundance: 0.18889 },
+            Nuclide { mass_number: 162, mass: 161.9268056, abundance: 0.25475 },
+            Nuclide { mass_number: 163, mass: 162.9287383, abundance: 0.24896 },
+            Nuclide { mass_number: 164, mass: 163.9291819, abundance: 0.2826 },
+        ],
+    },
+    Entry {
+        name: "Holmium",
+        period: 6,
+        group: None,
+        atomic_weight: 164.93033,
+        monoisotopic_mass: 164.9303288,
+        isotopes: &[
+            Nuclide { mass_number: 165, mass: 164.9303288, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Erbium",
+        period: 6,
+        group: None,
+        atomic_weight: 167.259,
+        monoisotopic_mass: 165.9302995,
+        isotopes: &[
+            Nuclide { mass_number: 162, mass: 161.9287884, abundance: 0.00139 },
+            Nuclide { mass_number: 164, mass: 163.9292088, abundance: 0.01601 },
+            Nuclide { mass_number: 166, mass: 165.9302995, abundance: 0.33503 },
+            Nuclide { mass_number: 167, mass: 166.9320546, abundance: 0.22869 },
+            Nuclide { mass_number: 168, mass: 167.9323767, abundance: 0.26978 },
+            Nuclide { mass_number: 170, mass: 169.9354702, abundance: 0.1491 },
+        ],
+    },
+    Entry {
+        name: "Thulium",
+        period: 6,
+        group: None,
+        atomic_weight: 168.93422,
+        monoisotopic_mass: 168.9342179,
+        isotopes: &[
+            Nuclide { mass_number: 169, mass: 168.9342179, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Ytterbium",
+        period: 6,
+        group: None,
+        atomic_weight: 173.045,
+        monoisotopic_mass: 173.9388664,
+        isotopes: &[
+            Nuclide { mass_number: 168, mass: 167.9338896, abundance: 0.00123 },
+            Nuclide { mass_number: 170, mass: 169.9347664, abundance: 0.02982 },
+            Nuclide { mass_number: 171, mass: 170.9363302, abundance: 0.1409 },
+            Nuclide { mass_number: 172, mass: 171.9363859, abundance: 0.2168 },
+            Nuclide { mass_number: 173, mass: 172.9382151, abundance: 0.16103 },
+            Nuclide { mass_number: 174, mass: 173.9388664, abundance: 0.32026 },
+            Nuclide { mass_number: 176, mass: 175.9425764, abundance: 0.12996 },
+        ],
+    },
+    Entry {
+        name: "Lutetium",
+        period: 6,
+        group: None,
+        atomic_weight: 174.9668,
+        monoisotopic_mass: 174.9407752,
+        isotopes: &[
+            Nuclide { mass_number: 175, mass: 174.9407752, abundance: 0.97401 },
+            Nuclide { mass_number: 176, mass: 175.9426897, abundance: 0.02599 },
+            Nuclide { mass_number: 177, mass: 176.9437615, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Hafnium",
+        period: 6,
+        group: Some(4),
+        atomic_weight: 178.49,
+        monoisotopic_mass: 179.946557,
+        isotopes: &[
+            Nuclide { mass_number: 174, mass: 173.9400461, abundance: 0.0016 },
+            Nuclide { mass_number: 176, mass: 175.9414076, abundance: 0.0526 },
+            Nuclide { mass_number: 177, mass: 176.9432277, abundance: 0.186 },
+            Nuclide { mass_number: 178, mass: 177.9437058, abundance: 0.2728 },
+            Nuclide { mass_number: 179, mass: 178.9458232, abundance: 0.1362 },
+            Nuclide { mass_number: 180, mass: 179.946557, abundance: 0.3508 },
+        ],
+    },
+    Entry {
+        name: "Tantalum",
+        period: 6,
+        group: Some(5),
+        atomic_weight: 180.94788,
+        monoisotopic_mass: 180.9479958,
+        isotopes: &[
+            Nuclide { mass_number: 180, mass: 179.9474648, abundance: 0.0001201 },
+            Nuclide { mass_number: 181, mass: 180.9479958, abundance: 0.9998799 },
+        ],
+    },
+    Entry {
+        name: "Tungsten",
+        period: 6,
+        group: Some(6),
+        atomic_weight: 183.84,
+        monoisotopic_mass: 183.95093092,
+        isotopes: &[
+            Nuclide { mass_number: 180, mass: 179.9467108, abundance: 0.0012 },
+            Nuclide { mass_number: 182, mass: 181.94820394, abundance: 0.265 },
+            Nuclide { mass_number: 183, mass: 182.95022275, abundance: 0.1431 },
+            Nuclide { mass_number: 184, mass: 183.95093092, abundance: 0.3064 },
+            Nuclide { mass_number: 186, mass: 185.9543628, abundance: 0.2843 },
+        ],
+    },
+    Entry {
+        name: "Rhenium",
+        period: 6,
+        group: Some(7),
+        atomic_weight: 186.207,
+        monoisotopic_mass: 186.9557501,
+        isotopes: &[
+            Nuclide { mass_number: 185, mass: 184.9529545, abundance: 0.374 },
+            Nuclide { mass_number: 187, mass: 186.9557501, abundance: 0.626 },
+        ],
+    },
+    Entry {
+        name: "Osmium",
+        period: 6,
+        group: Some(8),
+        atomic_weight: 190.23,
+        monoisotopic_mass: 191.961477,
+        isotopes: &[
+            Nuclide { mass_number: 184, mass: 183.9524885, abundance: 0.0002 },
+            Nuclide { mass_number: 186, mass: 185.953835, abundance: 0.0159 },
+            Nuclide { mass_number: 187, mass: 186.9557474, abundance: 0.0196 },
+            Nuclide { mass_number: 188, mass: 187.9558352, abundance: 0.1324 },
+            Nuclide { mass_number: 189, mass: 188.9581442, abundance: 0.1615 },
+            Nuclide { mass_number: 190, mass: 189.9584437, abundance: 0.2626 },
+            Nuclide { mass_number: 192, mass: 191.961477, abundance: 0.4078 },
+        ],
+    },
+    Entry {
+        name: "Iridium",
+        period: 6,
+        group: Some(9),
+        atomic_weight: 192.217,
+        monoisotopic_mass: 192.9629216,
+        isotopes: &[
+            Nuclide { mass_number: 191, mass: 190.9605893, abundance: 0.373 },
+            Nuclide { mass_number: 193, mass: 192.9629216, abundance: 0.627 },
+        ],
+    },
+    Entry {
+        name: "Platinum",
+        period: 6,
+        group: Some(10),
+        atomic_weight: 195.084,
+        monoisotopic_mass: 194.9647917,
+        isotopes: &[
+            Nuclide { mass_number: 190, mass: 189.9599297, abundance: 0.00012 },
+            Nuclide { mass_number: 192, mass: 191.9610387, abundance: 0.00782 },
+            Nuclide { mass_number: 194, mass: 193.9626809, abundance: 0.3286 },
+            Nuclide { mass_number: 195, mass: 194.9647917, abundance: 0.3378 },
+            Nuclide { mass_number: 196, mass: 195.96495209, abundance: 0.2521 },
+            Nuclide { mass_number: 198, mass: 197.9678949, abundance: 0.07356 },
+        ],
+    },
+    Entry {
+        name: "Gold",
+        period: 6,
+        group: Some(11),
+        atomic_weight: 196.966569,
+        monoisotopic_mass: 196.96656879,
+        isotopes: &[
+            Nuclide { mass_number: 197, mass: 196.96656879, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Mercury",
+        period: 6,
+        group: Some(12),
+        atomic_weight: 200.592,
+        monoisotopic_mass: 201.9706434,
+        isotopes: &[
+            Nuclide { mass_number: 196, mass: 195.9658326, abundance: 0.0015 },
+            Nuclide { mass_number: 198, mass: 197.9667686, abundance: 0.0997 },
+            Nuclide { mass_number: 199, mass: 198.96828064, abundance: 0.1687 },
+            Nuclide { mass_number: 200, mass: 199.96832659, abundance: 0.231 },
+            Nuclide { mass_number: 201, mass: 200.97030284, abundance: 0.1318 },
+            Nuclide { mass_number: 202, mass: 201.9706434, abundance: 0.2986 },
+            Nuclide { mass_number: 204, mass: 203.97349398, abundance: 0.0687 },
+        ],
+    },
+    Entry {
+        name: "Thallium",
+        period: 6,
+        group: Some(13),
+        atomic_weight: 204.38,
+        monoisotopic_mass: 204.9744278,
+        isotopes: &[
+            Nuclide { mass_number: 201, mass: 200.970819, abundance: 0.0 },
+            Nuclide { mass_number: 203, mass: 202.9723446, abundance: 0.2952 },
+            Nuclide { mass_number: 205, mass: 204.9744278, abundance: 0.7048 },
+        ],
+    },
+    Entry {
+        name: "Lead",
+        period: 6,
+        group: Some(14),
+        atomic_weight: 207.2,
+        monoisotopic_mass: 207.9766525,
+        isotopes: &[
+            Nuclide { mass_number: 204, mass: 203.973044, abundance: 0.014 },
+            Nuclide { mass_number: 206, mass: 205.9744657, abundance: 0.241 },
+            Nuclide { mass_number: 207, mass: 206.9758973, abundance: 0.221 },
+            Nuclide { mass_number: 208, mass: 207.9766525, abundance: 0.524 },
+        ],
+    },
+    Entry {
+        name: "Bismuth",
+        period: 6,
+        group: Some(15),
+        atomic_weight: 208.9804,
+        monoisotopic_mass: 208.9803991,
+        isotopes: &[
+            Nuclide { mass_number: 209, mass: 208.9803991, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Polonium",
+        period: 6,
+        group: Some(16),
+        atomic_weight: 208.9824308,
+        monoisotopic_mass: 208.9824308,
+        isotopes: &[
+            Nuclide { mass_number: 208, mass: 207.9812461, abundance: 0.0 },
+            Nuclide { mass_number: 209, mass: 208.9824308, abundance: 0.0 },
+            Nuclide { mass_number: 210, mass: 209.9828741, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Astatine",
+        period: 6,
+        group: Some(17),
+        atomic_weight: 209.9871479,
+        monoisotopic_mass: 209.9871479,
+        isotopes: &[
+            Nuclide { mass_number: 210, mass: 209.9871479, abundance: 0.0 },
+            Nuclide { mass_number: 211, mass: 210.9874966, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Radon",
+        period: 6,
+        group: Some(18),
+        atomic_weight: 222.0175782,
+        monoisotopic_mass: 222.0175782,
+        isotopes: &[
+            Nuclide { mass_number: 211, mass: 210.9906011, abundance: 0.0 },
+            Nuclide { mass_number: 220, mass: 220.0113941, abundance: 0.0 },
+            Nuclide { mass_number: 222, mass: 222.0175782, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Francium",
+        period: 7,
+        group: Some(1),
+        atomic_weight: 223.019736,
+        monoisotopic_mass: 223.019736,
+        isotopes: &[
+            Nuclide { mass_number: 223, mass: 223.019736, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Radium",
+        period: 7,
+        group: Some(2),
+        atomic_weight: 226.0254103,
+        monoisotopic_mass: 226.0254103,
+        isotopes: &[
+            Nuclide { mass_number: 223, mass: 223.0185023, abundance: 0.0 },
+            Nuclide { mass_number: 224, mass: 224.020212, abundance: 0.0 },
+            Nuclide { mass_number: 226, mass: 226.0254103, abundance: 0.0 },
+            Nuclide { mass_number: 228, mass: 228.0310707, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Actinium",
+        period: 7,
+        group: None,
+        atomic_weight: 227.0277523,
+        monoisotopic_mass: 227.0277523,
+        isotopes: &[
+            Nuclide { mass_number: 227, mass: 227.0277523, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Thorium",
+        period: 7,
+        group: None,
+        atomic_weight: 232.0377,
+        monoisotopic_mass: 232.0380558,
+        isotopes: &[
+            Nuclide { mass_number: 230, mass: 230.0331341, abundance: 0.0 },
+            Nuclide { mass_number: 232, mass: 232.0380558, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Protactinium",
+        period: 7,
+        group: None,
+        atomic_weight: 231.03588,
+        monoisotopic_mass: 231.0358842,
+        isotopes: &[
+            Nuclide { mass_number: 231, mass: 231.0358842, abundance: 1.0 },
+        ],
+    },
+    Entry {
+        name: "Uranium",
+        period: 7,
+        group: None,
+        atomic_weight: 238.02891,
+        monoisotopic_mass: 238.0507884,
+        isotopes: &[
+            Nuclide { mass_number: 233, mass: 233.0396355, abundance: 0.0 },
+            Nuclide { mass_number: 234, mass: 234.0409523, abundance: 5.4e-05 },
+            Nuclide { mass_number: 235, mass: 235.0439301, abundance: 0.007204 },
+            Nuclide { mass_number: 236, mass: 236.0455682, abundance: 0.0 },
+            Nuclide { mass_number: 238, mass: 238.0507884, abundance: 0.992742 },
+        ],
+    },
+    Entry {
+        name: "Neptunium",
+        period: 7,
+        group: None,
+        atomic_weight: 237.0481736,
+        monoisotopic_mass: 237.0481736,
+        isotopes: &[
+            Nuclide { mass_number: 236, mass: 236.04657, abundance: 0.0 },
+            Nuclide { mass_number: 237, mass: 237.0481736, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Plutonium",
+        period: 7,
+        group: None,
+        atomic_weight: 244.0642053,
+        monoisotopic_mass: 244.0642053,
+        isotopes: &[
+            Nuclide { mass_number: 238, mass: 238.0495601, abundance: 0.0 },
+            Nuclide { mass_number: 239, mass: 239.0521636, abundance: 0.0 },
+            Nuclide { mass_number: 240, mass: 240.0538138, abundance: 0.0 },
+            Nuclide { mass_number: 241, mass: 241.0568517, abundance: 0.0 },
+            Nuclide { mass_number: 242, mass: 242.0587428, abundance: 0.0 },
+            Nuclide { mass_number: 244, mass: 244.0642053, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Americium",
+        period: 7,
+        group: None,
+        atomic_weight: 243.0613813,
+        monoisotopic_mass: 243.0613813,
+        isotopes: &[
+            Nuclide { mass_number: 241, mass: 241.0568293, abundance: 0.0 },
+            Nuclide { mass_number: 243, mass: 243.0613813, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Curium",
+        period: 7,
+        group: None,
+        atomic_weight: 247.0703541,
+        monoisotopic_mass: 247.0703541,
+        isotopes: &[
+            Nuclide { mass_number: 243, mass: 243.0613893, abundance: 0.0 },
+            Nuclide { mass_number: 244, mass: 244.0627528, abundance: 0.0 },
+            Nuclide { mass_number: 245, mass: 245.0654915, abundance: 0.0 },
+            Nuclide { mass_number: 246, mass: 246.0672238, abundance: 0.0 },
+            Nuclide { mass_number: 247, mass: 247.0703541, abundance: 0.0 },
+            Nuclide { mass_number: 248, mass: 248.0723499, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Berkelium",
+        period: 7,
+        group: None,
+        atomic_weight: 247.0703073,
+        monoisotopic_mass: 247.0703073,
+        isotopes: &[
+            Nuclide { mass_number: 247, mass: 247.0703073, abundance: 0.0 },
+            Nuclide { mass_number: 249, mass: 249.0749877, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Californium",
+        period: 7,
+        group: None,
+        atomic_weight: 251.0795886,
+        monoisotopic_mass: 251.0795886,
+        isotopes: &[
+            Nuclide { mass_number: 249, mass: 249.0748539, abundance: 0.0 },
+            Nuclide { mass_number: 250, mass: 250.0764062, abundance: 0.0 },
+            Nuclide { mass_number: 251, mass: 251.0795886, abundance: 0.0 },
+            Nuclide { mass_number: 252, mass: 252.0816272, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Einsteinium",
+        period: 7,
+        group: None,
+        atomic_weight: 252.08298,
+        monoisotopic_mass: 252.08298,
+        isotopes: &[
+            Nuclide { mass_number: 252, mass: 252.08298, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Fermium",
+        period: 7,
+        group: None,
+        atomic_weight: 257.0951061,
+        monoisotopic_mass: 257.0951061,
+        isotopes: &[
+            Nuclide { mass_number: 257, mass: 257.0951061, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Mendelevium",
+        period: 7,
+        group: None,
+        atomic_weight: 258.0984315,
+        monoisotopic_mass: 258.0984315,
+        isotopes: &[
+            Nuclide { mass_number: 258, mass: 258.0984315, abundance: 0.0 },
+            Nuclide { mass_number: 260, mass: 260.10365, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Nobelium",
+        period: 7,
+        group: None,
+        atomic_weight: 259.10103,
+        monoisotopic_mass: 259.10103,
+        isotopes: &[
+            Nuclide { mass_number: 259, mass: 259.10103, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Lawrencium",
+        period: 7,
+        group: None,
+        atomic_weight: 262.10961,
+        monoisotopic_mass: 262.10961,
+        isotopes: &[
+            Nuclide { mass_number: 262, mass: 262.10961, abundance: 0.0 },
+        ],
+    },
+    Entry {
+        name: "Rutherfordium",
+        period: 7,
+        group: Some(4),
+        atomic_weight: 267.12179,
+        monoisotopic_mass: 267.12179,
+        isotopes: &[
+            Nuclide { mass_number: 267, mass: 267.12179, abundance: 0.0 },
+        ],
+    },
+];
@@ -2,8 +2,9 @@ use std::{fmt, fmt::Write};
 
 use super::{Element, Selection};
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Default)]
 pub enum Symbol {
+    #[default]
     Star,
     Element(Element),
     Selection(Selection),
@@ -18,9 +19,3 @@ impl fmt::Display for Symbol {
         }
     }
 }
-
-impl std::default::Default for Symbol {
-    fn default() -> Self {
-        Symbol::Star
-    }
-}
@@ -0,0 +1,7 @@
+#[derive(Debug, PartialEq, Clone)]
+pub enum ValenceError {
+    /// Valence exceeds every valence allowed for the element and charge.
+    Supervalent,
+    /// Charge leaves the element without any allowed valence.
+    ImpossibleCharge,
+}
@@ -0,0 +1,273 @@
+use super::{Element, ValenceError};
+
+/// Assigns allowed valences to elements, taking formal charge into account.
+pub trait ValenceModel {
+    /// Returns the valences allowed for element bearing charge, in ascending
+    /// order. An empty list means the model places no constraint on the
+    /// element.
+    fn valences(
+        &self,
+        element: &Element,
+        charge: i8,
+    ) -> Result<Vec<u8>, ValenceError>;
+
+    /// Returns the difference between the lowest allowed valence not less
+    /// than valence and valence itself.
+    fn subvalence(
+        &self,
+        element: &Element,
+        charge: i8,
+        valence: u8,
+    ) -> Result<u8, ValenceError> {
+        let valences = self.valences(element, charge)?;
+
+        if valences.is_empty() {
+            return Ok(0);
+        }
+
+        for allowed in valences {
+            if allowed >= valence {
+                return Ok(allowed - valence);
+            }
+        }
+
+        Err(ValenceError::Supervalent)
+    }
+}
+
+/// Valence model covering the main-group elements.
+///
+/// A charged atom is treated as isoelectronic with the neutral atom whose
+/// valence shell holds the same number of electrons, so `[N+]` behaves like
+/// carbon and `[O-]` like fluorine. Below four valence electrons the valence
+/// equals the electron count. From four electrons on, the lowest valence
+/// completes the octet, and elements of period three or higher may expand it
+/// in steps of two. Neutral atoms also accept the default valences of the
+/// working paper.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct DefaultValenceModel;
+
+impl ValenceModel for DefaultValenceModel {
+    fn valences(
+        &self,
+        element: &Element,
+        charge: i8,
+    ) -> Result<Vec<u8>, ValenceError> {
+        let period = element.period();
+        let electrons = match element.valence_electrons() {
+            Some(electrons) => electrons as i8 - charge,
+            None => return Ok(Vec::new()),
+        };
+        let mut result = if period == 1 {
+            match electrons {
+                0 | 2 => vec![0],
+                1 => vec![1],
+                _ => return Err(ValenceError::ImpossibleCharge),
+            }
+        } else {
+            match electrons {
+                0..=4 => vec![electrons as u8],
+                5..=8 => {
+                    let lowest = 8 - electrons as u8;
+
+                    if period == 2 {
+                        vec![lowest]
+                    } else {
+                        (lowest..=electrons as u8).step_by(2).collect()
+                    }
+                }
+                _ => return Err(ValenceError::ImpossibleCharge),
+            }
+        };
+
+        if charge == 0 {
+            result.extend(element.default_valences());
+            result.sort_unstable();
+            result.dedup();
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod valences {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn transition_metal() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::Fe, 0), Ok(vec![]))
+    }
+
+    #[test]
+    fn hydrogen() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::H, 0), Ok(vec![1]))
+    }
+
+    #[test]
+    fn hydride() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::H, -1), Ok(vec![0]))
+    }
+
+    #[test]
+    fn hydrogen_plus2() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(
+            model.valences(&Element::H, 2),
+            Err(ValenceError::ImpossibleCharge)
+        )
+    }
+
+    #[test]
+    fn carbon() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::C, 0), Ok(vec![4]))
+    }
+
+    #[test]
+    fn carbocation() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::C, 1), Ok(vec![3]))
+    }
+
+    #[test]
+    fn carbanion() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::C, -1), Ok(vec![3]))
+    }
+
+    #[test]
+    fn nitrogen() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::N, 0), Ok(vec![3, 5]))
+    }
+
+    #[test]
+    fn ammonium() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::N, 1), Ok(vec![4]))
+    }
+
+    #[test]
+    fn oxide() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::O, -1), Ok(vec![1]))
+    }
+
+    #[test]
+    fn oxygen_minus3() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(
+            model.valences(&Element::O, -3),
+            Err(ValenceError::ImpossibleCharge)
+        )
+    }
+
+    #[test]
+    fn boronate() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::B, -1), Ok(vec![4]))
+    }
+
+    #[test]
+    fn sulfonium() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::S, 1), Ok(vec![3, 5]))
+    }
+
+    #[test]
+    fn chlorine() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::Cl, 0), Ok(vec![1, 3, 5, 7]))
+    }
+
+    #[test]
+    fn sodium_cation() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::Na, 1), Ok(vec![0]))
+    }
+
+    #[test]
+    fn selenium() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::Se, 0), Ok(vec![2, 4, 6]))
+    }
+
+    #[test]
+    fn xenon() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.valences(&Element::Xe, 0), Ok(vec![0, 2, 4, 6, 8]))
+    }
+}
+
+#[cfg(test)]
+mod subvalence {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn unconstrained() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.subvalence(&Element::Fe, 0, 6), Ok(0))
+    }
+
+    #[test]
+    fn ammonium_subvalent() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.subvalence(&Element::N, 1, 3), Ok(1))
+    }
+
+    #[test]
+    fn ammonium_homovalent() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.subvalence(&Element::N, 1, 4), Ok(0))
+    }
+
+    #[test]
+    fn ammonium_supervalent() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(
+            model.subvalence(&Element::N, 1, 5),
+            Err(ValenceError::Supervalent)
+        )
+    }
+
+    #[test]
+    fn oxide_homovalent() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.subvalence(&Element::O, -1, 1), Ok(0))
+    }
+
+    #[test]
+    fn sulfur_second() {
+        let model = DefaultValenceModel;
+
+        assert_eq!(model.subvalence(&Element::S, 0, 3), Ok(1))
+    }
+}
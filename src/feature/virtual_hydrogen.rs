@@ -1,8 +1,9 @@
-use std::{default, fmt};
+use std::fmt;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Default)]
 pub enum VirtualHydrogen {
     H,
+    #[default]
     H1,
     H2,
     H3,
@@ -31,12 +32,6 @@ impl VirtualHydrogen {
     }
 }
 
-impl default::Default for VirtualHydrogen {
-    fn default() -> Self {
-        VirtualHydrogen::H1
-    }
-}
-
 impl From<&VirtualHydrogen> for u8 {
     fn from(value: &VirtualHydrogen) -> Self {
         match value {
@@ -0,0 +1,157 @@
+/// A fixed-length vector of bits.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct BitVec {
+    len: usize,
+    words: Vec<u64>,
+}
+
+impl BitVec {
+    /// Returns a vector of len bits, all unset.
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    /// Returns a vector of len bits from words as returned by words. Bits
+    /// past len are cleared. Panics unless there are exactly enough words.
+    pub fn from_words(len: usize, mut words: Vec<u64>) -> Self {
+        assert_eq!(words.len(), len.div_ceil(64), "word count");
+
+        if !len.is_multiple_of(64) {
+            if let Some(last) = words.last_mut() {
+                *last &= (1 << (len % 64)) - 1
+            }
+        }
+
+        Self { len, words }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bit at index, which must be less than len.
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "index out of bounds");
+
+        self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Sets the bit at index, which must be less than len.
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len, "index out of bounds");
+
+        if value {
+            self.words[index / 64] |= 1 << (index % 64)
+        } else {
+            self.words[index / 64] &= !(1 << (index % 64))
+        }
+    }
+
+    /// Returns the number of set bits.
+    pub fn count_ones(&self) -> u32 {
+        self.words.iter().map(|word| word.count_ones()).sum()
+    }
+
+    /// Returns the number of bits set in both self and other. Bits past the
+    /// shorter length are ignored.
+    pub fn and_count(&self, other: &Self) -> u32 {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .map(|(first, second)| (first & second).count_ones())
+            .sum()
+    }
+
+    /// Returns the indexes of set bits in ascending order.
+    pub fn ones(&self) -> Vec<usize> {
+        (0..self.len).filter(|index| self.get(*index)).collect()
+    }
+
+    /// Returns the bits in groups of 64, lowest index in the least
+    /// significant bit.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new() {
+        let bits = BitVec::new(70);
+
+        assert_eq!((bits.len(), bits.count_ones()), (70, 0))
+    }
+
+    #[test]
+    fn set() {
+        let mut bits = BitVec::new(70);
+
+        bits.set(3, true);
+        bits.set(69, true);
+
+        assert_eq!(bits.ones(), vec![3, 69])
+    }
+
+    #[test]
+    fn unset() {
+        let mut bits = BitVec::new(70);
+
+        bits.set(3, true);
+        bits.set(3, false);
+
+        assert_eq!(bits.get(3), false)
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn out_of_bounds() {
+        BitVec::new(70).get(70);
+    }
+
+    #[test]
+    fn and_count() {
+        let mut first = BitVec::new(128);
+        let mut second = BitVec::new(128);
+
+        first.set(1, true);
+        first.set(100, true);
+        second.set(100, true);
+        second.set(101, true);
+
+        assert_eq!(first.and_count(&second), 1)
+    }
+
+    #[test]
+    fn words() {
+        let mut bits = BitVec::new(65);
+
+        bits.set(0, true);
+        bits.set(64, true);
+
+        assert_eq!(bits.words(), &[1, 1])
+    }
+
+    #[test]
+    fn from_words() {
+        let bits = BitVec::from_words(65, vec![1, 3]);
+
+        assert_eq!(bits.ones(), vec![0, 64])
+    }
+
+    #[test]
+    #[should_panic(expected = "word count")]
+    fn from_words_short() {
+        BitVec::from_words(65, vec![1]);
+    }
+}
@@ -0,0 +1,52 @@
+use std::collections::BTreeMap;
+
+use super::BitVec;
+
+/// Counts of unfolded feature identifiers.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Default)]
+pub struct CountVec {
+    pub counts: BTreeMap<u32, u32>,
+}
+
+impl CountVec {
+    /// Returns the count of identifier, 0 if absent.
+    pub fn get(&self, identifier: u32) -> u32 {
+        self.counts.get(&identifier).copied().unwrap_or(0)
+    }
+
+    /// Returns a vector of len bits, which must not be 0, with each
+    /// identifier setting the bit at identifier modulo len.
+    pub fn fold(&self, len: usize) -> BitVec {
+        let mut result = BitVec::new(len);
+
+        for identifier in self.counts.keys() {
+            result.set(*identifier as usize % len, true)
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn get() {
+        let counts = CountVec {
+            counts: BTreeMap::from([(7, 2)]),
+        };
+
+        assert_eq!((counts.get(7), counts.get(8)), (2, 0))
+    }
+
+    #[test]
+    fn fold() {
+        let counts = CountVec {
+            counts: BTreeMap::from([(3, 1), (11, 2), (12, 1)]),
+        };
+
+        assert_eq!(counts.fold(8).ones(), vec![3, 4])
+    }
+}
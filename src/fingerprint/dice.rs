@@ -0,0 +1,40 @@
+use super::Features;
+
+/// Returns the Dice similarity of first and second: twice the common
+/// features over the sum of features. Two empty fingerprints score 0.
+pub fn dice<T: Features>(first: &T, second: &T) -> f64 {
+    let total = first.total() + second.total();
+
+    if total == 0 {
+        0.
+    } else {
+        2. * first.common(second) as f64 / total as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::BitVec;
+    use pretty_assertions::assert_eq;
+
+    fn bits(ones: &[usize]) -> BitVec {
+        let mut result = BitVec::new(16);
+
+        for one in ones {
+            result.set(*one, true)
+        }
+
+        result
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(dice(&bits(&[]), &bits(&[])), 0.)
+    }
+
+    #[test]
+    fn partial() {
+        assert_eq!(dice(&bits(&[1, 2, 3]), &bits(&[2, 3, 4, 5, 6])), 0.5)
+    }
+}
@@ -0,0 +1,54 @@
+use super::{BitVec, CountVec};
+
+/// A fingerprint that can be compared by tanimoto, dice, and tversky.
+pub trait Features {
+    /// Returns the number of features.
+    fn total(&self) -> u32;
+
+    /// Returns the number of features shared with other.
+    fn common(&self, other: &Self) -> u32;
+}
+
+impl Features for BitVec {
+    fn total(&self) -> u32 {
+        self.count_ones()
+    }
+
+    fn common(&self, other: &Self) -> u32 {
+        self.and_count(other)
+    }
+}
+
+/// Counts are features, so an identifier found twice in both shares two.
+impl Features for CountVec {
+    fn total(&self) -> u32 {
+        self.counts.values().sum()
+    }
+
+    fn common(&self, other: &Self) -> u32 {
+        self.counts
+            .iter()
+            .map(|(identifier, count)| *count.min(&other.get(*identifier)))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn counts() {
+        let first = CountVec {
+            counts: BTreeMap::from([(1, 2), (2, 1)]),
+        };
+        let second = CountVec {
+            counts: BTreeMap::from([(1, 3), (3, 1)]),
+        };
+
+        assert_eq!((first.total(), first.common(&second)), (3, 2))
+    }
+}
@@ -0,0 +1,1064 @@
+use super::MaccsKey;
+
+/// The 166 public MACCS keys, in order. Queries follow the SMARTS of the
+/// widely used open-source implementation, with recursive atoms split into
+/// alternatives.
+///
+/// Three keys have no query. ISOTOPE counts atoms with an isotope,
+/// Aromatic ring > 1 counts independent cycles of aromatic bonds, and
+/// Fragments counts connected components.
+pub const MACCS_DEFINITIONS: [MaccsKey; 166] = [
+    MaccsKey {
+        number: 1,
+        name: "ISOTOPE",
+        queries: &[],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 2,
+        name: "Atomic number > 103",
+        queries: &["[#104]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 3,
+        name: "Group IVA, VA, VIA rows 4-6",
+        queries: &["[#32,#33,#34,#50,#51,#52,#82,#83,#84]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 4,
+        name: "Actinide",
+        queries: &["[Ac,Th,Pa,U,Np,Pu,Am,Cm,Bk,Cf,Es,Fm,Md,No,Lr]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 5,
+        name: "Group IIIB, IVB",
+        queries: &["[Sc,Ti,Y,Zr,Hf]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 6,
+        name: "Lanthanide",
+        queries: &["[La,Ce,Pr,Nd,Pm,Sm,Eu,Gd,Tb,Dy,Ho,Er,Tm,Yb,Lu]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 7,
+        name: "Group VB, VIB, VIIB",
+        queries: &["[V,Cr,Mn,Nb,Mo,Tc,Ta,W,Re]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 8,
+        name: "QAAA@1",
+        queries: &["[!#6;!#1]1~*~*~*~1"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 9,
+        name: "Group VIII",
+        queries: &["[Fe,Co,Ni,Ru,Rh,Pd,Os,Ir,Pt]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 10,
+        name: "Group IIA",
+        queries: &["[Be,Mg,Ca,Sr,Ba,Ra]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 11,
+        name: "4M ring",
+        queries: &["*1~*~*~*~1"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 12,
+        name: "Group IB, IIB",
+        queries: &["[Cu,Zn,Ag,Cd,Au,Hg]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 13,
+        name: "ON(C)C",
+        queries: &["[#8]~[#7](~[#6])~[#6]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 14,
+        name: "S-S",
+        queries: &["[#16]-[#16]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 15,
+        name: "OC(O)O",
+        queries: &["[#8]~[#6](~[#8])~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 16,
+        name: "QAA@1",
+        queries: &["[!#6;!#1]1~*~*~1"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 17,
+        name: "CTC",
+        queries: &["[#6]#[#6]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 18,
+        name: "Group IIIA",
+        queries: &["[#5,#13,#31,#49,#81]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 19,
+        name: "7M ring",
+        queries: &["*1~*~*~*~*~*~*~1"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 20,
+        name: "SI",
+        queries: &["[#14]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 21,
+        name: "C=C(Q)Q",
+        queries: &["[#6]=[#6](~[!#6;!#1])~[!#6;!#1]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 22,
+        name: "3M ring",
+        queries: &["*1~*~*~1"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 23,
+        name: "NC(O)O",
+        queries: &["[#7]~[#6](~[#8])~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 24,
+        name: "N-O",
+        queries: &["[#7]-[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 25,
+        name: "NC(N)N",
+        queries: &["[#7]~[#6](~[#7])~[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 26,
+        name: "C$=C($A)$A",
+        queries: &["[#6]=;@[#6](@*)@*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 27,
+        name: "I",
+        queries: &["I"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 28,
+        name: "QCH2Q",
+        queries: &["[!#6;!#1]~[CH2]~[!#6;!#1]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 29,
+        name: "P",
+        queries: &["[#15]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 30,
+        name: "CQ(C)(C)A",
+        queries: &["[#6]~[!#6;!#1](~[#6])(~[#6])~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 31,
+        name: "QX",
+        queries: &["[!#6;!#1]~[F,Cl,Br,I]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 32,
+        name: "CSN",
+        queries: &["[#6]~[#16]~[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 33,
+        name: "NS",
+        queries: &["[#7]~[#16]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 34,
+        name: "CH2=A",
+        queries: &["[CH2]=*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 35,
+        name: "Group IA",
+        queries: &["[Li,Na,K,Rb,Cs,Fr]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 36,
+        name: "S heterocycle",
+        queries: &["[#16R]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 37,
+        name: "NC(O)N",
+        queries: &["[#7]~[#6](~[#8])~[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 38,
+        name: "NC(C)N",
+        queries: &["[#7]~[#6](~[#6])~[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 39,
+        name: "OS(O)O",
+        queries: &["[#8]~[#16](~[#8])~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 40,
+        name: "S-O",
+        queries: &["[#16]-[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 41,
+        name: "CTN",
+        queries: &["[#6]#[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 42,
+        name: "F",
+        queries: &["F"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 43,
+        name: "QHAQH",
+        queries: &["[!#6;!#1;!H0]~*~[!#6;!#1;!H0]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 44,
+        name: "OTHER",
+        queries: &["[!#1;!#6;!#7;!#8;!#9;!#14;!#15;!#16;!#17;!#35;!#53]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 45,
+        name: "C=CN",
+        queries: &["[#6]=[#6]~[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 46,
+        name: "BR",
+        queries: &["Br"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 47,
+        name: "SAN",
+        queries: &["[#16]~*~[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 48,
+        name: "OQ(O)O",
+        queries: &["[#8]~[!#6;!#1](~[#8])~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 49,
+        name: "CHARGE",
+        queries: &["[!+0]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 50,
+        name: "C=C(C)C",
+        queries: &["[#6]=[#6](~[#6])~[#6]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 51,
+        name: "CSO",
+        queries: &["[#6]~[#16]~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 52,
+        name: "NN",
+        queries: &["[#7]~[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 53,
+        name: "QHAAAQH",
+        queries: &["[!#6;!#1;!H0]~*~*~*~[!#6;!#1;!H0]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 54,
+        name: "QHAAQH",
+        queries: &["[!#6;!#1;!H0]~*~*~[!#6;!#1;!H0]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 55,
+        name: "OSO",
+        queries: &["[#8]~[#16]~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 56,
+        name: "ON(O)C",
+        queries: &["[#8]~[#7](~[#8])~[#6]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 57,
+        name: "O heterocycle",
+        queries: &["[#8R]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 58,
+        name: "QSQ",
+        queries: &["[!#6;!#1]~[#16]~[!#6;!#1]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 59,
+        name: "Snot%A%A",
+        queries: &["[#16]!:*:*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 60,
+        name: "S=O",
+        queries: &["[#16]=[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 61,
+        name: "AS(A)A",
+        queries: &["*~[#16](~*)~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 62,
+        name: "A$!A$A",
+        queries: &["*@*!@*@*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 63,
+        name: "N=O",
+        queries: &["[#7]=[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 64,
+        name: "A$A!S",
+        queries: &["*@*!@[#16]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 65,
+        name: "C%N",
+        queries: &["c:n"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 66,
+        name: "CC(C)(C)A",
+        queries: &["[#6]~[#6](~[#6])(~[#6])~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 67,
+        name: "QS",
+        queries: &["[!#6;!#1]~[#16]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 68,
+        name: "QHQH",
+        queries: &["[!#6;!#1;!H0]~[!#6;!#1;!H0]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 69,
+        name: "QQH",
+        queries: &["[!#6;!#1]~[!#6;!#1;!H0]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 70,
+        name: "QNQ",
+        queries: &["[!#6;!#1]~[#7]~[!#6;!#1]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 71,
+        name: "NO",
+        queries: &["[#7]~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 72,
+        name: "OAAO",
+        queries: &["[#8]~*~*~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 73,
+        name: "S=A",
+        queries: &["[#16]=*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 74,
+        name: "CH3ACH3",
+        queries: &["[CH3]~*~[CH3]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 75,
+        name: "A!N$A",
+        queries: &["*!@[#7]@*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 76,
+        name: "C=C(A)A",
+        queries: &["[#6]=[#6](~*)~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 77,
+        name: "NAN",
+        queries: &["[#7]~*~[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 78,
+        name: "C=N",
+        queries: &["[#6]=[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 79,
+        name: "NAAN",
+        queries: &["[#7]~*~*~[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 80,
+        name: "NAAAN",
+        queries: &["[#7]~*~*~*~[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 81,
+        name: "SA(A)A",
+        queries: &["[#16]~*(~*)~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 82,
+        name: "ACH2QH",
+        queries: &["*~[CH2]~[!#6;!#1;!H0]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 83,
+        name: "QAAAA@1",
+        queries: &["[!#6;!#1]1~*~*~*~*~1"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 84,
+        name: "NH2",
+        queries: &["[NH2]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 85,
+        name: "CN(C)C",
+        queries: &["[#6]~[#7](~[#6])~[#6]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 86,
+        name: "CH2QCH2",
+        queries: &["[C;H2,H3][!#6;!#1][C;H2,H3]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 87,
+        name: "X!A$A",
+        queries: &["[F,Cl,Br,I]!@*@*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 88,
+        name: "S",
+        queries: &["[#16]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 89,
+        name: "OAAAO",
+        queries: &["[#8]~*~*~*~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 90,
+        name: "QHAACH2A",
+        queries: &[
+            "[!#6;!#1;!H0]~*~*~[CH2]~*",
+            "[!#6;!#1;!H0;R]1@[R]@[R]@[CH2;R]@1",
+            "[!#6;!#1;!H0]~[R]1@[R]@[CH2;R]@1",
+        ],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 91,
+        name: "QHAAACH2A",
+        queries: &[
+            "[!#6;!#1;!H0]~*~*~*~[CH2]~*",
+            "[!#6;!#1;!H0;R]1@[R]@[R]@[R]@[CH2;R]@1",
+            "[!#6;!#1;!H0]~[R]1@[R]@[R]@[CH2;R]@1",
+            "[!#6;!#1;!H0]~*~[R]1@[R]@[CH2;R]@1",
+        ],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 92,
+        name: "OC(N)C",
+        queries: &["[#8]~[#6](~[#7])~[#6]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 93,
+        name: "QCH3",
+        queries: &["[!#6;!#1]~[CH3]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 94,
+        name: "QN",
+        queries: &["[!#6;!#1]~[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 95,
+        name: "NAAO",
+        queries: &["[#7]~*~*~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 96,
+        name: "5M ring",
+        queries: &["*1~*~*~*~*~1"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 97,
+        name: "NAAAO",
+        queries: &["[#7]~*~*~*~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 98,
+        name: "QAAAAA@1",
+        queries: &["[!#6;!#1]1~*~*~*~*~*~1"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 99,
+        name: "C=C",
+        queries: &["[#6]=[#6]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 100,
+        name: "ACH2N",
+        queries: &["*~[CH2]~[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 101,
+        name: "8M ring or larger",
+        queries: &[
+            "[R]1@[R]@[R]@[R]@[R]@[R]@[R]@[R]@1",
+            "[R]1@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@1",
+            "[R]1@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@1",
+            "[R]1@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@1",
+            "[R]1@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@1",
+            "[R]1@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@1",
+            "[R]1@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@[R]@1",
+        ],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 102,
+        name: "QO",
+        queries: &["[!#6;!#1]~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 103,
+        name: "CL",
+        queries: &["Cl"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 104,
+        name: "QHACH2A",
+        queries: &["[!#6;!#1;!H0]~*~[CH2]~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 105,
+        name: "A$A($A)$A",
+        queries: &["*@*(@*)@*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 106,
+        name: "QA(Q)Q",
+        queries: &["[!#6;!#1]~*(~[!#6;!#1])~[!#6;!#1]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 107,
+        name: "XA(A)A",
+        queries: &["[F,Cl,Br,I]~*(~*)~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 108,
+        name: "CH3AAACH2A",
+        queries: &["[CH3]~*~*~*~[CH2]~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 109,
+        name: "ACH2O",
+        queries: &["*~[CH2]~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 110,
+        name: "NCO",
+        queries: &["[#7]~[#6]~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 111,
+        name: "NACH2A",
+        queries: &["[#7]~*~[CH2]~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 112,
+        name: "AA(A)(A)A",
+        queries: &["*~*(~*)(~*)~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 113,
+        name: "Onot%A%A",
+        queries: &["[#8]!:*:*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 114,
+        name: "CH3CH2A",
+        queries: &["[CH3]~[CH2]~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 115,
+        name: "CH3ACH2A",
+        queries: &["[CH3]~*~[CH2]~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 116,
+        name: "CH3AACH2A",
+        queries: &["[CH3]~*~*~[CH2]~*", "[CH3]~*1~*~[CH2]@1"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 117,
+        name: "NAO",
+        queries: &["[#7]~*~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 118,
+        name: "ACH2CH2A > 1",
+        queries: &["*~[CH2]~[CH2]~*", "*1~[CH2]~[CH2]~1"],
+        more_than: 1,
+    },
+    MaccsKey {
+        number: 119,
+        name: "N=A",
+        queries: &["[#7]=*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 120,
+        name: "Heterocyclic atom > 1",
+        queries: &["[!#6;R]"],
+        more_than: 1,
+    },
+    MaccsKey {
+        number: 121,
+        name: "N heterocycle",
+        queries: &["[#7;R]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 122,
+        name: "AN(A)A",
+        queries: &["*~[#7](~*)~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 123,
+        name: "OCO",
+        queries: &["[#8]~[#6]~[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 124,
+        name: "QQ",
+        queries: &["[!#6;!#1]~[!#6;!#1]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 125,
+        name: "Aromatic ring > 1",
+        queries: &[],
+        more_than: 1,
+    },
+    MaccsKey {
+        number: 126,
+        name: "A!O!A",
+        queries: &["*!@[#8]!@*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 127,
+        name: "A$A!O > 1",
+        queries: &["*@*!@[#8]"],
+        more_than: 1,
+    },
+    MaccsKey {
+        number: 128,
+        name: "ACH2AAACH2A",
+        queries: &[
+            "*~[CH2]~*~*~*~[CH2]~*",
+            "[R]1@[CH2;R]@[R]@[R]@[R]@[CH2;R]@1",
+            "*~[CH2]~[R]1@[R]@[R]@[CH2;R]@1",
+            "*~[CH2]~*~[R]1@[R]@[CH2;R]@1",
+        ],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 129,
+        name: "ACH2AACH2A",
+        queries: &[
+            "*~[CH2]~*~*~[CH2]~*",
+            "[R]1@[CH2]@[R]@[R]@[CH2;R]@1",
+            "*~[CH2]~[R]1@[R]@[CH2;R]@1",
+        ],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 130,
+        name: "QQ > 1",
+        queries: &["[!#6;!#1]~[!#6;!#1]"],
+        more_than: 1,
+    },
+    MaccsKey {
+        number: 131,
+        name: "QH > 1",
+        queries: &["[!#6;!#1;!H0]"],
+        more_than: 1,
+    },
+    MaccsKey {
+        number: 132,
+        name: "OACH2A",
+        queries: &["[#8]~*~[CH2]~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 133,
+        name: "A$A!N",
+        queries: &["*@*!@[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 134,
+        name: "X",
+        queries: &["[F,Cl,Br,I]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 135,
+        name: "Nnot%A%A",
+        queries: &["[#7]!:*:*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 136,
+        name: "O=A > 1",
+        queries: &["[#8]=*"],
+        more_than: 1,
+    },
+    MaccsKey {
+        number: 137,
+        name: "Heterocycle",
+        queries: &["[!C;!c;R]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 138,
+        name: "QCH2A > 1",
+        queries: &["[!#6;!#1]~[CH2]~*"],
+        more_than: 1,
+    },
+    MaccsKey {
+        number: 139,
+        name: "OH",
+        queries: &["[O;!H0]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 140,
+        name: "O > 3",
+        queries: &["[#8]"],
+        more_than: 3,
+    },
+    MaccsKey {
+        number: 141,
+        name: "CH3 > 2",
+        queries: &["[CH3]"],
+        more_than: 2,
+    },
+    MaccsKey {
+        number: 142,
+        name: "N > 1",
+        queries: &["[#7]"],
+        more_than: 1,
+    },
+    MaccsKey {
+        number: 143,
+        name: "A$A!O",
+        queries: &["*@*!@[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 144,
+        name: "Anot%A%Anot%A",
+        queries: &["*!:*:*!:*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 145,
+        name: "6M ring > 1",
+        queries: &["*1~*~*~*~*~*~1"],
+        more_than: 1,
+    },
+    MaccsKey {
+        number: 146,
+        name: "O > 2",
+        queries: &["[#8]"],
+        more_than: 2,
+    },
+    MaccsKey {
+        number: 147,
+        name: "ACH2CH2A",
+        queries: &["*~[CH2]~[CH2]~*", "[R]1@[CH2;R]@[CH2;R]@1"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 148,
+        name: "AQ(A)A",
+        queries: &["*~[!#6;!#1](~*)~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 149,
+        name: "CH3 > 1",
+        queries: &["[C;H3,H4]"],
+        more_than: 1,
+    },
+    MaccsKey {
+        number: 150,
+        name: "A!A$A!A",
+        queries: &["*!@*@*!@*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 151,
+        name: "NH",
+        queries: &["[#7;!H0]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 152,
+        name: "OC(C)C",
+        queries: &["[#8]~[#6](~[#6])~[#6]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 153,
+        name: "QCH2A",
+        queries: &["[!#6;!#1]~[CH2]~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 154,
+        name: "C=O",
+        queries: &["[#6]=[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 155,
+        name: "A!CH2!A",
+        queries: &["*!@[CH2]!@*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 156,
+        name: "NA(A)A",
+        queries: &["[#7]~*(~*)~*"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 157,
+        name: "C-O",
+        queries: &["[#6]-[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 158,
+        name: "C-N",
+        queries: &["[#6]-[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 159,
+        name: "O > 1",
+        queries: &["[#8]"],
+        more_than: 1,
+    },
+    MaccsKey {
+        number: 160,
+        name: "CH3",
+        queries: &["[C;H3,H4]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 161,
+        name: "N",
+        queries: &["[#7]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 162,
+        name: "Aromatic",
+        queries: &["[a]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 163,
+        name: "6M ring",
+        queries: &["*1~*~*~*~*~*~1"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 164,
+        name: "O",
+        queries: &["[#8]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 165,
+        name: "Ring",
+        queries: &["[R]"],
+        more_than: 0,
+    },
+    MaccsKey {
+        number: 166,
+        name: "Fragments",
+        queries: &[],
+        more_than: 1,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::Query;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn numbers() {
+        let numbers = MACCS_DEFINITIONS
+            .iter()
+            .map(|key| key.number)
+            .collect::<Vec<_>>();
+
+        assert_eq!(numbers, (1..=166).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn queries() {
+        for key in MACCS_DEFINITIONS.iter() {
+            for query in key.queries {
+                assert_eq!(
+                    query.parse::<Query>().map(|_| ()),
+                    Ok(()),
+                    "{}",
+                    query
+                )
+            }
+        }
+    }
+}
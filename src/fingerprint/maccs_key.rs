@@ -0,0 +1,15 @@
+/// The definition of a MACCS structural key.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MaccsKey {
+    /// From 1 to 166, also the index of the key's bit.
+    pub number: usize,
+    /// The name given in the public key list.
+    pub name: &'static str,
+    /// Alternative queries, empty for keys computed without one. With one
+    /// query, the key counts unique matches. With several, written in
+    /// SMARTS as a recursive atom, it counts distinct atoms matched by the
+    /// first atom of any of them.
+    pub queries: &'static [&'static str],
+    /// The key is set when the count exceeds this.
+    pub more_than: usize,
+}
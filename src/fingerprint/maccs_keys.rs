@@ -0,0 +1,145 @@
+use std::{collections::HashSet, sync::OnceLock};
+
+use super::{BitVec, MaccsKey, MACCS_DEFINITIONS};
+use crate::{
+    feature::AtomKind,
+    graph::{components, Atom},
+    query::{matches, Query},
+    search::{BondClass, MatchOptions},
+};
+
+/// Returns the 166 MACCS keys of atoms as a BitVec of length 167, with key
+/// n at index n and index 0 unset, following MACCS_DEFINITIONS.
+pub fn maccs_keys(atoms: &[Atom]) -> BitVec {
+    let mut result = BitVec::new(MACCS_DEFINITIONS.len() + 1);
+
+    for (key, queries) in MACCS_DEFINITIONS.iter().zip(queries()) {
+        let count = match key.number {
+            1 => atoms.iter().filter(|atom| has_isotope(atom)).count(),
+            125 => aromatic_rings(atoms),
+            166 => components(atoms, |_, _| true).1,
+            _ => count(key, queries, atoms),
+        };
+
+        result.set(key.number, count > key.more_than)
+    }
+
+    result
+}
+
+fn queries() -> &'static [Vec<Query>] {
+    static QUERIES: OnceLock<Vec<Vec<Query>>> = OnceLock::new();
+
+    QUERIES.get_or_init(|| {
+        MACCS_DEFINITIONS
+            .iter()
+            .map(|key| {
+                key.queries
+                    .iter()
+                    .map(|query| query.parse().expect("MACCS query"))
+                    .collect()
+            })
+            .collect()
+    })
+}
+
+fn count(key: &MaccsKey, queries: &[Query], atoms: &[Atom]) -> usize {
+    match queries {
+        [query] => {
+            let options = MatchOptions {
+                unique: true,
+                max_matches: Some(key.more_than + 1),
+                stereo: false,
+            };
+
+            matches(query, atoms, &options).len()
+        }
+        _ => {
+            let options = MatchOptions {
+                unique: false,
+                max_matches: None,
+                stereo: false,
+            };
+
+            queries
+                .iter()
+                .flat_map(|query| matches(query, atoms, &options))
+                .map(|mapping| mapping[0])
+                .collect::<HashSet<_>>()
+                .len()
+        }
+    }
+}
+
+fn has_isotope(atom: &Atom) -> bool {
+    match &atom.kind {
+        AtomKind::Bracket(bracket) => bracket.isotope.is_some(),
+        _ => false,
+    }
+}
+
+/// Returns the number of independent cycles formed by aromatic bonds.
+fn aromatic_rings(atoms: &[Atom]) -> usize {
+    let (bonds, components) = components(atoms, |sid, bond| {
+        BondClass::new(atoms, sid, bond) == BondClass::Aromatic
+    });
+
+    bonds + components - atoms.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn keys(string: &str) -> Vec<usize> {
+        maccs_keys(&atoms_of(string)).ones()
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(maccs_keys(&[]).len(), 167)
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(
+            keys("CCO"),
+            vec![82, 109, 114, 139, 153, 155, 157, 160, 164]
+        )
+    }
+
+    #[test]
+    fn acetic_acid() {
+        assert_eq!(keys("CC(=O)O"), vec![123, 139, 154, 157, 159, 160, 164])
+    }
+
+    #[test]
+    fn benzene() {
+        assert_eq!(keys("c1ccccc1"), vec![162, 163, 165])
+    }
+
+    #[test]
+    fn pyridine() {
+        assert_eq!(keys("c1ccncc1"), vec![65, 98, 121, 137, 161, 162, 163, 165])
+    }
+
+    #[test]
+    fn naphthalene() {
+        assert_eq!(
+            keys("c1ccc2ccccc2c1"),
+            vec![101, 105, 125, 145, 162, 163, 165]
+        )
+    }
+
+    #[test]
+    fn sodium_chloride() {
+        assert_eq!(keys("[Na+].[Cl-]"), vec![35, 44, 49, 103, 134, 166])
+    }
+
+    #[test]
+    fn labeled_methane() {
+        assert_eq!(keys("[13CH4]"), vec![1, 160])
+    }
+}
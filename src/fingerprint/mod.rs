@@ -0,0 +1,28 @@
+mod bit_vec;
+mod count_vec;
+mod dice;
+mod features;
+mod maccs_definitions;
+mod maccs_key;
+mod maccs_keys;
+mod morgan_bits;
+mod morgan_counts;
+mod morgan_identifiers;
+mod morgan_options;
+mod tanimoto;
+mod tversky;
+
+pub use bit_vec::BitVec;
+pub use count_vec::CountVec;
+pub use dice::dice;
+pub use features::Features;
+pub use maccs_definitions::MACCS_DEFINITIONS;
+pub use maccs_key::MaccsKey;
+pub use maccs_keys::maccs_keys;
+pub use morgan_bits::morgan_bits;
+pub use morgan_counts::morgan_counts;
+pub use morgan_options::MorganOptions;
+pub use tanimoto::tanimoto;
+pub use tversky::tversky;
+
+pub(crate) use morgan_identifiers::morgan_identifiers;
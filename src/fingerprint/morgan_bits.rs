@@ -0,0 +1,93 @@
+use super::{morgan_identifiers, BitVec, MorganOptions};
+use crate::graph::Atom;
+
+/// Returns the Morgan (ECFP-style) fingerprint of atoms folded to the
+/// length of options, each identifier setting the bit at identifier modulo
+/// length. Identifiers are those of morgan_counts, so stored fingerprints
+/// stay comparable across releases.
+pub fn morgan_bits(atoms: &[Atom], options: &MorganOptions) -> BitVec {
+    let mut result = BitVec::new(options.len);
+
+    for identifier in morgan_identifiers(atoms, options.radius) {
+        result.set(identifier as usize % options.len, true)
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fingerprint::tanimoto, read::atoms_of};
+    use pretty_assertions::assert_eq;
+
+    fn bits(string: &str, options: &MorganOptions) -> BitVec {
+        morgan_bits(&atoms_of(string), options)
+    }
+
+    #[test]
+    fn len() {
+        let options = MorganOptions {
+            len: 1024,
+            ..Default::default()
+        };
+
+        assert_eq!(bits("CCO", &options).len(), 1024)
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(bits("CCO", &Default::default()).count_ones(), 6)
+    }
+
+    #[test]
+    fn radius() {
+        let options = MorganOptions {
+            radius: 0,
+            ..Default::default()
+        };
+
+        assert_eq!(bits("CCO", &options).count_ones(), 3)
+    }
+
+    #[test]
+    fn identical() {
+        let options = MorganOptions::default();
+
+        assert_eq!(
+            tanimoto(
+                &bits("N[C@@H](C)C(=O)O", &options),
+                &bits("OC(=O)C(C)N", &options)
+            ),
+            1.
+        )
+    }
+
+    #[test]
+    fn similar() {
+        let options = MorganOptions::default();
+        let similarity = tanimoto(
+            &bits("c1ccccc1CO", &options),
+            &bits("c1ccccc1CN", &options),
+        );
+
+        assert!(similarity > 0.4 && similarity < 1.)
+    }
+
+    #[test]
+    fn charge() {
+        let options = MorganOptions::default();
+
+        assert!(bits("CC(=O)O", &options) != bits("CC(=O)[O-]", &options))
+    }
+
+    #[test]
+    fn stable() {
+        let options = MorganOptions::default();
+
+        assert_eq!(
+            bits("CCO", &options).ones(),
+            vec![283, 469, 707, 1207, 1372, 1748]
+        )
+    }
+}
@@ -0,0 +1,43 @@
+use super::{morgan_identifiers, CountVec, MorganOptions};
+use crate::graph::Atom;
+
+/// Returns the Morgan (ECFP-style) fingerprint of atoms as unfolded
+/// identifier counts, up to the radius of options. See morgan_bits for a
+/// folded fingerprint.
+pub fn morgan_counts(atoms: &[Atom], options: &MorganOptions) -> CountVec {
+    let mut result = CountVec::default();
+
+    for identifier in morgan_identifiers(atoms, options.radius) {
+        *result.counts.entry(identifier).or_default() += 1
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fingerprint::tanimoto, read::atoms_of};
+    use pretty_assertions::assert_eq;
+
+    fn counts(string: &str) -> CountVec {
+        morgan_counts(&atoms_of(string), &Default::default())
+    }
+
+    #[test]
+    fn repeated_environment() {
+        let counts = counts("CC(C)C");
+
+        assert_eq!(counts.counts.values().copied().max(), Some(3))
+    }
+
+    #[test]
+    fn self_similarity() {
+        assert_eq!(tanimoto(&counts("c1ccccc1O"), &counts("Oc1ccccc1")), 1.)
+    }
+
+    #[test]
+    fn count_tanimoto() {
+        assert_eq!(tanimoto(&counts("CCCC"), &counts("CCCCC")), 8. / 14.)
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::{BTreeSet, HashSet};
+
+use crate::{
+    graph::{ring_bonds, Atom},
+    hash::Fnv128,
+    search::{AtomKey, BondClass},
+};
+
+/// Returns the identifiers of the distinct circular environments of atoms
+/// up to radius, by radius and then by atom. Environments covering the
+/// same bonds as one already found are skipped, as are environments that
+/// didn't grow since the last radius. At radius 0 every atom counts.
+///
+/// Identifiers are the low 32 bits of Fnv128 hashes and won't change
+/// between releases:
+///
+/// - At radius 0, the hash of the atomic number (0 for Star), charge as a
+///   two's complement byte, mass number as a u16 (0 if none), hydrogen
+///   count, number of bonds, ring membership (0 or 1), and selection (0 or
+///   1).
+/// - At each further radius, the hash of the atom's last identifier as a
+///   u32 followed by the BondClass index and last identifier of each
+///   neighbor, sorted.
+pub fn morgan_identifiers(atoms: &[Atom], radius: usize) -> Vec<u32> {
+    let rings = ring_bonds(atoms);
+    let mut identifiers = atoms
+        .iter()
+        .enumerate()
+        .map(|(index, atom)| initial(atom, rings[index].contains(&true)))
+        .collect::<Vec<_>>();
+    let mut environments = vec![BTreeSet::new(); atoms.len()];
+    let mut seen = HashSet::new();
+    let mut result = identifiers.clone();
+
+    for _ in 0..radius {
+        let mut next = Vec::with_capacity(atoms.len());
+        let mut candidates = Vec::new();
+
+        for (index, atom) in atoms.iter().enumerate() {
+            let mut environment = environments[index].clone();
+            let mut neighbors = Vec::new();
+
+            for bond in &atom.bonds {
+                environment.insert((index.min(bond.tid), index.max(bond.tid)));
+                environment.extend(environments[bond.tid].iter().copied());
+                neighbors.push((
+                    BondClass::new(atoms, index, bond) as u8,
+                    identifiers[bond.tid],
+                ))
+            }
+
+            neighbors.sort_unstable();
+
+            let mut hasher = Fnv128::new();
+
+            hasher.write_u32(identifiers[index]);
+
+            for (class, identifier) in neighbors {
+                hasher.write_u8(class);
+                hasher.write_u32(identifier)
+            }
+
+            let identifier = hasher.finish() as u32;
+
+            if environment != environments[index] {
+                candidates.push((environment.clone(), identifier))
+            }
+
+            next.push((environment, identifier))
+        }
+
+        candidates.sort_unstable();
+
+        for (environment, identifier) in candidates {
+            if seen.insert(environment) {
+                result.push(identifier)
+            }
+        }
+
+        (environments, identifiers) = next.into_iter().unzip();
+    }
+
+    result
+}
+
+fn initial(atom: &Atom, ring: bool) -> u32 {
+    let key = AtomKey::new(atom);
+    let mut hasher = Fnv128::new();
+
+    hasher.write_u8(match &key.element {
+        Some(element) => element.atomic_number(),
+        None => 0,
+    });
+    hasher.write_u8(key.charge as u8);
+    hasher.write_u16(key.isotope.unwrap_or(0));
+    hasher.write_u8(key.hydrogens);
+    hasher.write_u8(atom.bonds.len() as u8);
+    hasher.write_u8(ring as u8);
+    hasher.write_u8(key.selected as u8);
+
+    hasher.finish() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn identifiers(string: &str, radius: usize) -> Vec<u32> {
+        morgan_identifiers(&atoms_of(string), radius)
+    }
+
+    #[test]
+    fn radius_zero() {
+        assert_eq!(identifiers("CCO", 0).len(), 3)
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(identifiers("CCO", 2).len(), 6)
+    }
+
+    #[test]
+    fn methane() {
+        assert_eq!(identifiers("C", 2).len(), 1)
+    }
+
+    #[test]
+    fn order_independent() {
+        let mut first = identifiers("OCC(=O)N", 3);
+        let mut second = identifiers("NC(=O)CO", 3);
+
+        first.sort_unstable();
+        second.sort_unstable();
+
+        assert_eq!(first, second)
+    }
+
+    #[test]
+    fn stable() {
+        assert_eq!(identifiers("C", 0), vec![4_070_987_247])
+    }
+}
@@ -0,0 +1,17 @@
+/// Controls Morgan fingerprints.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MorganOptions {
+    /// Bonds from the center of the largest environment, 2 for ECFP4.
+    pub radius: usize,
+    /// Bits in a folded fingerprint. Must not be 0.
+    pub len: usize,
+}
+
+impl Default for MorganOptions {
+    fn default() -> Self {
+        Self {
+            radius: 2,
+            len: 2048,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use super::Features;
+
+/// Returns the Tanimoto (Jaccard) similarity of first and second: common
+/// features over all features. Two empty fingerprints score 0.
+pub fn tanimoto<T: Features>(first: &T, second: &T) -> f64 {
+    let common = first.common(second);
+    let union = first.total() + second.total() - common;
+
+    if union == 0 {
+        0.
+    } else {
+        common as f64 / union as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::BitVec;
+    use pretty_assertions::assert_eq;
+
+    fn bits(ones: &[usize]) -> BitVec {
+        let mut result = BitVec::new(16);
+
+        for one in ones {
+            result.set(*one, true)
+        }
+
+        result
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(tanimoto(&bits(&[]), &bits(&[])), 0.)
+    }
+
+    #[test]
+    fn identical() {
+        assert_eq!(tanimoto(&bits(&[1, 2]), &bits(&[1, 2])), 1.)
+    }
+
+    #[test]
+    fn partial() {
+        assert_eq!(tanimoto(&bits(&[1, 2, 3]), &bits(&[2, 3, 4])), 0.5)
+    }
+}
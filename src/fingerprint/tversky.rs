@@ -0,0 +1,58 @@
+use super::Features;
+
+/// Returns the Tversky similarity of first to second, weighting features
+/// only in first by alpha and features only in second by beta. With both
+/// weights 1 this is tanimoto, and with both 0.5 dice. Scores 0 when the
+/// denominator is 0.
+pub fn tversky<T: Features>(
+    first: &T,
+    second: &T,
+    alpha: f64,
+    beta: f64,
+) -> f64 {
+    let common = first.common(second) as f64;
+    let denominator = alpha * (first.total() as f64 - common)
+        + beta * (second.total() as f64 - common)
+        + common;
+
+    if denominator == 0. {
+        0.
+    } else {
+        common / denominator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::BitVec;
+    use pretty_assertions::assert_eq;
+
+    fn bits(ones: &[usize]) -> BitVec {
+        let mut result = BitVec::new(16);
+
+        for one in ones {
+            result.set(*one, true)
+        }
+
+        result
+    }
+
+    #[test]
+    fn substructure() {
+        assert_eq!(
+            tversky(&bits(&[1, 2]), &bits(&[1, 2, 3, 4, 5, 6]), 1., 0.),
+            1.
+        )
+    }
+
+    #[test]
+    fn tanimoto() {
+        assert_eq!(tversky(&bits(&[1, 2, 3]), &bits(&[2, 3, 4]), 1., 1.), 0.5)
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(tversky(&bits(&[]), &bits(&[]), 1., 1.), 0.)
+    }
+}
@@ -22,6 +22,12 @@ impl Writer {
     }
 }
 
+impl Default for Writer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Follower for Writer {
     fn root(&mut self, root: &AtomKind) {
         let top = match self.stack.last_mut() {
@@ -0,0 +1,297 @@
+use std::{cmp::Ordering, collections::BTreeMap, fmt, fmt::Write};
+
+use crate::{
+    feature::{AtomKind, Element, Isotope, Symbol},
+    graph::Atom,
+};
+
+/// Elemental composition and net charge of a molecule. Isotope-labelled
+/// atoms are counted apart from unlabelled atoms of the same element, and
+/// Star atoms apart from all elements.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Formula {
+    counts: BTreeMap<(Element, Option<Isotope>), usize>,
+    stars: usize,
+    charge: i32,
+}
+
+impl Formula {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds count atoms of element, labelled with isotope if given.
+    pub fn add(
+        &mut self,
+        element: Element,
+        isotope: Option<Isotope>,
+        count: usize,
+    ) {
+        if count > 0 {
+            *self.counts.entry((element, isotope)).or_insert(0) += count;
+        }
+    }
+
+    pub fn add_stars(&mut self, count: usize) {
+        self.stars += count
+    }
+
+    /// Adds like add, returning None and leaving the formula unchanged if
+    /// the total would overflow.
+    pub(crate) fn checked_add(
+        &mut self,
+        element: Element,
+        isotope: Option<Isotope>,
+        count: usize,
+    ) -> Option<()> {
+        let total = self.counts.get(&(element.clone(), isotope.clone()));
+        let total = total.unwrap_or(&0).checked_add(count)?;
+
+        if total > 0 {
+            self.counts.insert((element, isotope), total);
+        }
+
+        Some(())
+    }
+
+    /// Adds like add_stars, returning None and leaving the formula
+    /// unchanged if the total would overflow.
+    pub(crate) fn checked_add_stars(&mut self, count: usize) -> Option<()> {
+        self.stars = self.stars.checked_add(count)?;
+
+        Some(())
+    }
+
+    pub fn add_charge(&mut self, charge: i32) {
+        self.charge += charge
+    }
+
+    /// Returns the number of atoms of element, labelled or not.
+    pub fn count(&self, element: &Element) -> usize {
+        self.counts
+            .iter()
+            .filter(|((e, _), _)| e == element)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// Returns the number of atoms of element carrying exactly isotope,
+    /// where None selects unlabelled atoms.
+    pub fn isotope_count(
+        &self,
+        element: &Element,
+        isotope: Option<&Isotope>,
+    ) -> usize {
+        match self.counts.get(&(element.clone(), isotope.cloned())) {
+            Some(count) => *count,
+            None => 0,
+        }
+    }
+
+    pub fn stars(&self) -> usize {
+        self.stars
+    }
+
+    pub fn charge(&self) -> i32 {
+        self.charge
+    }
+
+    /// Returns element, isotope, and count triples in Hill order: carbon
+    /// then hydrogen if carbon is present, and all other elements by symbol.
+    /// Unlabelled atoms precede labelled atoms of the same element.
+    pub fn entries(&self) -> Vec<(&Element, Option<&Isotope>, usize)> {
+        let carbon = self.count(&Element::C) > 0;
+        let mut result = self
+            .counts
+            .iter()
+            .map(|((element, isotope), count)| {
+                (element, isotope.as_ref(), *count)
+            })
+            .collect::<Vec<_>>();
+
+        result.sort_by(|(a, a_isotope, _), (b, b_isotope, _)| {
+            hill_rank(a, carbon)
+                .cmp(&hill_rank(b, carbon))
+                .then_with(|| a.to_string().cmp(&b.to_string()))
+                .then_with(|| a_isotope.cmp(b_isotope))
+        });
+
+        result
+    }
+}
+
+/// Returns the formula of atoms. Hydrogens are counted with Atom::hydrogens
+/// in addition to any hydrogen atoms in the graph.
+pub fn formula(atoms: &[Atom]) -> Formula {
+    let mut result = Formula::new();
+
+    for atom in atoms {
+        match &atom.kind {
+            AtomKind::Star => result.add_stars(1),
+            AtomKind::Shortcut(shortcut) => {
+                result.add(shortcut.into(), None, 1)
+            }
+            AtomKind::Selection(selection) => {
+                result.add(selection.into(), None, 1)
+            }
+            AtomKind::Bracket(bracket) => {
+                match &bracket.symbol {
+                    Symbol::Star => result.add_stars(1),
+                    Symbol::Element(element) => {
+                        result.add(element.clone(), bracket.isotope.clone(), 1)
+                    }
+                    Symbol::Selection(selection) => {
+                        result.add(selection.into(), bracket.isotope.clone(), 1)
+                    }
+                }
+
+                result.add_charge(bracket.charge() as i32)
+            }
+        }
+
+        result.add(Element::H, None, atom.hydrogens() as usize)
+    }
+
+    result
+}
+
+fn hill_rank(element: &Element, carbon: bool) -> Ordering {
+    if !carbon {
+        Ordering::Equal
+    } else {
+        match element {
+            Element::C => Ordering::Less,
+            Element::H => Ordering::Equal,
+            _ => Ordering::Greater,
+        }
+    }
+}
+
+impl fmt::Display for Formula {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (element, isotope, count) in self.entries() {
+            match isotope {
+                Some(isotope) => write!(f, "[{}{}]", isotope, element)?,
+                None => element.fmt(f)?,
+            }
+
+            if count > 1 {
+                count.fmt(f)?
+            }
+        }
+
+        if self.stars > 0 {
+            f.write_char('*')?;
+
+            if self.stars > 1 {
+                self.stars.fmt(f)?
+            }
+        }
+
+        match self.charge {
+            0 => Ok(()),
+            1 => f.write_char('+'),
+            -1 => f.write_char('-'),
+            charge if charge > 0 => write!(f, "+{}", charge),
+            charge => charge.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod formula {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn formula_of(string: &str) -> Formula {
+        formula(&atoms_of(string))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(formula_of("").to_string(), "")
+    }
+
+    #[test]
+    fn methane() {
+        assert_eq!(formula_of("C").to_string(), "CH4")
+    }
+
+    #[test]
+    fn nitrobenzene() {
+        assert_eq!(formula_of("c1ccccc1[N+](=O)[O-]").to_string(), "C6H5NO2")
+    }
+
+    #[test]
+    fn acetate() {
+        assert_eq!(formula_of("CC(=O)[O-]").to_string(), "C2H3O2-")
+    }
+
+    #[test]
+    fn sulfate() {
+        assert_eq!(formula_of("[O-]S(=O)(=O)[O-]").to_string(), "O4S-2")
+    }
+
+    #[test]
+    fn ammonium() {
+        assert_eq!(formula_of("[NH4+]").to_string(), "H4N+")
+    }
+
+    #[test]
+    fn no_carbon() {
+        assert_eq!(formula_of("[Na+].[Cl-]").to_string(), "ClNa")
+    }
+
+    #[test]
+    fn explicit_hydrogen() {
+        assert_eq!(formula_of("[H]C([H])([H])[H]").to_string(), "CH4")
+    }
+
+    #[test]
+    fn virtual_hydrogens() {
+        assert_eq!(formula_of("[CH3]").to_string(), "CH3")
+    }
+
+    #[test]
+    fn isotope() {
+        assert_eq!(formula_of("[13CH3]C").to_string(), "C[13C]H6")
+    }
+
+    #[test]
+    fn labelled_hydrogen() {
+        assert_eq!(formula_of("[2H]C([2H])O").to_string(), "CH2[2H]2O")
+    }
+
+    #[test]
+    fn stars() {
+        let formula = formula_of("*CC*");
+
+        assert_eq!(
+            (formula.to_string(), formula.stars()),
+            ("C2H4*2".to_string(), 2)
+        )
+    }
+
+    #[test]
+    fn bracket_star() {
+        assert_eq!(formula_of("[*-]").to_string(), "*-")
+    }
+
+    #[test]
+    fn count_ignores_isotope() {
+        let formula = formula_of("[13CH4].C");
+
+        assert_eq!(formula.count(&Element::C), 2)
+    }
+
+    #[test]
+    fn isotope_count() {
+        let formula = formula_of("[13CH4].C");
+
+        assert_eq!(
+            formula.isotope_count(&Element::C, Isotope::new(13).as_ref()),
+            1
+        )
+    }
+}
@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)]
+mod formula;
+mod parse;
+
+pub use formula::{formula, Formula};
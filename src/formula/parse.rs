@@ -0,0 +1,273 @@
+use std::str::FromStr;
+
+use lyn::Scanner;
+
+use super::Formula;
+use crate::{
+    feature::Isotope,
+    read::{digit, element, missing_character, nonzero, Error},
+};
+
+impl FromStr for Formula {
+    type Err = Error;
+
+    /// Parses the notation produced by Display. Element counts may appear in
+    /// any order and the same element may appear more than once.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut scanner = Scanner::new(string);
+        let mut result = Formula::new();
+
+        while term(&mut scanner, &mut result)? {}
+
+        if let Some(charge) = charge(&mut scanner)? {
+            result.add_charge(charge)
+        }
+
+        if scanner.is_done() {
+            Ok(result)
+        } else {
+            Err(Error::Character(scanner.cursor()))
+        }
+    }
+}
+
+/// Reads one term into formula. A count that takes a total past usize is
+/// an error at its first digit.
+fn term(scanner: &mut Scanner, formula: &mut Formula) -> Result<bool, Error> {
+    let added = if scanner.take(&'*') {
+        let start = scanner.cursor();
+
+        (start, formula.checked_add_stars(count(scanner)?))
+    } else if scanner.take(&'[') {
+        let isotope = match isotope(scanner) {
+            Some(isotope) => isotope,
+            None => return Err(missing_character(scanner)),
+        };
+        let element = match element(scanner)? {
+            Some(element) => element,
+            None => return Err(missing_character(scanner)),
+        };
+
+        if !scanner.take(&']') {
+            return Err(missing_character(scanner));
+        }
+
+        let start = scanner.cursor();
+
+        (
+            start,
+            formula.checked_add(element, Some(isotope), count(scanner)?),
+        )
+    } else if let Some(element) = element(scanner)? {
+        let start = scanner.cursor();
+
+        (start, formula.checked_add(element, None, count(scanner)?))
+    } else {
+        return Ok(false);
+    };
+
+    match added {
+        (_, Some(())) => Ok(true),
+        (start, None) => Err(Error::Character(start)),
+    }
+}
+
+fn isotope(scanner: &mut Scanner) -> Option<Isotope> {
+    let mut sum = nonzero(scanner)? as u16;
+
+    for _ in 0..2 {
+        match digit(scanner) {
+            Some(digit) => sum = sum * 10 + digit as u16,
+            None => break,
+        }
+    }
+
+    Isotope::new(sum)
+}
+
+/// Reads an optional count, defaulting to one. A count too large for usize
+/// is an error at its first digit.
+fn count(scanner: &mut Scanner) -> Result<usize, Error> {
+    let start = scanner.cursor();
+    let mut result = match nonzero(scanner) {
+        Some(digit) => digit as usize,
+        None => return Ok(1),
+    };
+
+    while let Some(digit) = digit(scanner) {
+        result = result
+            .checked_mul(10)
+            .and_then(|result| result.checked_add(digit as usize))
+            .ok_or(Error::Character(start))?
+    }
+
+    Ok(result)
+}
+
+fn charge(scanner: &mut Scanner) -> Result<Option<i32>, Error> {
+    let sign = if scanner.take(&'+') {
+        1
+    } else if scanner.take(&'-') {
+        -1
+    } else {
+        return Ok(None);
+    };
+    let start = scanner.cursor();
+
+    match i32::try_from(count(scanner)?) {
+        Ok(magnitude) => Ok(Some(sign * magnitude)),
+        Err(_) => Err(Error::Character(start)),
+    }
+}
+
+#[cfg(test)]
+mod from_str {
+    use super::*;
+    use crate::feature::Element;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty() {
+        assert_eq!("".parse::<Formula>(), Ok(Formula::new()))
+    }
+
+    #[test]
+    fn invalid_character() {
+        assert_eq!("CH4?".parse::<Formula>(), Err(Error::Character(3)))
+    }
+
+    #[test]
+    fn unknown_element() {
+        assert_eq!("Xx".parse::<Formula>(), Err(Error::Character(1)))
+    }
+
+    #[test]
+    fn unclosed_isotope() {
+        assert_eq!("[13C".parse::<Formula>(), Err(Error::EndOfLine))
+    }
+
+    #[test]
+    fn isotope_without_mass() {
+        assert_eq!("[C]".parse::<Formula>(), Err(Error::Character(1)))
+    }
+
+    #[test]
+    fn count_overflow() {
+        assert_eq!(
+            "C99999999999999999999".parse::<Formula>(),
+            Err(Error::Character(1))
+        )
+    }
+
+    #[test]
+    fn star_count_overflow() {
+        assert_eq!(
+            "*99999999999999999999".parse::<Formula>(),
+            Err(Error::Character(1))
+        )
+    }
+
+    #[test]
+    fn total_overflow() {
+        assert_eq!(
+            "C18446744073709551615C".parse::<Formula>(),
+            Err(Error::Character(22))
+        )
+    }
+
+    #[test]
+    fn star_total_overflow() {
+        assert_eq!(
+            "*18446744073709551615*".parse::<Formula>(),
+            Err(Error::Character(22))
+        )
+    }
+
+    #[test]
+    fn charge_overflow() {
+        assert_eq!("C+2147483648".parse::<Formula>(), Err(Error::Character(2)))
+    }
+
+    #[test]
+    fn largest_charge() {
+        assert_eq!(
+            "C-2147483647"
+                .parse::<Formula>()
+                .map(|formula| formula.charge()),
+            Ok(-2147483647)
+        )
+    }
+
+    #[test]
+    fn charge_before_end() {
+        assert_eq!("C-H".parse::<Formula>(), Err(Error::Character(2)))
+    }
+
+    #[test]
+    fn nitrobenzene() {
+        let mut expected = Formula::new();
+
+        expected.add(Element::C, None, 6);
+        expected.add(Element::H, None, 5);
+        expected.add(Element::N, None, 1);
+        expected.add(Element::O, None, 2);
+
+        assert_eq!("C6H5NO2".parse::<Formula>(), Ok(expected))
+    }
+
+    #[test]
+    fn repeated_element() {
+        let mut expected = Formula::new();
+
+        expected.add(Element::C, None, 2);
+        expected.add(Element::H, None, 6);
+        expected.add(Element::O, None, 1);
+
+        assert_eq!("CH3CH2OH".parse::<Formula>(), Ok(expected))
+    }
+
+    #[test]
+    fn multidigit_count() {
+        let formula = "C120H2".parse::<Formula>().unwrap();
+
+        assert_eq!(formula.count(&Element::C), 120)
+    }
+
+    #[test]
+    fn isotope() {
+        let formula = "CH2[2H]2O".parse::<Formula>().unwrap();
+
+        assert_eq!(
+            formula.isotope_count(&Element::H, Isotope::new(2).as_ref()),
+            2
+        )
+    }
+
+    #[test]
+    fn stars() {
+        let formula = "C2H4*2".parse::<Formula>().unwrap();
+
+        assert_eq!(formula.stars(), 2)
+    }
+
+    #[test]
+    fn anion() {
+        let formula = "C2H3O2-".parse::<Formula>().unwrap();
+
+        assert_eq!(formula.charge(), -1)
+    }
+
+    #[test]
+    fn dication() {
+        let formula = "Ca+2".parse::<Formula>().unwrap();
+
+        assert_eq!(formula.charge(), 2)
+    }
+
+    #[test]
+    fn round_trip() {
+        let string = "C5[13C]H4[2H]2ClNO3S*-2";
+
+        assert_eq!(string.parse::<Formula>().unwrap().to_string(), string)
+    }
+}
@@ -1,6 +1,9 @@
-use crate::feature::{AtomKind, Bracket, Selection, Shortcut};
+use crate::feature::{
+    AtomKind, Bracket, DefaultValenceModel, Selection, Shortcut, ValenceError,
+    ValenceModel,
+};
 
-use super::Bond;
+use super::{Bond, Hybridization};
 
 #[derive(Debug, PartialEq, Default, Clone)]
 pub struct Atom {
@@ -49,6 +52,13 @@ impl Atom {
         self.kind.subvalence(self.valence())
     }
 
+    pub fn checked_subvalence(
+        &self,
+        model: &impl ValenceModel,
+    ) -> Result<u8, ValenceError> {
+        self.kind.checked_subvalence(self.valence(), model)
+    }
+
     pub fn implicit_hydrogens(&self) -> u8 {
         match &self.kind {
             AtomKind::Star => 0,
@@ -69,6 +79,82 @@ impl Atom {
             AtomKind::Bracket(bracket) => bracket.hydrogens(),
         }
     }
+
+    /// Returns the charge, which is zero unless given by a Bracket atom.
+    pub fn charge(&self) -> i8 {
+        match &self.kind {
+            AtomKind::Bracket(bracket) => bracket.charge(),
+            _ => 0,
+        }
+    }
+
+    /// Returns the number of valence shell electrons, adjusted for charge.
+    /// Returns None for Star atoms and elements outside the main group.
+    pub fn valence_electrons(&self) -> Option<u8> {
+        let electrons = self.kind.element()?.valence_electrons()?;
+
+        u8::try_from(electrons as i8 - self.charge()).ok()
+    }
+
+    /// Returns the number of unpaired electrons. Only Bracket atoms can have
+    /// them, as subvalence left after any double bond from selection.
+    pub fn unpaired_electrons(&self) -> Option<u8> {
+        self.valence_electrons()?;
+
+        match &self.kind {
+            AtomKind::Bracket(_) => {
+                let subvalence =
+                    self.checked_subvalence(&DefaultValenceModel).ok()?;
+
+                Some(if self.kind.is_selected() && subvalence > 0 {
+                    subvalence - 1
+                } else {
+                    subvalence
+                })
+            }
+            _ => Some(0),
+        }
+    }
+
+    /// Returns the number of nonbonding electron pairs, or None if the
+    /// electron count can't be balanced.
+    pub fn lone_pairs(&self) -> Option<u8> {
+        let electrons = self.valence_electrons()?;
+        let unpaired = self.unpaired_electrons()?;
+        let mut bonding = self.valence() + self.implicit_hydrogens();
+
+        if self.kind.is_selected() && self.subvalence() > 0 {
+            bonding += 1;
+        }
+
+        let nonbonding =
+            electrons.checked_sub(bonding)?.checked_sub(unpaired)?;
+
+        if nonbonding % 2 == 0 {
+            Some(nonbonding / 2)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a hybridization guess. Selected atoms are sp2. Others are
+    /// assigned from the steric number, the count of neighbors, hydrogens,
+    /// and lone pairs. Returns None if the steric number falls outside two
+    /// through four.
+    pub fn hybridization(&self) -> Option<Hybridization> {
+        let lone_pairs = self.lone_pairs()?;
+
+        if self.kind.is_selected() {
+            return Some(Hybridization::Sp2);
+        }
+
+        match self.bonds.len() + (self.hydrogens() + lone_pairs) as usize {
+            2 => Some(Hybridization::Sp),
+            3 => Some(Hybridization::Sp2),
+            4 => Some(Hybridization::Sp3),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -184,6 +270,61 @@ mod subvalence {
     }
 }
 
+#[cfg(test)]
+mod checked_subvalence {
+    use crate::feature::{
+        Charge, DefaultValenceModel, Element, Symbol, VirtualHydrogen,
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn ammonium() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::N),
+                hydrogens: Some(VirtualHydrogen::H3),
+                charge: Some(Charge::Plus),
+                ..Default::default()
+            },
+            vec![Bond::single(1)],
+        );
+
+        assert_eq!(atom.checked_subvalence(&DefaultValenceModel), Ok(0))
+    }
+
+    #[test]
+    fn supervalent_cation() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::N),
+                charge: Some(Charge::Plus),
+                ..Default::default()
+            },
+            vec![Bond::double(1), Bond::double(2), Bond::single(3)],
+        );
+
+        assert_eq!(
+            atom.checked_subvalence(&DefaultValenceModel),
+            Err(ValenceError::Supervalent)
+        )
+    }
+
+    #[test]
+    fn shortcut_supervalent() {
+        let atom = Atom::shortcut(
+            Shortcut::O,
+            vec![Bond::single(1), Bond::single(2), Bond::single(3)],
+        );
+
+        assert_eq!(
+            atom.checked_subvalence(&DefaultValenceModel),
+            Err(ValenceError::Supervalent)
+        )
+    }
+}
+
 #[cfg(test)]
 mod implicit_hydrogens {
     use crate::feature;
@@ -269,3 +410,358 @@ mod hydrogens {
         assert_eq!(atom.hydrogens(), 1)
     }
 }
+
+#[cfg(test)]
+mod charge {
+    use crate::feature::{Charge, Element, Symbol};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn shortcut() {
+        let atom = Atom::shortcut(Shortcut::N, vec![]);
+
+        assert_eq!(atom.charge(), 0)
+    }
+
+    #[test]
+    fn bracket() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::N),
+                charge: Some(Charge::Plus),
+                ..Default::default()
+            },
+            vec![],
+        );
+
+        assert_eq!(atom.charge(), 1)
+    }
+}
+
+#[cfg(test)]
+mod valence_electrons {
+    use crate::feature::{Charge, Element, Symbol};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn star() {
+        let atom = Atom::star(vec![]);
+
+        assert_eq!(atom.valence_electrons(), None)
+    }
+
+    #[test]
+    fn transition_metal() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::Fe),
+                ..Default::default()
+            },
+            vec![],
+        );
+
+        assert_eq!(atom.valence_electrons(), None)
+    }
+
+    #[test]
+    fn shortcut_oxygen() {
+        let atom = Atom::shortcut(Shortcut::O, vec![]);
+
+        assert_eq!(atom.valence_electrons(), Some(6))
+    }
+
+    #[test]
+    fn bracket_oxide() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::O),
+                charge: Some(Charge::Minus),
+                ..Default::default()
+            },
+            vec![],
+        );
+
+        assert_eq!(atom.valence_electrons(), Some(7))
+    }
+}
+
+#[cfg(test)]
+mod unpaired_electrons {
+    use crate::feature::{Element, Symbol, VirtualHydrogen};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn shortcut() {
+        let atom = Atom::shortcut(Shortcut::C, vec![Bond::single(1)]);
+
+        assert_eq!(atom.unpaired_electrons(), Some(0))
+    }
+
+    #[test]
+    fn methyl_radical() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::C),
+                hydrogens: Some(VirtualHydrogen::H3),
+                ..Default::default()
+            },
+            vec![],
+        );
+
+        assert_eq!(atom.unpaired_electrons(), Some(1))
+    }
+
+    #[test]
+    fn methylene() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::C),
+                hydrogens: Some(VirtualHydrogen::H2),
+                ..Default::default()
+            },
+            vec![],
+        );
+
+        assert_eq!(atom.unpaired_electrons(), Some(2))
+    }
+
+    #[test]
+    fn selected_bracket() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Selection(Selection::N),
+                hydrogens: Some(VirtualHydrogen::H),
+                ..Default::default()
+            },
+            vec![Bond::elided(1), Bond::elided(2)],
+        );
+
+        assert_eq!(atom.unpaired_electrons(), Some(0))
+    }
+}
+
+#[cfg(test)]
+mod lone_pairs {
+    use crate::feature::{Charge, Element, Symbol, VirtualHydrogen};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn star() {
+        let atom = Atom::star(vec![]);
+
+        assert_eq!(atom.lone_pairs(), None)
+    }
+
+    #[test]
+    fn water() {
+        let atom = Atom::shortcut(Shortcut::O, vec![]);
+
+        assert_eq!(atom.lone_pairs(), Some(2))
+    }
+
+    #[test]
+    fn carbonyl_oxygen() {
+        let atom = Atom::shortcut(Shortcut::O, vec![Bond::double(1)]);
+
+        assert_eq!(atom.lone_pairs(), Some(2))
+    }
+
+    #[test]
+    fn pyridine_nitrogen() {
+        let atom = Atom::selection(
+            Selection::N,
+            vec![Bond::elided(1), Bond::elided(2)],
+        );
+
+        assert_eq!(atom.lone_pairs(), Some(1))
+    }
+
+    #[test]
+    fn pyrrole_nitrogen() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Selection(Selection::N),
+                hydrogens: Some(VirtualHydrogen::H),
+                ..Default::default()
+            },
+            vec![Bond::elided(1), Bond::elided(2)],
+        );
+
+        assert_eq!(atom.lone_pairs(), Some(1))
+    }
+
+    #[test]
+    fn ammonium() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::N),
+                hydrogens: Some(VirtualHydrogen::H4),
+                charge: Some(Charge::Plus),
+                ..Default::default()
+            },
+            vec![],
+        );
+
+        assert_eq!(atom.lone_pairs(), Some(0))
+    }
+
+    #[test]
+    fn oxide() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::O),
+                charge: Some(Charge::Minus),
+                ..Default::default()
+            },
+            vec![Bond::single(1)],
+        );
+
+        assert_eq!(atom.lone_pairs(), Some(3))
+    }
+
+    #[test]
+    fn methyl_radical() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::C),
+                hydrogens: Some(VirtualHydrogen::H3),
+                ..Default::default()
+            },
+            vec![],
+        );
+
+        assert_eq!(atom.lone_pairs(), Some(0))
+    }
+
+    #[test]
+    fn sulfoxide() {
+        let atom = Atom::shortcut(
+            Shortcut::S,
+            vec![Bond::single(1), Bond::double(2), Bond::single(3)],
+        );
+
+        assert_eq!(atom.lone_pairs(), Some(1))
+    }
+
+    #[test]
+    fn supervalent() {
+        let atom = Atom::shortcut(
+            Shortcut::O,
+            vec![Bond::single(1), Bond::single(2), Bond::single(3)],
+        );
+
+        assert_eq!(atom.lone_pairs(), None)
+    }
+}
+
+#[cfg(test)]
+mod hybridization {
+    use crate::feature::{Charge, Element, Symbol, VirtualHydrogen};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn star() {
+        let atom = Atom::star(vec![]);
+
+        assert_eq!(atom.hybridization(), None)
+    }
+
+    #[test]
+    fn methane() {
+        let atom = Atom::shortcut(Shortcut::C, vec![]);
+
+        assert_eq!(atom.hybridization(), Some(Hybridization::Sp3))
+    }
+
+    #[test]
+    fn alkene_carbon() {
+        let atom = Atom::shortcut(Shortcut::C, vec![Bond::double(1)]);
+
+        assert_eq!(atom.hybridization(), Some(Hybridization::Sp2))
+    }
+
+    #[test]
+    fn nitrile_nitrogen() {
+        let atom = Atom::shortcut(Shortcut::N, vec![Bond::triple(1)]);
+
+        assert_eq!(atom.hybridization(), Some(Hybridization::Sp))
+    }
+
+    #[test]
+    fn allene_carbon() {
+        let atom =
+            Atom::shortcut(Shortcut::C, vec![Bond::double(1), Bond::double(2)]);
+
+        assert_eq!(atom.hybridization(), Some(Hybridization::Sp))
+    }
+
+    #[test]
+    fn water() {
+        let atom = Atom::shortcut(Shortcut::O, vec![]);
+
+        assert_eq!(atom.hybridization(), Some(Hybridization::Sp3))
+    }
+
+    #[test]
+    fn furan_oxygen() {
+        let atom = Atom::selection(
+            Selection::O,
+            vec![Bond::elided(1), Bond::elided(2)],
+        );
+
+        assert_eq!(atom.hybridization(), Some(Hybridization::Sp2))
+    }
+
+    #[test]
+    fn carbocation() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::C),
+                hydrogens: Some(VirtualHydrogen::H3),
+                charge: Some(Charge::Plus),
+                ..Default::default()
+            },
+            vec![],
+        );
+
+        assert_eq!(atom.hybridization(), Some(Hybridization::Sp2))
+    }
+
+    #[test]
+    fn carbanion() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::C),
+                hydrogens: Some(VirtualHydrogen::H3),
+                charge: Some(Charge::Minus),
+                ..Default::default()
+            },
+            vec![],
+        );
+
+        assert_eq!(atom.hybridization(), Some(Hybridization::Sp3))
+    }
+
+    #[test]
+    fn hydrogen() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::H),
+                ..Default::default()
+            },
+            vec![Bond::single(1)],
+        );
+
+        assert_eq!(atom.hybridization(), None)
+    }
+}
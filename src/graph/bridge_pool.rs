@@ -5,7 +5,7 @@ use std::hash::{Hash, Hasher};
 
 use crate::feature::Bridge;
 
-#[derive(Eq, PartialEq, PartialOrd)]
+#[derive(Eq, PartialEq)]
 struct Index(u8);
 
 impl Ord for Index {
@@ -14,6 +14,12 @@ impl Ord for Index {
     }
 }
 
+impl PartialOrd for Index {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[derive(Debug, Eq)]
 struct Pair(usize, usize);
 
@@ -73,6 +79,12 @@ impl BridgePool {
     }
 }
 
+impl Default for BridgePool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod pair {
     use super::*;
@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 
 use crate::{
-    feature::{AtomKind, BondKind, Bridge},
+    feature::{AtomKind, AtomParity, BondKind, Bridge},
     follow::Follower,
+    stereo::{ligands, Ligand},
 };
 
 use super::{Atom, Bond};
@@ -12,6 +13,7 @@ pub struct Builder {
     atoms: Vec<Atom>,
     head: Option<usize>,
     stack: Vec<usize>,
+    roots: Vec<usize>,
     bridges: HashMap<Bridge, (usize, usize, BondKind)>,
 }
 
@@ -21,12 +23,106 @@ impl Builder {
             atoms: Vec::new(),
             stack: Vec::new(),
             head: None,
+            roots: Vec::new(),
             bridges: HashMap::new(),
         }
     }
 
+    /// Returns the indexes of atoms with a bridge that was never closed, in
+    /// ascending order. Such bridges are left out of the built atoms.
+    pub fn unmatched_bridges(&self) -> Vec<usize> {
+        let mut result = self
+            .bridges
+            .values()
+            .map(|(sid, _, _)| *sid)
+            .collect::<Vec<_>>();
+
+        result.sort_unstable();
+
+        result
+    }
+
+    /// Returns the atoms.
+    ///
+    /// A root atom whose first bond is a ring closure to a lower index, as
+    /// in `C1.[C@H]1(F)Cl`, can't be told apart from an atom reached through
+    /// that bond. Such a root is stored as if it had been: its parity is
+    /// inverted when it has an implicit ligand, which the string places
+    /// first but stereo::ligands places second, and an Up or Down marker on
+    /// the closure is stored like a chain bond's, as stereo::direction reads
+    /// it.
     pub fn build(self) -> Vec<Atom> {
-        self.atoms
+        let mut atoms = self.atoms;
+
+        for root in self.roots {
+            let low = match atoms[root].bonds.first() {
+                Some(bond) if bond.tid < root => bond.tid,
+                _ => continue,
+            };
+
+            store_as_chain(&mut atoms, low, root);
+
+            let implicit = ligands(&atoms, root)
+                .into_iter()
+                .filter(|ligand| ligand == &Ligand::Implicit)
+                .count();
+
+            if implicit % 2 == 0 {
+                continue;
+            }
+
+            if let AtomKind::Bracket(bracket) = &mut atoms[root].kind {
+                bracket.parity = match bracket.parity {
+                    Some(AtomParity::Counterclockwise) => {
+                        Some(AtomParity::Clockwise)
+                    }
+                    Some(AtomParity::Clockwise) => {
+                        Some(AtomParity::Counterclockwise)
+                    }
+                    None => None,
+                }
+            }
+        }
+
+        atoms
+    }
+}
+
+/// Stores the bridge bond between low and high under the kind it has read
+/// from low, on both atoms. Markers that contradict each other are kept.
+fn store_as_chain(atoms: &mut [Atom], low: usize, high: usize) {
+    let forward = atoms[low]
+        .bonds
+        .iter()
+        .find(|bond| bond.tid == high)
+        .map(|bond| bond.kind.clone())
+        .expect("bond");
+    let backward = atoms[high].bonds[0].kind.clone();
+    let kind = match (&forward, &backward) {
+        (BondKind::Up | BondKind::Down, BondKind::Up | BondKind::Down) => {
+            if forward != backward.reverse() {
+                return;
+            }
+
+            forward
+        }
+        (BondKind::Up | BondKind::Down, _) => forward,
+        (_, BondKind::Up | BondKind::Down) => backward.reverse(),
+        _ => return,
+    };
+
+    for (sid, tid) in [(low, high), (high, low)] {
+        if let Some(bond) =
+            atoms[sid].bonds.iter_mut().find(|bond| bond.tid == tid)
+        {
+            bond.kind = kind.clone()
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -38,6 +134,7 @@ impl Follower for Builder {
             kind: kind.clone(),
             bonds: Vec::new(),
         });
+        self.roots.push(id);
         self.head.replace(id);
     }
 
@@ -98,9 +195,35 @@ impl Follower for Builder {
     }
 }
 
+#[cfg(test)]
+mod unmatched_bridges {
+    use super::*;
+    use crate::read::read;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn matched() {
+        let mut builder = Builder::new();
+
+        read("C1CC1", &mut builder).unwrap();
+
+        assert_eq!(builder.unmatched_bridges(), vec![])
+    }
+
+    #[test]
+    fn unmatched() {
+        let mut builder = Builder::new();
+
+        read("C1CC2CC1C3", &mut builder).unwrap();
+
+        assert_eq!(builder.unmatched_bridges(), vec![2, 5])
+    }
+}
+
 #[cfg(test)]
 mod build {
     use super::*;
+    use crate::read::read;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -238,4 +361,37 @@ mod build {
             ]
         )
     }
+
+    #[test]
+    fn root_closing_bridge_to_lower_index() {
+        let mut builder = Builder::new();
+
+        read("C1.[C@H]1(F)Cl", &mut builder).unwrap();
+
+        assert_eq!(builder.build()[1].kind.to_string(), "[C@@H1]")
+    }
+
+    #[test]
+    fn root_closing_directional_bridge() {
+        let mut builder = Builder::new();
+
+        read("F/C=C/1.C1", &mut builder).unwrap();
+
+        assert_eq!(
+            builder.build()[3].bonds,
+            vec![Bond {
+                kind: BondKind::Up,
+                tid: 2
+            }]
+        )
+    }
+
+    #[test]
+    fn root_opening_bridge() {
+        let mut builder = Builder::new();
+
+        read("[C@H]1(F)Cl.C1", &mut builder).unwrap();
+
+        assert_eq!(builder.build()[0].kind.to_string(), "[C@H1]")
+    }
 }
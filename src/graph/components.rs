@@ -0,0 +1,67 @@
+use super::{Atom, Bond};
+
+/// Returns the number of bonds for which include is true, given the index
+/// of the atom the bond leaves, and the number of connected components
+/// they leave. Bonds and atoms minus components gives the number of
+/// independent cycles.
+pub fn components<F>(atoms: &[Atom], include: F) -> (usize, usize)
+where
+    F: Fn(usize, &Bond) -> bool,
+{
+    let mut roots = (0..atoms.len()).collect::<Vec<_>>();
+    let mut bonds = 0;
+    let mut result = atoms.len();
+
+    for (sid, atom) in atoms.iter().enumerate() {
+        for bond in &atom.bonds {
+            if bond.tid < sid || !include(sid, bond) {
+                continue;
+            }
+
+            bonds += 1;
+
+            let (first, second) = (root(&roots, sid), root(&roots, bond.tid));
+
+            if first != second {
+                roots[second] = first;
+                result -= 1;
+            }
+        }
+    }
+
+    (bonds, result)
+}
+
+fn root(roots: &[usize], mut id: usize) -> usize {
+    while roots[id] != id {
+        id = roots[id]
+    }
+
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn components_of(string: &str) -> (usize, usize) {
+        components(&atoms_of(string), |_, _| true)
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(components_of(""), (0, 0))
+    }
+
+    #[test]
+    fn ring_and_ion() {
+        assert_eq!(components_of("C1CC1.[Na+]"), (3, 2))
+    }
+
+    #[test]
+    fn excluded_bonds() {
+        assert_eq!(components(&atoms_of("CC=CC"), |sid, _| sid != 1), (2, 2))
+    }
+}
@@ -0,0 +1,6 @@
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Geometry {
+    Linear,
+    TrigonalPlanar,
+    Tetrahedral,
+}
@@ -0,0 +1,42 @@
+use std::fmt;
+
+use super::Geometry;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Hybridization {
+    Sp,
+    Sp2,
+    Sp3,
+}
+
+impl Hybridization {
+    /// Returns the arrangement of electron domains around the atom.
+    pub fn geometry(&self) -> Geometry {
+        match self {
+            Self::Sp => Geometry::Linear,
+            Self::Sp2 => Geometry::TrigonalPlanar,
+            Self::Sp3 => Geometry::Tetrahedral,
+        }
+    }
+}
+
+impl fmt::Display for Hybridization {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Sp => "sp",
+            Self::Sp2 => "sp2",
+            Self::Sp3 => "sp3",
+        })
+    }
+}
+
+#[cfg(test)]
+mod geometry {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn sp2() {
+        assert_eq!(Hybridization::Sp2.geometry(), Geometry::TrigonalPlanar)
+    }
+}
@@ -2,10 +2,21 @@ mod atom;
 mod bond;
 mod bridge_pool;
 mod builder;
+mod components;
+mod geometry;
+mod hybridization;
+mod ring_bonds;
+mod to_bracket;
 mod walk;
 
 pub use atom::Atom;
 pub use bond::Bond;
 pub use bridge_pool::BridgePool;
 pub use builder::Builder;
+pub use geometry::Geometry;
+pub use hybridization::Hybridization;
+pub use ring_bonds::ring_bonds;
 pub use walk::walk;
+
+pub(crate) use components::components;
+pub(crate) use to_bracket::to_bracket;
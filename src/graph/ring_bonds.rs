@@ -0,0 +1,149 @@
+use super::Atom;
+
+/// Returns, for each bond of each atom, whether the bond lies on a ring.
+/// The result parallels the bonds of atoms. A bond lies on a ring unless
+/// removing it disconnects its component.
+pub fn ring_bonds(atoms: &[Atom]) -> Vec<Vec<bool>> {
+    let mut result = atoms
+        .iter()
+        .map(|atom| vec![true; atom.bonds.len()])
+        .collect::<Vec<_>>();
+    let mut order = vec![usize::MAX; atoms.len()];
+    let mut low = vec![usize::MAX; atoms.len()];
+    let mut counter = 0;
+
+    for root in 0..atoms.len() {
+        if order[root] != usize::MAX {
+            continue;
+        }
+
+        order[root] = counter;
+        low[root] = counter;
+        counter += 1;
+
+        // Entries hold an atom, its parent, and the next bond to explore.
+        let mut stack = vec![(root, usize::MAX, 0)];
+
+        while let Some((id, parent, next)) = stack.last_mut() {
+            let (id, parent) = (*id, *parent);
+
+            match atoms[id].bonds.get(*next) {
+                Some(bond) => {
+                    *next += 1;
+
+                    let tid = bond.tid;
+
+                    if tid == parent {
+                        continue;
+                    } else if order[tid] == usize::MAX {
+                        order[tid] = counter;
+                        low[tid] = counter;
+                        counter += 1;
+                        stack.push((tid, id, 0));
+                    } else {
+                        low[id] = low[id].min(order[tid]);
+                    }
+                }
+                None => {
+                    stack.pop();
+
+                    if parent == usize::MAX {
+                        continue;
+                    }
+
+                    low[parent] = low[parent].min(low[id]);
+
+                    if low[id] > order[parent] {
+                        mark_chain(atoms, &mut result, parent, id);
+                    }
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn mark_chain(
+    atoms: &[Atom],
+    result: &mut [Vec<bool>],
+    sid: usize,
+    tid: usize,
+) {
+    for (id, other) in [(sid, tid), (tid, sid)] {
+        for (index, bond) in atoms[id].bonds.iter().enumerate() {
+            if bond.tid == other {
+                result[id][index] = false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn ring_bonds_of(string: &str) -> Vec<Vec<bool>> {
+        ring_bonds(&atoms_of(string))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(ring_bonds_of(""), Vec::<Vec<bool>>::new())
+    }
+
+    #[test]
+    fn chain() {
+        assert_eq!(
+            ring_bonds_of("CCC"),
+            vec![vec![false], vec![false, false], vec![false]]
+        )
+    }
+
+    #[test]
+    fn cyclopropane() {
+        assert_eq!(
+            ring_bonds_of("C1CC1"),
+            vec![vec![true, true], vec![true, true], vec![true, true]]
+        )
+    }
+
+    #[test]
+    fn methylcyclopropane() {
+        assert_eq!(
+            ring_bonds_of("CC1CC1"),
+            vec![
+                vec![false],
+                vec![false, true, true],
+                vec![true, true],
+                vec![true, true]
+            ]
+        )
+    }
+
+    #[test]
+    fn biphenyl_linker() {
+        let result = ring_bonds_of("c1ccccc1-c1ccccc1");
+
+        assert_eq!(
+            (result[5].clone(), result[6][0]),
+            (vec![true, true, false], false)
+        )
+    }
+
+    #[test]
+    fn separate_components() {
+        assert_eq!(
+            ring_bonds_of("C1CC1.CC"),
+            vec![
+                vec![true, true],
+                vec![true, true],
+                vec![true, true],
+                vec![false],
+                vec![false]
+            ]
+        )
+    }
+}
@@ -0,0 +1,30 @@
+use super::Atom;
+use crate::feature::{AtomKind, Bracket, Symbol, VirtualHydrogen};
+
+/// Replaces a Shortcut or Selection atom with the equivalent Bracket atom,
+/// and returns the bracket. Returns None for Star atoms.
+pub fn to_bracket(atom: &mut Atom) -> Option<&mut Bracket> {
+    let symbol = match &atom.kind {
+        AtomKind::Star => return None,
+        AtomKind::Shortcut(shortcut) => Some(Symbol::Element(shortcut.into())),
+        AtomKind::Selection(selection) => {
+            Some(Symbol::Selection(selection.clone()))
+        }
+        AtomKind::Bracket(_) => None,
+    };
+
+    if let Some(symbol) = symbol {
+        let hydrogens = atom.implicit_hydrogens();
+
+        atom.kind = AtomKind::Bracket(Bracket {
+            symbol,
+            hydrogens: VirtualHydrogen::new(hydrogens),
+            ..Default::default()
+        })
+    }
+
+    match &mut atom.kind {
+        AtomKind::Bracket(bracket) => Some(bracket),
+        _ => unreachable!("bracket"),
+    }
+}
@@ -1,19 +1,28 @@
-use std::collections::HashMap;
+use std::{borrow::Cow, collections::HashMap};
 
-use crate::follow::Follower;
+use crate::{
+    feature::{AtomKind, AtomParity},
+    follow::Follower,
+    stereo::{is_odd, ligands, Ligand},
+};
 
 use super::{Atom, Bond, BridgePool};
 
-pub fn walk(atoms: &Vec<Atom>, follower: &mut impl Follower) {
-    let mut atoms = atoms.into_iter().enumerate().collect::<HashMap<_, _>>();
+/// Sends atoms to follower depth-first. Parity is rewritten where needed so
+/// that it refers to the order in which neighbors are followed, rather than
+/// the order of stereo::ligands.
+pub fn walk(atoms: &[Atom], follower: &mut impl Follower) {
+    let graph = atoms;
+    let mut atoms = atoms.iter().enumerate().collect::<HashMap<_, _>>();
 
     for i in 0..atoms.len() {
-        walk_root(i, &mut atoms, follower)
+        walk_root(i, graph, &mut atoms, follower)
     }
 }
 
 fn walk_root(
     mut hid: usize,
+    graph: &[Atom],
     atoms: &mut HashMap<usize, &Atom>,
     follower: &mut impl Follower,
 ) {
@@ -24,7 +33,7 @@ fn walk_root(
     let mut bridge_pool = BridgePool::new();
     let mut stack = bonds(hid, root, None).collect::<Vec<_>>();
 
-    follower.root(&root.kind);
+    follower.root(&kind(graph, hid, None));
 
     while let Some((push, sid, bond)) = stack.pop() {
         if sid != hid {
@@ -46,8 +55,48 @@ fn walk_root(
 
         hid = bond.tid;
 
-        stack.extend(bonds(bond.tid, &target, Some(sid)));
-        follower.extend(&bond.kind, &target.kind);
+        stack.extend(bonds(bond.tid, target, Some(sid)));
+        follower.extend(&bond.kind, &kind(graph, bond.tid, Some(sid)));
+    }
+}
+
+fn kind(atoms: &[Atom], id: usize, back: Option<usize>) -> Cow<'_, AtomKind> {
+    let kind = &atoms[id].kind;
+    let bracket = match kind {
+        AtomKind::Bracket(bracket) if bracket.parity.is_some() => bracket,
+        _ => return Cow::Borrowed(kind),
+    };
+    let expected = ligands(atoms, id);
+    let mut actual = back.map(Ligand::Atom).into_iter().collect::<Vec<_>>();
+
+    actual.extend(
+        expected
+            .iter()
+            .filter(|ligand| **ligand == Ligand::Implicit),
+    );
+    actual.extend(expected.iter().filter(|ligand| match (ligand, back) {
+        (Ligand::Atom(tid), Some(back)) => *tid != back,
+        (Ligand::Atom(_), None) => true,
+        (Ligand::Implicit, _) => false,
+    }));
+
+    match is_odd(&expected, &actual) {
+        Some(true) => {
+            let mut bracket = bracket.clone();
+
+            bracket.parity = match bracket.parity {
+                Some(AtomParity::Counterclockwise) => {
+                    Some(AtomParity::Clockwise)
+                }
+                Some(AtomParity::Clockwise) => {
+                    Some(AtomParity::Counterclockwise)
+                }
+                None => None,
+            };
+
+            Cow::Owned(AtomKind::Bracket(bracket))
+        }
+        _ => Cow::Borrowed(kind),
     }
 }
 
@@ -79,9 +128,20 @@ mod tests {
     use crate::{
         feature::{AtomKind, Bracket, Element, Symbol},
         follow::Writer,
+        read::atoms_of,
+        search::is_isomorphic,
     };
     use pretty_assertions::assert_eq;
 
+    fn round_trip(string: &str) -> (Vec<Atom>, Vec<Atom>) {
+        let atoms = atoms_of(string);
+        let mut writer = Writer::new();
+
+        walk(&atoms, &mut writer);
+
+        (atoms, atoms_of(&writer.write()))
+    }
+
     #[test]
     fn p1() {
         let graph = vec![Atom::star(vec![])];
@@ -255,4 +315,26 @@ mod tests {
 
         assert_eq!(writer.write(), "*(*(**12)2)1")
     }
+
+    #[test]
+    fn parity_behind_bridge() {
+        let (atoms, written) = round_trip("C1(F)CC[C@@H]1Cl");
+
+        assert!(is_isomorphic(&atoms, &written, true))
+    }
+
+    #[test]
+    fn parity_root_behind_bridge() {
+        let (atoms, written) = round_trip("C1CC(F)[C@]1(Cl)Br");
+
+        assert!(is_isomorphic(&atoms, &written, true))
+    }
+
+    #[test]
+    fn parity_unchanged() {
+        let mut writer = Writer::new();
+        walk(&atoms_of("N[C@@H](C)C(=O)O"), &mut writer);
+
+        assert_eq!(writer.write(), "N[C@@H1](C)C(=O)O")
+    }
 }
@@ -0,0 +1,80 @@
+const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+const PRIME: u128 = 0x0000000001000000000000000000013b;
+
+/// The 128-bit FNV-1a hash. Unlike std::hash::DefaultHasher, its output is
+/// fixed by specification, so values can be stored and compared across
+/// platforms and releases. Integers are written as little-endian bytes.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Fnv128 {
+    state: u128,
+}
+
+impl Fnv128 {
+    pub fn new() -> Self {
+        Self {
+            state: OFFSET_BASIS,
+        }
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u128;
+            self.state = self.state.wrapping_mul(PRIME);
+        }
+    }
+
+    pub fn write_u8(&mut self, value: u8) {
+        self.write(&[value])
+    }
+
+    pub fn write_u16(&mut self, value: u16) {
+        self.write(&value.to_le_bytes())
+    }
+
+    pub fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes())
+    }
+
+    pub fn write_u128(&mut self, value: u128) {
+        self.write(&value.to_le_bytes())
+    }
+
+    pub fn finish(&self) -> u128 {
+        self.state
+    }
+}
+
+impl Default for Fnv128 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod finish {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty() {
+        assert_eq!(Fnv128::new().finish(), OFFSET_BASIS)
+    }
+
+    #[test]
+    fn a() {
+        let mut hasher = Fnv128::new();
+
+        hasher.write(b"a");
+
+        assert_eq!(hasher.finish(), 0xd228cb696f1a8caf78912b704e4a8964)
+    }
+
+    #[test]
+    fn foobar() {
+        let mut hasher = Fnv128::new();
+
+        hasher.write(b"foobar");
+
+        assert_eq!(hasher.finish(), 0x343e1662793c64bf6f0d3597ba446f18)
+    }
+}
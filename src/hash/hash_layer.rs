@@ -0,0 +1,11 @@
+/// Atom and bond features included in a molecule hash. Each layer includes
+/// the features of the layers before it.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum HashLayer {
+    /// Element, selection, hydrogen count, and bond classes.
+    Connectivity,
+    /// Adds formal charge and isotope.
+    ChargesAndIsotopes,
+    /// Adds tetrahedral parity and double bond configuration.
+    Stereo,
+}
@@ -0,0 +1,7 @@
+mod fnv128;
+mod hash_layer;
+mod molecule_hash;
+
+pub use fnv128::Fnv128;
+pub use hash_layer::HashLayer;
+pub use molecule_hash::molecule_hash;
@@ -0,0 +1,393 @@
+use super::{Fnv128, HashLayer};
+use crate::{
+    feature::AtomParity,
+    graph::Atom,
+    search::{parity, AtomKey, BondClass},
+    stereo::{double_bonds, ligands, Configuration, DoubleBond, Ligand},
+};
+
+/// Returns a 128-bit hash of atoms that doesn't depend on atom order.
+/// Isomorphic molecules always hash alike. Different molecules almost
+/// always hash differently, but confirm with is_isomorphic when it matters.
+///
+/// The algorithm is a Weisfeiler-Lehman refinement hashed with Fnv128 and
+/// won't change between releases:
+///
+/// 1. Each atom starts with the hash of its atomic number (0 for Star),
+///    selection (0 or 1), and hydrogen count as bytes. From
+///    ChargesAndIsotopes on, its charge as a two's complement byte and mass
+///    number as a u16 (0 if none) follow.
+/// 2. Each round hashes an atom's label, its degree as a byte, and the
+///    BondClass index and label of each neighbor, sorted. With Stereo, a
+///    final byte gives the winding of a stereocenter whose ligands have
+///    distinct labels: 1 or 2 for counterclockwise or clockwise about
+///    ligands ordered by label, else 0. Virtual hydrogens and implicit
+///    ligands take label 0. Then, for each double bond from
+///    stereo::double_bonds on the atom, a byte gives 1 or 2 for cis or
+///    trans between the highest labelled neighbor at each end, or 0 if
+///    either end has two neighbors with the same label. These bytes are
+///    sorted.
+/// 3. Rounds stop after the first that doesn't increase the number of
+///    distinct labels, or after as many rounds as atoms.
+/// 4. The result hashes the layer index as a byte, the atom count as a
+///    u32, and all labels in ascending order.
+///
+/// Labels are written as u128 values.
+pub fn molecule_hash(atoms: &[Atom], layer: HashLayer) -> u128 {
+    let mut labels = atoms
+        .iter()
+        .map(|atom| initial_label(atom, layer))
+        .collect::<Vec<_>>();
+    let mut classes = distinct(&labels);
+    let double_bonds = if layer >= HashLayer::Stereo {
+        double_bonds(atoms)
+    } else {
+        Vec::new()
+    };
+
+    for _ in 0..atoms.len() {
+        let next = (0..atoms.len())
+            .map(|index| refine(atoms, index, &labels, &double_bonds, layer))
+            .collect::<Vec<_>>();
+        let next_classes = distinct(&next);
+
+        labels = next;
+
+        if next_classes <= classes {
+            break;
+        }
+
+        classes = next_classes;
+    }
+
+    labels.sort_unstable();
+
+    let mut hasher = Fnv128::new();
+
+    hasher.write_u8(layer as u8);
+    hasher.write_u32(atoms.len() as u32);
+
+    for label in labels {
+        hasher.write_u128(label)
+    }
+
+    hasher.finish()
+}
+
+fn initial_label(atom: &Atom, layer: HashLayer) -> u128 {
+    let key = AtomKey::new(atom);
+    let mut hasher = Fnv128::new();
+
+    hasher.write_u8(match &key.element {
+        Some(element) => element.atomic_number(),
+        None => 0,
+    });
+    hasher.write_u8(key.selected as u8);
+    hasher.write_u8(key.hydrogens);
+
+    if layer >= HashLayer::ChargesAndIsotopes {
+        hasher.write_u8(key.charge as u8);
+        hasher.write_u16(key.isotope.unwrap_or(0));
+    }
+
+    hasher.finish()
+}
+
+fn refine(
+    atoms: &[Atom],
+    index: usize,
+    labels: &[u128],
+    double_bonds: &[DoubleBond],
+    layer: HashLayer,
+) -> u128 {
+    let atom = &atoms[index];
+    let mut neighbors = atom
+        .bonds
+        .iter()
+        .map(|bond| {
+            (BondClass::new(atoms, index, bond) as u8, labels[bond.tid])
+        })
+        .collect::<Vec<_>>();
+    let mut hasher = Fnv128::new();
+
+    neighbors.sort_unstable();
+    hasher.write_u128(labels[index]);
+    hasher.write_u8(atom.bonds.len() as u8);
+
+    for (class, label) in neighbors {
+        hasher.write_u8(class);
+        hasher.write_u128(label);
+    }
+
+    if layer >= HashLayer::Stereo {
+        let mut configurations = double_bonds
+            .iter()
+            .filter(|double_bond| {
+                double_bond.source == index || double_bond.target == index
+            })
+            .map(|double_bond| configuration(atoms, index, double_bond, labels))
+            .collect::<Vec<_>>();
+
+        configurations.sort_unstable();
+        hasher.write_u8(winding(atoms, index, labels));
+
+        for configuration in configurations {
+            hasher.write_u8(configuration)
+        }
+    }
+
+    hasher.finish()
+}
+
+fn configuration(
+    atoms: &[Atom],
+    index: usize,
+    double_bond: &DoubleBond,
+    labels: &[u128],
+) -> u8 {
+    let partner = if double_bond.source == index {
+        double_bond.target
+    } else {
+        double_bond.source
+    };
+    let (near, far) = match (
+        highest(atoms, index, partner, labels),
+        highest(atoms, partner, index, labels),
+    ) {
+        (Some(near), Some(far)) => (near, far),
+        _ => return 0,
+    };
+
+    match double_bond.configuration_between(index, near, far) {
+        Some(Configuration::Cis) => 1,
+        Some(Configuration::Trans) => 2,
+        None => 0,
+    }
+}
+
+/// Returns the neighbor of end other than partner with the highest label,
+/// or None if there is none or two share it.
+fn highest(
+    atoms: &[Atom],
+    end: usize,
+    partner: usize,
+    labels: &[u128],
+) -> Option<usize> {
+    let mut neighbors = atoms[end]
+        .bonds
+        .iter()
+        .map(|bond| bond.tid)
+        .filter(|tid| *tid != partner)
+        .collect::<Vec<_>>();
+
+    neighbors.sort_by_key(|tid| std::cmp::Reverse(labels[*tid]));
+
+    match neighbors.as_slice() {
+        [first, second, ..] if labels[*first] == labels[*second] => None,
+        [first, ..] => Some(*first),
+        [] => None,
+    }
+}
+
+fn winding(atoms: &[Atom], index: usize, labels: &[u128]) -> u8 {
+    let parity = match parity(&atoms[index].kind) {
+        Some(parity) => parity,
+        None => return 0,
+    };
+    let ligands = ligands(atoms, index)
+        .into_iter()
+        .map(|ligand| match ligand {
+            Ligand::Atom(ligand) => labels[ligand],
+            Ligand::Implicit => 0,
+        })
+        .collect::<Vec<_>>();
+    let mut inversions = 0;
+
+    for i in 0..ligands.len() {
+        for j in i + 1..ligands.len() {
+            if ligands[i] == ligands[j] {
+                return 0;
+            } else if ligands[i] > ligands[j] {
+                inversions += 1
+            }
+        }
+    }
+
+    match (parity, inversions % 2 == 0) {
+        (AtomParity::Counterclockwise, true)
+        | (AtomParity::Clockwise, false) => 1,
+        _ => 2,
+    }
+}
+
+fn distinct(labels: &[u128]) -> usize {
+    let mut labels = labels.to_vec();
+
+    labels.sort_unstable();
+    labels.dedup();
+
+    labels.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn hash_of(string: &str, layer: HashLayer) -> u128 {
+        molecule_hash(&atoms_of(string), layer)
+    }
+
+    #[test]
+    fn stable_empty() {
+        assert_eq!(
+            hash_of("", HashLayer::Connectivity),
+            0xf453019fdf83d94f7080396838a79b0f
+        )
+    }
+
+    #[test]
+    fn stable_ethanol() {
+        assert_eq!(
+            hash_of("CCO", HashLayer::Connectivity),
+            0x0594a4ba65c5eaf9447a93672ebd6d05
+        )
+    }
+
+    #[test]
+    fn stable_alanine() {
+        assert_eq!(
+            hash_of("N[C@@H](C)C(=O)O", HashLayer::Stereo),
+            0x535e69f77452c335ec46f485f25ecbf3
+        )
+    }
+
+    #[test]
+    fn order_independent() {
+        assert_eq!(
+            hash_of("OCC", HashLayer::Connectivity),
+            hash_of("CCO", HashLayer::Connectivity)
+        )
+    }
+
+    #[test]
+    fn isomers() {
+        assert_ne!(
+            hash_of("CCO", HashLayer::Connectivity),
+            hash_of("COC", HashLayer::Connectivity)
+        )
+    }
+
+    #[test]
+    fn bond_class() {
+        assert_ne!(
+            hash_of("C=CC=C", HashLayer::Connectivity),
+            hash_of("CC=CC", HashLayer::Connectivity)
+        )
+    }
+
+    #[test]
+    fn layers_differ() {
+        assert_ne!(
+            hash_of("C", HashLayer::Connectivity),
+            hash_of("C", HashLayer::ChargesAndIsotopes)
+        )
+    }
+
+    #[test]
+    fn isotope_ignored() {
+        assert_eq!(
+            hash_of("[13CH4]", HashLayer::Connectivity),
+            hash_of("C", HashLayer::Connectivity)
+        )
+    }
+
+    #[test]
+    fn isotope() {
+        assert_ne!(
+            hash_of("[13CH4]", HashLayer::ChargesAndIsotopes),
+            hash_of("C", HashLayer::ChargesAndIsotopes)
+        )
+    }
+
+    #[test]
+    fn charge_ignored() {
+        assert_eq!(
+            hash_of("C[NH3+]", HashLayer::Connectivity),
+            hash_of("C[NH3]", HashLayer::Connectivity)
+        )
+    }
+
+    #[test]
+    fn charge() {
+        assert_ne!(
+            hash_of("C[NH3+]", HashLayer::ChargesAndIsotopes),
+            hash_of("C[NH3]", HashLayer::ChargesAndIsotopes)
+        )
+    }
+
+    #[test]
+    fn cis_trans() {
+        assert_ne!(
+            hash_of("C/C=C/C", HashLayer::Stereo),
+            hash_of("C/C=C\\C", HashLayer::Stereo)
+        )
+    }
+
+    #[test]
+    fn cis_trans_order_independent() {
+        assert_eq!(
+            hash_of("F/C=C/Cl", HashLayer::Stereo),
+            hash_of("Cl/C=C/F", HashLayer::Stereo)
+        )
+    }
+
+    #[test]
+    fn cis_trans_other_neighbor() {
+        assert_eq!(
+            hash_of("C/C(F)=C/C", HashLayer::Stereo),
+            hash_of("C/C=C(\\F)C", HashLayer::Stereo)
+        )
+    }
+
+    #[test]
+    fn cis_trans_ignored() {
+        assert_eq!(
+            hash_of("C/C=C/C", HashLayer::ChargesAndIsotopes),
+            hash_of("C/C=C\\C", HashLayer::ChargesAndIsotopes)
+        )
+    }
+
+    #[test]
+    fn stereo_ignored() {
+        assert_eq!(
+            hash_of("N[C@@H](C)C(=O)O", HashLayer::ChargesAndIsotopes),
+            hash_of("N[C@H](C)C(=O)O", HashLayer::ChargesAndIsotopes)
+        )
+    }
+
+    #[test]
+    fn enantiomers() {
+        assert_ne!(
+            hash_of("N[C@@H](C)C(=O)O", HashLayer::Stereo),
+            hash_of("N[C@H](C)C(=O)O", HashLayer::Stereo)
+        )
+    }
+
+    #[test]
+    fn enantiomer_reordered() {
+        assert_eq!(
+            hash_of("N[C@@H](C)C(=O)O", HashLayer::Stereo),
+            hash_of("OC(=O)[C@@H](N)C", HashLayer::Stereo)
+        )
+    }
+
+    #[test]
+    fn symmetric_center() {
+        assert_eq!(
+            hash_of("C[C@H](C)O", HashLayer::Stereo),
+            hash_of("C[C@@H](C)O", HashLayer::Stereo)
+        )
+    }
+}
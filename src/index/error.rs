@@ -0,0 +1,25 @@
+use std::{convert, io};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The file doesn't start with the index signature.
+    Signature,
+    /// The file was written by an unsupported version of the format.
+    Version(u32),
+    /// A fingerprint's length differs from the index's.
+    Length {
+        expected: usize,
+        actual: usize,
+    },
+    /// A stored Balsa string isn't valid UTF-8.
+    Utf8,
+    /// The stored fingerprint length exceeds the largest the format reads.
+    FingerprintLength(u64),
+}
+
+impl convert::From<io::Error> for Error {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
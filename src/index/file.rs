@@ -0,0 +1,251 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, ErrorKind, Read, Write},
+    path::Path,
+};
+
+use super::{Error, Record, SimilarityIndex};
+use crate::fingerprint::BitVec;
+
+const SIGNATURE: &[u8; 8] = b"BALSAIDX";
+const VERSION: u32 = 1;
+const MAX_FINGERPRINT_LEN: u64 = 1 << 16;
+
+/// The binary format, all integers little-endian:
+///
+/// - header: the 8 bytes "BALSAIDX", version (u32, currently 1),
+///   fingerprint length in bits (u64), and record count (u64)
+/// - each record in insertion order: id (u64), Balsa string length in
+///   bytes (u32), the UTF-8 Balsa string, and the fingerprint as returned
+///   by BitVec::words (u64 each)
+///
+/// Reading rejects fingerprint lengths above 65,536 bits, and reads each
+/// string only as far as the file allows, so a damaged file gives an error
+/// rather than a large allocation.
+impl SimilarityIndex {
+    /// Writes the index to path, replacing any file there.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        self.write(&mut writer)?;
+
+        Ok(writer.flush()?)
+    }
+
+    /// Reads an index written by save.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Self::read(BufReader::new(File::open(path)?))
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_all(SIGNATURE)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&(self.fingerprint_len() as u64).to_le_bytes())?;
+        writer.write_all(&(self.len() as u64).to_le_bytes())?;
+
+        for (record, fingerprint) in self.records() {
+            writer.write_all(&record.id.to_le_bytes())?;
+            writer.write_all(&(record.balsa.len() as u32).to_le_bytes())?;
+            writer.write_all(record.balsa.as_bytes())?;
+
+            for word in fingerprint.words() {
+                writer.write_all(&word.to_le_bytes())?
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn read<R: Read>(mut reader: R) -> Result<Self, Error> {
+        let mut signature = [0; 8];
+
+        reader.read_exact(&mut signature)?;
+
+        if &signature != SIGNATURE {
+            return Err(Error::Signature);
+        }
+
+        let version = u32::from_le_bytes(bytes(&mut reader)?);
+
+        if version != VERSION {
+            return Err(Error::Version(version));
+        }
+
+        let len = match u64::from_le_bytes(bytes(&mut reader)?) {
+            len if len > MAX_FINGERPRINT_LEN => {
+                return Err(Error::FingerprintLength(len))
+            }
+            len => len as usize,
+        };
+        let count = u64::from_le_bytes(bytes(&mut reader)?);
+        let mut result = Self::new(len);
+
+        for _ in 0..count {
+            let id = u64::from_le_bytes(bytes(&mut reader)?);
+            let size = u32::from_le_bytes(bytes(&mut reader)?) as u64;
+            let mut balsa = Vec::new();
+
+            if (&mut reader).take(size).read_to_end(&mut balsa)? as u64 != size
+            {
+                return Err(Error::Io(ErrorKind::UnexpectedEof.into()));
+            }
+
+            let balsa = String::from_utf8(balsa).map_err(|_| Error::Utf8)?;
+            let words = (0..len.div_ceil(64))
+                .map(|_| bytes(&mut reader).map(u64::from_le_bytes))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            result
+                .insert(Record { id, balsa }, BitVec::from_words(len, words))?
+        }
+
+        Ok(result)
+    }
+}
+
+fn bytes<R: Read, const N: usize>(reader: &mut R) -> Result<[u8; N], Error> {
+    let mut result = [0; N];
+
+    reader.read_exact(&mut result)?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn index() -> SimilarityIndex {
+        let mut result = SimilarityIndex::new(70);
+        let mut first = BitVec::new(70);
+        let mut second = BitVec::new(70);
+
+        first.set(3, true);
+        first.set(69, true);
+        second.set(3, true);
+
+        for (id, balsa, fingerprint) in [(7, "CCO", first), (9, "C", second)] {
+            let record = Record {
+                id,
+                balsa: balsa.to_string(),
+            };
+
+            result.insert(record, fingerprint).unwrap()
+        }
+
+        result
+    }
+
+    fn bytes_of(index: &SimilarityIndex) -> Vec<u8> {
+        let mut result = Vec::new();
+
+        index.write(&mut result).unwrap();
+
+        result
+    }
+
+    #[test]
+    fn header() {
+        assert_eq!(
+            bytes_of(&SimilarityIndex::new(70)),
+            b"BALSAIDX\x01\0\0\0\x46\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0"
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let index = index();
+
+        assert_eq!(
+            SimilarityIndex::read(bytes_of(&index).as_slice()).unwrap(),
+            index
+        )
+    }
+
+    #[test]
+    fn save_and_load() {
+        let index = index();
+        let path = std::env::temp_dir()
+            .join(format!("balsa-index-{}.bin", std::process::id()));
+
+        index.save(&path).unwrap();
+
+        let loaded = SimilarityIndex::load(&path);
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.unwrap(), index)
+    }
+
+    #[test]
+    fn bad_signature() {
+        let mut bytes = bytes_of(&index());
+
+        bytes[0] = b'X';
+
+        assert!(matches!(
+            SimilarityIndex::read(bytes.as_slice()),
+            Err(Error::Signature)
+        ))
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let mut bytes = bytes_of(&index());
+
+        bytes[8] = 2;
+
+        assert!(matches!(
+            SimilarityIndex::read(bytes.as_slice()),
+            Err(Error::Version(2))
+        ))
+    }
+
+    #[test]
+    fn truncated() {
+        let bytes = bytes_of(&index());
+
+        assert!(matches!(
+            SimilarityIndex::read(&bytes[..bytes.len() - 1]),
+            Err(Error::Io(_))
+        ))
+    }
+
+    #[test]
+    fn implausible_fingerprint_length() {
+        let mut bytes = bytes_of(&index());
+
+        bytes[12..20].copy_from_slice(&u64::MAX.to_le_bytes());
+
+        assert!(matches!(
+            SimilarityIndex::read(bytes.as_slice()),
+            Err(Error::FingerprintLength(u64::MAX))
+        ))
+    }
+
+    #[test]
+    fn implausible_string_length() {
+        let mut bytes = bytes_of(&index());
+
+        bytes[28 + 8..28 + 12].copy_from_slice(&u32::MAX.to_le_bytes());
+
+        assert!(matches!(
+            SimilarityIndex::read(bytes.as_slice()),
+            Err(Error::Io(_))
+        ))
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut bytes = bytes_of(&index());
+
+        // The first record's string follows the header, id, and length.
+        bytes[28 + 8 + 4] = 0xff;
+
+        assert!(matches!(
+            SimilarityIndex::read(bytes.as_slice()),
+            Err(Error::Utf8)
+        ))
+    }
+}
@@ -0,0 +1,9 @@
+use super::Record;
+
+/// A record found by a similarity search.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Hit<'a> {
+    pub record: &'a Record,
+    /// Tanimoto similarity to the query.
+    pub similarity: f64,
+}
@@ -0,0 +1,12 @@
+mod error;
+mod file;
+mod hit;
+mod record;
+mod similarity_index;
+mod similarity_options;
+
+pub use error::Error;
+pub use hit::Hit;
+pub use record::Record;
+pub use similarity_index::SimilarityIndex;
+pub use similarity_options::SimilarityOptions;
@@ -0,0 +1,44 @@
+use crate::{
+    follow::Writer,
+    graph::{walk, Atom},
+};
+
+/// An entry of a SimilarityIndex.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Record {
+    /// Chosen by the caller, not necessarily unique.
+    pub id: u64,
+    pub balsa: String,
+}
+
+impl Record {
+    /// Returns a record of atoms written by follow::Writer.
+    pub fn new(id: u64, atoms: &[Atom]) -> Self {
+        let mut writer = Writer::new();
+
+        walk(atoms, &mut writer);
+
+        Self {
+            id,
+            balsa: writer.write(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            Record::new(7, &atoms_of("OCC")),
+            Record {
+                id: 7,
+                balsa: "OCC".to_string()
+            }
+        )
+    }
+}
@@ -0,0 +1,459 @@
+use std::{cmp::Ordering, thread};
+
+use super::{Error, Hit, Record, SimilarityOptions};
+use crate::fingerprint::BitVec;
+
+/// An in-memory collection of records searched by the Tanimoto similarity
+/// of their fingerprints. Records are kept in buckets by fingerprint
+/// popcount, and searches skip buckets whose BitBound, the smaller of two
+/// popcounts over the larger, rules them out.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SimilarityIndex {
+    fingerprint_len: usize,
+    buckets: Vec<Vec<Entry>>,
+    count: usize,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Entry {
+    position: usize,
+    record: Record,
+    fingerprint: BitVec,
+}
+
+struct Candidate<'a> {
+    entry: &'a Entry,
+    similarity: f64,
+}
+
+impl SimilarityIndex {
+    /// Returns an empty index of fingerprints of len bits.
+    pub fn new(len: usize) -> Self {
+        Self {
+            fingerprint_len: len,
+            buckets: vec![Vec::new(); len + 1],
+            count: 0,
+        }
+    }
+
+    /// Returns the number of records.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn fingerprint_len(&self) -> usize {
+        self.fingerprint_len
+    }
+
+    /// Adds record with its fingerprint.
+    pub fn insert(
+        &mut self,
+        record: Record,
+        fingerprint: BitVec,
+    ) -> Result<(), Error> {
+        self.check(&fingerprint)?;
+
+        self.buckets[fingerprint.count_ones() as usize].push(Entry {
+            position: self.count,
+            record,
+            fingerprint,
+        });
+        self.count += 1;
+
+        Ok(())
+    }
+
+    /// Returns records and their fingerprints in insertion order.
+    pub fn records(&self) -> Vec<(&Record, &BitVec)> {
+        let mut entries = self.buckets.iter().flatten().collect::<Vec<_>>();
+
+        entries.sort_by_key(|entry| entry.position);
+
+        entries
+            .into_iter()
+            .map(|entry| (&entry.record, &entry.fingerprint))
+            .collect()
+    }
+
+    /// Returns the k records most similar to query, most similar first and
+    /// ties in insertion order.
+    pub fn top_k(
+        &self,
+        query: &BitVec,
+        k: usize,
+        options: &SimilarityOptions,
+    ) -> Result<Vec<Hit<'_>>, Error> {
+        self.check(query)?;
+
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+
+        let popcount = query.count_ones() as usize;
+        let mut order = (0..self.buckets.len())
+            .map(|bucket| (bound(popcount, bucket), bucket))
+            .collect::<Vec<_>>();
+
+        order.sort_by(|first, second| {
+            second.0.total_cmp(&first.0).then(first.1.cmp(&second.1))
+        });
+
+        let mut result = run(options.threads, |part, parts| {
+            let mut result = Vec::new();
+
+            for (bound, bucket) in &order {
+                if result.len() == k {
+                    let last: &Candidate = &result[k - 1];
+
+                    if last.similarity > *bound {
+                        break;
+                    }
+                }
+
+                result.extend(self.score(query, *bucket, part, parts));
+                result.sort_by(compare);
+                result.truncate(k);
+            }
+
+            result
+        });
+
+        result.sort_by(compare);
+        result.truncate(k);
+
+        Ok(hits(result))
+    }
+
+    /// Returns the records with similarity to query of at least threshold,
+    /// most similar first and ties in insertion order.
+    pub fn threshold(
+        &self,
+        query: &BitVec,
+        threshold: f64,
+        options: &SimilarityOptions,
+    ) -> Result<Vec<Hit<'_>>, Error> {
+        self.check(query)?;
+
+        let popcount = query.count_ones() as usize;
+        let buckets = (0..self.buckets.len())
+            .filter(|bucket| bound(popcount, *bucket) >= threshold)
+            .collect::<Vec<_>>();
+        let mut result = run(options.threads, |part, parts| {
+            buckets
+                .iter()
+                .flat_map(|bucket| self.score(query, *bucket, part, parts))
+                .filter(|candidate| candidate.similarity >= threshold)
+                .collect()
+        });
+
+        result.sort_by(compare);
+
+        Ok(hits(result))
+    }
+
+    fn check(&self, fingerprint: &BitVec) -> Result<(), Error> {
+        if fingerprint.len() == self.fingerprint_len {
+            Ok(())
+        } else {
+            Err(Error::Length {
+                expected: self.fingerprint_len,
+                actual: fingerprint.len(),
+            })
+        }
+    }
+
+    /// Scores part of parts equal slices of bucket.
+    fn score(
+        &self,
+        query: &BitVec,
+        bucket: usize,
+        part: usize,
+        parts: usize,
+    ) -> Vec<Candidate<'_>> {
+        let entries = &self.buckets[bucket];
+        let start = entries.len() * part / parts;
+        let end = entries.len() * (part + 1) / parts;
+        let popcount = query.count_ones() as usize;
+
+        entries[start..end]
+            .iter()
+            .map(|entry| {
+                let common = query.and_count(&entry.fingerprint) as usize;
+                let union = popcount + bucket - common;
+
+                Candidate {
+                    entry,
+                    similarity: if union == 0 {
+                        0.
+                    } else {
+                        common as f64 / union as f64
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the highest Tanimoto similarity possible between fingerprints
+/// with these popcounts.
+fn bound(first: usize, second: usize) -> f64 {
+    match first.max(second) {
+        0 => 0.,
+        max => first.min(second) as f64 / max as f64,
+    }
+}
+
+fn compare(first: &Candidate, second: &Candidate) -> Ordering {
+    second
+        .similarity
+        .total_cmp(&first.similarity)
+        .then(first.entry.position.cmp(&second.entry.position))
+}
+
+fn hits(candidates: Vec<Candidate>) -> Vec<Hit> {
+    candidates
+        .into_iter()
+        .map(|candidate| Hit {
+            record: &candidate.entry.record,
+            similarity: candidate.similarity,
+        })
+        .collect()
+}
+
+/// Calls search with each part and the number of parts, on threads if
+/// more than one, and joins the results.
+fn run<'a, F>(threads: usize, search: F) -> Vec<Candidate<'a>>
+where
+    F: Fn(usize, usize) -> Vec<Candidate<'a>> + Sync,
+{
+    if threads <= 1 {
+        return search(0, 1);
+    }
+
+    thread::scope(|scope| {
+        let handles = (0..threads)
+            .map(|part| {
+                let search = &search;
+
+                scope.spawn(move || search(part, threads))
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("search thread"))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fingerprint::{morgan_bits, tanimoto, MorganOptions},
+        read::atoms_of,
+    };
+    use pretty_assertions::assert_eq;
+
+    const OPTIONS: MorganOptions = MorganOptions {
+        radius: 2,
+        len: 256,
+    };
+
+    const STRINGS: [&str; 10] = [
+        "CCO",
+        "CCCO",
+        "CCCCO",
+        "c1ccccc1",
+        "c1ccccc1O",
+        "c1ccccc1CO",
+        "CC(=O)O",
+        "CCN",
+        "C",
+        "CCO",
+    ];
+
+    fn fingerprint(string: &str) -> BitVec {
+        morgan_bits(&atoms_of(string), &OPTIONS)
+    }
+
+    fn index() -> SimilarityIndex {
+        let mut result = SimilarityIndex::new(OPTIONS.len);
+
+        for (id, string) in STRINGS.iter().enumerate() {
+            let record = Record {
+                id: id as u64,
+                balsa: string.to_string(),
+            };
+
+            result.insert(record, fingerprint(string)).unwrap()
+        }
+
+        result
+    }
+
+    fn ids(hits: Vec<Hit>) -> Vec<u64> {
+        hits.iter().map(|hit| hit.record.id).collect()
+    }
+
+    /// Ranks every record by brute force.
+    fn ranking(query: &BitVec) -> Vec<(u64, f64)> {
+        let mut result = STRINGS
+            .iter()
+            .enumerate()
+            .map(|(id, string)| {
+                (id as u64, tanimoto(query, &fingerprint(string)))
+            })
+            .collect::<Vec<_>>();
+
+        result.sort_by(|first, second| second.1.total_cmp(&first.1));
+
+        result
+    }
+
+    #[test]
+    fn insert_wrong_length() {
+        let mut index = SimilarityIndex::new(256);
+        let record = Record {
+            id: 0,
+            balsa: "C".to_string(),
+        };
+
+        assert!(matches!(
+            index.insert(record, BitVec::new(128)),
+            Err(Error::Length {
+                expected: 256,
+                actual: 128
+            })
+        ))
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(index().len(), 10)
+    }
+
+    #[test]
+    fn records() {
+        let index = index();
+        let strings = index
+            .records()
+            .iter()
+            .map(|(record, _)| record.balsa.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(strings, STRINGS)
+    }
+
+    #[test]
+    fn top_k_zero() {
+        let index = index();
+
+        assert_eq!(
+            index
+                .top_k(&fingerprint("CCO"), 0, &Default::default())
+                .unwrap(),
+            vec![]
+        )
+    }
+
+    #[test]
+    fn top_k_ties() {
+        let index = index();
+        let hits = index
+            .top_k(&fingerprint("CCO"), 2, &Default::default())
+            .unwrap();
+
+        assert_eq!(
+            hits.iter()
+                .map(|hit| (hit.record.id, hit.similarity))
+                .collect::<Vec<_>>(),
+            vec![(0, 1.), (9, 1.)]
+        )
+    }
+
+    #[test]
+    fn top_k_brute_force() {
+        let index = index();
+
+        for string in ["CCO", "c1ccccc1C", "CC(=O)N", "C"] {
+            let query = fingerprint(string);
+
+            for k in 1..=10 {
+                let hits = index.top_k(&query, k, &Default::default()).unwrap();
+                let expected = ranking(&query)
+                    .iter()
+                    .take(k)
+                    .map(|(_, similarity)| *similarity)
+                    .collect::<Vec<_>>();
+
+                assert_eq!(
+                    hits.iter().map(|hit| hit.similarity).collect::<Vec<_>>(),
+                    expected
+                )
+            }
+        }
+    }
+
+    #[test]
+    fn top_k_threads() {
+        let index = index();
+        let query = fingerprint("c1ccccc1C");
+        let threads = SimilarityOptions { threads: 3 };
+
+        assert_eq!(
+            ids(index.top_k(&query, 4, &threads).unwrap()),
+            ids(index.top_k(&query, 4, &Default::default()).unwrap())
+        )
+    }
+
+    #[test]
+    fn threshold_brute_force() {
+        let index = index();
+
+        for string in ["CCO", "c1ccccc1C", "CC(=O)N", "C"] {
+            let query = fingerprint(string);
+
+            for threshold in [0., 0.2, 0.5, 1.] {
+                let hits = index
+                    .threshold(&query, threshold, &Default::default())
+                    .unwrap();
+                let mut expected = ranking(&query)
+                    .into_iter()
+                    .filter(|(_, similarity)| *similarity >= threshold)
+                    .map(|(id, _)| id)
+                    .collect::<Vec<_>>();
+                let mut actual = ids(hits);
+
+                expected.sort();
+                actual.sort();
+
+                assert_eq!(actual, expected)
+            }
+        }
+    }
+
+    #[test]
+    fn threshold_threads() {
+        let index = index();
+        let query = fingerprint("CCCO");
+        let threads = SimilarityOptions { threads: 4 };
+
+        assert_eq!(
+            ids(index.threshold(&query, 0.1, &threads).unwrap()),
+            ids(index.threshold(&query, 0.1, &Default::default()).unwrap())
+        )
+    }
+
+    #[test]
+    fn query_wrong_length() {
+        assert!(matches!(
+            index().threshold(&BitVec::new(8), 0.5, &Default::default()),
+            Err(Error::Length { .. })
+        ))
+    }
+}
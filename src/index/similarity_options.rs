@@ -0,0 +1,13 @@
+/// Controls SimilarityIndex searches.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SimilarityOptions {
+    /// Threads scoring records, 1 to search on the calling thread. Results
+    /// don't depend on it.
+    pub threads: usize,
+}
+
+impl Default for SimilarityOptions {
+    fn default() -> Self {
+        Self { threads: 1 }
+    }
+}
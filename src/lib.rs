@@ -1,5 +1,18 @@
+pub mod cluster;
+pub mod descriptor;
 pub mod feature;
+pub mod fingerprint;
 pub mod follow;
+pub mod formula;
 pub mod graph;
+pub mod hash;
+pub mod index;
+pub mod mass;
+pub mod query;
 pub mod read;
+pub mod search;
+pub mod standardize;
+pub mod stereo;
+pub mod tautomer;
 pub mod tree;
+pub mod validate;
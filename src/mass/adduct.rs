@@ -0,0 +1,88 @@
+use std::fmt;
+
+use crate::feature::Element;
+
+/// Ionization state used to compute m/z.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum Adduct {
+    /// The molecule as written, ionized only by its own formal charge.
+    M,
+    MPlusH,
+    MPlusNa,
+    MPlusK,
+    MPlusNH4,
+    MPlus2H,
+    MMinusH,
+    MPlusCl,
+}
+
+impl Adduct {
+    /// Returns the charge the adduct adds to the molecule.
+    pub fn charge(&self) -> i32 {
+        match self {
+            Self::M => 0,
+            Self::MPlusH | Self::MPlusNa | Self::MPlusK | Self::MPlusNH4 => 1,
+            Self::MPlus2H => 2,
+            Self::MMinusH | Self::MPlusCl => -1,
+        }
+    }
+
+    /// Returns the monoisotopic mass of the atoms the adduct adds to the
+    /// molecule, or removes if negative, without correcting for electrons.
+    pub fn mass(&self) -> f64 {
+        let hydrogen = Element::H.monoisotopic_mass();
+
+        match self {
+            Self::M => 0.,
+            Self::MPlusH => hydrogen,
+            Self::MPlusNa => Element::Na.monoisotopic_mass(),
+            Self::MPlusK => Element::K.monoisotopic_mass(),
+            Self::MPlusNH4 => Element::N.monoisotopic_mass() + 4. * hydrogen,
+            Self::MPlus2H => 2. * hydrogen,
+            Self::MMinusH => -hydrogen,
+            Self::MPlusCl => Element::Cl.monoisotopic_mass(),
+        }
+    }
+}
+
+impl fmt::Display for Adduct {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::M => "[M]",
+            Self::MPlusH => "[M+H]+",
+            Self::MPlusNa => "[M+Na]+",
+            Self::MPlusK => "[M+K]+",
+            Self::MPlusNH4 => "[M+NH4]+",
+            Self::MPlus2H => "[M+2H]2+",
+            Self::MMinusH => "[M-H]-",
+            Self::MPlusCl => "[M+Cl]-",
+        })
+    }
+}
+
+#[cfg(test)]
+mod charge {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn m_plus_2h() {
+        assert_eq!(Adduct::MPlus2H.charge(), 2)
+    }
+
+    #[test]
+    fn m_minus_h() {
+        assert_eq!(Adduct::MMinusH.charge(), -1)
+    }
+}
+
+#[cfg(test)]
+mod to_string {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn m_plus_na() {
+        assert_eq!(Adduct::MPlusNa.to_string(), "[M+Na]+")
+    }
+}
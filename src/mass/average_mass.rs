@@ -0,0 +1,55 @@
+use super::{sum::sum, Error};
+use crate::{formula::formula, graph::Atom};
+
+/// Returns the average molecular mass of atoms in daltons. Unlabelled atoms
+/// use standard atomic weights and labelled atoms their isotope mass.
+pub fn average_mass(atoms: &[Atom]) -> Result<f64, Error> {
+    sum(&formula(atoms), |element| element.atomic_weight())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        feature::{Element, Isotope},
+        read::atoms_of,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn average_mass_of(string: &str) -> Result<String, Error> {
+        average_mass(&atoms_of(string)).map(|mass| format!("{:.3}", mass))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(average_mass_of(""), Ok("0.000".to_string()))
+    }
+
+    #[test]
+    fn star() {
+        assert_eq!(average_mass_of("*C"), Err(Error::Star))
+    }
+
+    #[test]
+    fn water() {
+        assert_eq!(average_mass_of("O"), Ok("18.015".to_string()))
+    }
+
+    #[test]
+    fn benzene() {
+        assert_eq!(average_mass_of("c1ccccc1"), Ok("78.114".to_string()))
+    }
+
+    #[test]
+    fn labelled() {
+        assert_eq!(average_mass_of("[13CH4]"), Ok("17.035".to_string()))
+    }
+
+    #[test]
+    fn unknown_isotope() {
+        assert_eq!(
+            average_mass_of("[99CH4]"),
+            Err(Error::UnknownIsotope(Element::C, Isotope::new(99).unwrap()))
+        )
+    }
+}
@@ -0,0 +1,11 @@
+use crate::feature::{Element, Isotope};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Error {
+    /// An atom carries an isotope unknown for its element.
+    UnknownIsotope(Element, Isotope),
+    /// A Star atom, which has no mass, is present.
+    Star,
+    /// The ion has no net charge, so m/z is undefined.
+    Uncharged,
+}
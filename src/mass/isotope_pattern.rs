@@ -0,0 +1,271 @@
+use super::{Error, PatternOptions, Peak};
+use crate::{
+    feature::{Element, Isotope},
+    formula::formula,
+    graph::Atom,
+};
+
+const PRECISION: f64 = 0.001;
+
+/// Returns the isotope pattern of atoms in order of increasing mass.
+///
+/// The pattern is the convolution of the natural isotope distribution of
+/// each unlabelled atom. Labelled atoms contribute only their isotope. Masses
+/// are those of the neutral atoms, as with monoisotopic_mass.
+pub fn isotope_pattern(
+    atoms: &[Atom],
+    options: &PatternOptions,
+) -> Result<Vec<Peak>, Error> {
+    let formula = formula(atoms);
+
+    if formula.stars() > 0 {
+        return Err(Error::Star);
+    } else if formula.entries().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut result = vec![Peak {
+        mass: 0.,
+        intensity: 1.,
+    }];
+
+    for (element, isotope, count) in formula.entries() {
+        let distribution = distribution(element, isotope)?;
+        let power = power(distribution, count, options);
+
+        result = convolve(&result, &power, options);
+    }
+
+    let max = most_intense(&result);
+
+    result.retain(|peak| peak.intensity >= max * options.threshold);
+
+    for peak in result.iter_mut() {
+        peak.intensity *= 100. / max;
+    }
+
+    Ok(result)
+}
+
+fn distribution(
+    element: &Element,
+    isotope: Option<&Isotope>,
+) -> Result<Vec<Peak>, Error> {
+    if let Some(isotope) = isotope {
+        return match element.isotope(isotope.into()) {
+            Some(nuclide) => Ok(vec![Peak {
+                mass: nuclide.mass,
+                intensity: 1.,
+            }]),
+            None => {
+                Err(Error::UnknownIsotope(element.clone(), isotope.clone()))
+            }
+        };
+    }
+
+    let result = element
+        .isotopes()
+        .iter()
+        .filter(|nuclide| nuclide.abundance > 0.)
+        .map(|nuclide| Peak {
+            mass: nuclide.mass,
+            intensity: nuclide.abundance,
+        })
+        .collect::<Vec<_>>();
+
+    if result.is_empty() {
+        Ok(vec![Peak {
+            mass: element.monoisotopic_mass(),
+            intensity: 1.,
+        }])
+    } else {
+        Ok(result)
+    }
+}
+
+fn power(
+    distribution: Vec<Peak>,
+    mut count: usize,
+    options: &PatternOptions,
+) -> Vec<Peak> {
+    let mut result = vec![Peak {
+        mass: 0.,
+        intensity: 1.,
+    }];
+    let mut base = distribution;
+
+    while count > 0 {
+        if count & 1 == 1 {
+            result = convolve(&result, &base, options);
+        }
+
+        count >>= 1;
+
+        if count > 0 {
+            base = convolve(&base, &base, options);
+        }
+    }
+
+    result
+}
+
+fn convolve(a: &[Peak], b: &[Peak], options: &PatternOptions) -> Vec<Peak> {
+    let mut peaks = Vec::with_capacity(a.len() * b.len());
+
+    for x in a {
+        for y in b {
+            peaks.push(Peak {
+                mass: x.mass + y.mass,
+                intensity: x.intensity * y.intensity,
+            })
+        }
+    }
+
+    peaks.sort_by(|x, y| x.mass.total_cmp(&y.mass));
+
+    let mut result: Vec<Peak> = Vec::new();
+
+    for peak in peaks {
+        match result.last_mut() {
+            Some(last) if peak.mass - last.mass <= options.resolution => {
+                let intensity = last.intensity + peak.intensity;
+
+                last.mass = (last.mass * last.intensity
+                    + peak.mass * peak.intensity)
+                    / intensity;
+                last.intensity = intensity;
+            }
+            _ => result.push(peak),
+        }
+    }
+
+    // Intermediate distributions are pruned more leniently than the result
+    // so that many minor contributions can still add up to a visible peak.
+    let cutoff = most_intense(&result) * options.threshold * PRECISION;
+
+    result.retain(|peak| peak.intensity >= cutoff);
+
+    result
+}
+
+fn most_intense(peaks: &[Peak]) -> f64 {
+    peaks.iter().map(|peak| peak.intensity).fold(0., f64::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn pattern_of(
+        string: &str,
+        options: &PatternOptions,
+    ) -> Result<Vec<String>, Error> {
+        isotope_pattern(&atoms_of(string), options).map(|peaks| {
+            peaks
+                .iter()
+                .map(|peak| format!("{:.4} {:.2}", peak.mass, peak.intensity))
+                .collect()
+        })
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(pattern_of("", &PatternOptions::default()), Ok(vec![]))
+    }
+
+    #[test]
+    fn star() {
+        assert_eq!(
+            pattern_of("*C", &PatternOptions::default()),
+            Err(Error::Star)
+        )
+    }
+
+    #[test]
+    fn unknown_isotope() {
+        assert_eq!(
+            pattern_of("[99CH4]", &PatternOptions::default()),
+            Err(Error::UnknownIsotope(Element::C, Isotope::new(99).unwrap()))
+        )
+    }
+
+    #[test]
+    fn methane() {
+        assert_eq!(
+            pattern_of("C", &PatternOptions::default()),
+            Ok(vec![
+                "16.0313 100.00".to_string(),
+                "17.0348 1.13".to_string()
+            ])
+        )
+    }
+
+    #[test]
+    fn labelled_methane() {
+        assert_eq!(
+            pattern_of("[13CH4]", &PatternOptions::default()),
+            Ok(vec!["17.0347 100.00".to_string()])
+        )
+    }
+
+    #[test]
+    fn hydrogen_chloride() {
+        assert_eq!(
+            pattern_of("Cl", &PatternOptions::default()),
+            Ok(vec![
+                "35.9767 100.00".to_string(),
+                "37.9737 32.00".to_string(),
+            ])
+        )
+    }
+
+    #[test]
+    fn caffeine() {
+        assert_eq!(
+            pattern_of(
+                "Cn1cnc2c1c(=O)n(C)c(=O)n2C",
+                &PatternOptions::default()
+            ),
+            Ok(vec![
+                "194.0804 100.00".to_string(),
+                "195.0829 10.31".to_string(),
+                "196.0850 0.89".to_string(),
+            ])
+        )
+    }
+
+    #[test]
+    fn fine_structure() {
+        let options = PatternOptions {
+            resolution: 0.0001,
+            threshold: 0.001,
+        };
+
+        assert_eq!(
+            pattern_of("C=O", &options),
+            Ok(vec![
+                "30.0106 100.00".to_string(),
+                "31.0139 1.08".to_string(),
+                "32.0148 0.21".to_string(),
+            ])
+        )
+    }
+
+    #[test]
+    fn pruned() {
+        let options = PatternOptions {
+            resolution: 0.5,
+            threshold: 0.05,
+        };
+
+        assert_eq!(
+            pattern_of("Cn1cnc2c1c(=O)n(C)c(=O)n2C", &options),
+            Ok(vec![
+                "194.0804 100.00".to_string(),
+                "195.0829 10.31".to_string(),
+            ])
+        )
+    }
+}
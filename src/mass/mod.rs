@@ -0,0 +1,18 @@
+mod adduct;
+mod average_mass;
+mod error;
+mod isotope_pattern;
+mod monoisotopic_mass;
+mod mz;
+mod pattern_options;
+mod peak;
+mod sum;
+
+pub use adduct::Adduct;
+pub use average_mass::average_mass;
+pub use error::Error;
+pub use isotope_pattern::isotope_pattern;
+pub use monoisotopic_mass::monoisotopic_mass;
+pub use mz::{mz, ELECTRON_MASS};
+pub use pattern_options::PatternOptions;
+pub use peak::Peak;
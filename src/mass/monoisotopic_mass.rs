@@ -0,0 +1,62 @@
+use super::{sum::sum, Error};
+use crate::{formula::formula, graph::Atom};
+
+/// Returns the monoisotopic mass of atoms in daltons. Unlabelled atoms use
+/// the mass of their most abundant isotope and labelled atoms the mass of
+/// their isotope.
+pub fn monoisotopic_mass(atoms: &[Atom]) -> Result<f64, Error> {
+    sum(&formula(atoms), |element| element.monoisotopic_mass())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        feature::{Element, Isotope},
+        read::atoms_of,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn monoisotopic_mass_of(string: &str) -> Result<String, Error> {
+        monoisotopic_mass(&atoms_of(string)).map(|mass| format!("{:.5}", mass))
+    }
+
+    #[test]
+    fn star() {
+        assert_eq!(monoisotopic_mass_of("[*]"), Err(Error::Star))
+    }
+
+    #[test]
+    fn water() {
+        assert_eq!(monoisotopic_mass_of("O"), Ok("18.01056".to_string()))
+    }
+
+    #[test]
+    fn caffeine() {
+        assert_eq!(
+            monoisotopic_mass_of("Cn1cnc2c1c(=O)n(C)c(=O)n2C"),
+            Ok("194.08038".to_string())
+        )
+    }
+
+    #[test]
+    fn deuterated() {
+        assert_eq!(
+            monoisotopic_mass_of("[2H]C([2H])([2H])O"),
+            Ok("35.04504".to_string())
+        )
+    }
+
+    #[test]
+    fn radiolabel() {
+        assert_eq!(monoisotopic_mass_of("[14CH4]"), Ok("18.03454".to_string()))
+    }
+
+    #[test]
+    fn unknown_isotope() {
+        assert_eq!(
+            monoisotopic_mass_of("[7H]"),
+            Err(Error::UnknownIsotope(Element::H, Isotope::new(7).unwrap()))
+        )
+    }
+}
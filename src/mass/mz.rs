@@ -0,0 +1,75 @@
+use super::{sum::sum, Adduct, Error};
+use crate::{formula::formula, graph::Atom};
+
+/// Rest mass of the electron in daltons.
+pub const ELECTRON_MASS: f64 = 0.000548579909;
+
+/// Returns the monoisotopic mass-to-charge ratio of atoms ionized as adduct.
+/// The charge state combines the formal charge of atoms with the charge of
+/// adduct.
+pub fn mz(atoms: &[Atom], adduct: &Adduct) -> Result<f64, Error> {
+    let formula = formula(atoms);
+    let mass = sum(&formula, |element| element.monoisotopic_mass())?;
+    let charge = formula.charge() + adduct.charge();
+
+    if charge == 0 {
+        return Err(Error::Uncharged);
+    }
+
+    Ok((mass + adduct.mass() - charge as f64 * ELECTRON_MASS)
+        / charge.abs() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    const CAFFEINE: &str = "Cn1cnc2c1c(=O)n(C)c(=O)n2C";
+
+    fn mz_of(string: &str, adduct: &Adduct) -> Result<String, Error> {
+        mz(&atoms_of(string), adduct).map(|mz| format!("{:.4}", mz))
+    }
+
+    #[test]
+    fn uncharged() {
+        assert_eq!(mz_of(CAFFEINE, &Adduct::M), Err(Error::Uncharged))
+    }
+
+    #[test]
+    fn star() {
+        assert_eq!(mz_of("*", &Adduct::MPlusH), Err(Error::Star))
+    }
+
+    #[test]
+    fn intrinsic_cation() {
+        assert_eq!(mz_of("C[N+](C)(C)C", &Adduct::M), Ok("74.0964".to_string()))
+    }
+
+    #[test]
+    fn protonated() {
+        assert_eq!(mz_of(CAFFEINE, &Adduct::MPlusH), Ok("195.0877".to_string()))
+    }
+
+    #[test]
+    fn sodiated() {
+        assert_eq!(
+            mz_of(CAFFEINE, &Adduct::MPlusNa),
+            Ok("217.0696".to_string())
+        )
+    }
+
+    #[test]
+    fn doubly_protonated() {
+        assert_eq!(mz_of(CAFFEINE, &Adduct::MPlus2H), Ok("98.0475".to_string()))
+    }
+
+    #[test]
+    fn deprotonated() {
+        assert_eq!(
+            mz_of("CC(=O)O", &Adduct::MMinusH),
+            Ok("59.0139".to_string())
+        )
+    }
+}
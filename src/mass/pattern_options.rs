@@ -0,0 +1,20 @@
+/// Controls the calculation of isotope patterns.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PatternOptions {
+    /// Peaks whose masses lie within resolution daltons of each other are
+    /// merged into a single peak at their intensity-weighted mean mass. The
+    /// default of 0.5 yields one peak per nominal mass.
+    pub resolution: f64,
+    /// Peaks less intense than threshold times the most intense peak are
+    /// discarded from the result.
+    pub threshold: f64,
+}
+
+impl Default for PatternOptions {
+    fn default() -> Self {
+        Self {
+            resolution: 0.5,
+            threshold: 0.001,
+        }
+    }
+}
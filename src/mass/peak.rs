@@ -0,0 +1,7 @@
+/// A peak of an isotope pattern. Mass is given in daltons and intensity
+/// relative to the most intense peak of the pattern, which is 100.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Peak {
+    pub mass: f64,
+    pub intensity: f64,
+}
@@ -0,0 +1,34 @@
+use super::Error;
+use crate::{feature::Element, formula::Formula};
+
+/// Sums the masses of formula, taking unlabelled masses from unlabelled and
+/// labelled masses from the isotope table.
+pub fn sum(
+    formula: &Formula,
+    unlabelled: impl Fn(&Element) -> f64,
+) -> Result<f64, Error> {
+    if formula.stars() > 0 {
+        return Err(Error::Star);
+    }
+
+    let mut result = 0.;
+
+    for (element, isotope, count) in formula.entries() {
+        let mass = match isotope {
+            Some(isotope) => match element.isotope(isotope.into()) {
+                Some(nuclide) => nuclide.mass,
+                None => {
+                    return Err(Error::UnknownIsotope(
+                        element.clone(),
+                        isotope.clone(),
+                    ))
+                }
+            },
+            None => unlabelled(element),
+        };
+
+        result += mass * count as f64;
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,76 @@
+use std::{fmt, fmt::Write};
+
+use crate::feature::{Element, Selection};
+
+/// A test applied to a single target atom.
+#[derive(Debug, PartialEq, Clone)]
+pub enum AtomPrimitive {
+    /// Any atom, written `*`.
+    Star,
+    /// An unselected atom of the element, written as its symbol. Hydrogen is
+    /// written `#1` because `H` denotes a hydrogen count.
+    Element(Element),
+    /// A selected atom of the element, written in lowercase.
+    Selection(Selection),
+    /// An atom of the element with this atomic number, selected or not,
+    /// written `#n`.
+    AtomicNumber(u8),
+    /// A selected atom, written `a`.
+    Aromatic,
+    /// An unselected atom, written `A`.
+    Aliphatic,
+    /// An atom with this many bonded neighbors, written `Dn`.
+    Degree(u8),
+    /// An atom with this many hydrogens, virtual, implicit, or as bonded
+    /// atoms, written `Hn`.
+    Hydrogens(u8),
+    /// An atom on a ring, written `R`.
+    Ring,
+    /// An atom with this formal charge, written `+n` or `-n`.
+    Charge(i8),
+}
+
+impl fmt::Display for AtomPrimitive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Star => f.write_char('*'),
+            Self::Element(Element::H) => f.write_str("#1"),
+            Self::Element(element) => element.fmt(f),
+            Self::Selection(selection) => selection.fmt(f),
+            Self::AtomicNumber(number) => write!(f, "#{}", number),
+            Self::Aromatic => f.write_char('a'),
+            Self::Aliphatic => f.write_char('A'),
+            Self::Degree(degree) => write!(f, "D{}", degree),
+            Self::Hydrogens(hydrogens) => write!(f, "H{}", hydrogens),
+            Self::Ring => f.write_char('R'),
+            Self::Charge(charge) if *charge < 0 => write!(f, "-{}", -charge),
+            Self::Charge(charge) => write!(f, "+{}", charge),
+        }
+    }
+}
+
+#[cfg(test)]
+mod to_string {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn hydrogen() {
+        assert_eq!(AtomPrimitive::Element(Element::H).to_string(), "#1")
+    }
+
+    #[test]
+    fn chlorine() {
+        assert_eq!(AtomPrimitive::Element(Element::Cl).to_string(), "Cl")
+    }
+
+    #[test]
+    fn anion() {
+        assert_eq!(AtomPrimitive::Charge(-2).to_string(), "-2")
+    }
+
+    #[test]
+    fn neutral() {
+        assert_eq!(AtomPrimitive::Charge(0).to_string(), "+0")
+    }
+}
@@ -0,0 +1,31 @@
+use std::{fmt, fmt::Write};
+
+/// A test applied to a single target bond.
+#[derive(Debug, PartialEq, Clone)]
+pub enum BondPrimitive {
+    /// A single bond, written `-`.
+    Single,
+    /// A double bond, written `=`.
+    Double,
+    /// A triple bond, written `#`.
+    Triple,
+    /// An aromatic bond, written `:`.
+    Aromatic,
+    /// Any bond, written `~`.
+    Any,
+    /// A bond on a ring, written `@`. Chain bonds are written `!@`.
+    Ring,
+}
+
+impl fmt::Display for BondPrimitive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char(match self {
+            Self::Single => '-',
+            Self::Double => '=',
+            Self::Triple => '#',
+            Self::Aromatic => ':',
+            Self::Any => '~',
+            Self::Ring => '@',
+        })
+    }
+}
@@ -0,0 +1,29 @@
+use std::convert;
+
+use crate::{feature::Bridge, read};
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    EndOfLine,
+    Character(usize),
+    /// A bridge was opened but never closed.
+    OpenBridge(Bridge),
+}
+
+impl convert::From<lyn::Error> for Error {
+    fn from(value: lyn::Error) -> Self {
+        match value {
+            lyn::Error::EndOfLine => Self::EndOfLine,
+            lyn::Error::Character(pos) => Self::Character(pos),
+        }
+    }
+}
+
+impl convert::From<read::Error> for Error {
+    fn from(value: read::Error) -> Self {
+        match value {
+            read::Error::EndOfLine => Self::EndOfLine,
+            read::Error::Character(pos) => Self::Character(pos),
+        }
+    }
+}
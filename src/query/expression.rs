@@ -0,0 +1,290 @@
+use std::{fmt, fmt::Write};
+
+/// A logical combination of primitives.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Expression<P> {
+    Primitive(P),
+    Not(Box<Expression<P>>),
+    And(Vec<Expression<P>>),
+    Or(Vec<Expression<P>>),
+}
+
+impl<P> Expression<P> {
+    /// Evaluates the expression, using test for primitives.
+    pub fn eval(&self, test: &impl Fn(&P) -> bool) -> bool {
+        match self {
+            Self::Primitive(primitive) => test(primitive),
+            Self::Not(inner) => !inner.eval(test),
+            Self::And(terms) => terms.iter().all(|term| term.eval(test)),
+            Self::Or(terms) => terms.iter().any(|term| term.eval(test)),
+        }
+    }
+
+    /// Returns the conjunction of terms, or the only term if there is one.
+    pub fn and(mut terms: Vec<Self>) -> Self {
+        if terms.len() == 1 {
+            terms.pop().expect("term")
+        } else {
+            Self::And(flatten(terms, Self::is_and))
+        }
+    }
+
+    /// Returns the disjunction of terms, or the only term if there is one.
+    pub fn or(mut terms: Vec<Self>) -> Self {
+        if terms.len() == 1 {
+            terms.pop().expect("term")
+        } else {
+            Self::Or(flatten(terms, Self::is_or))
+        }
+    }
+
+    fn is_and(&self) -> bool {
+        matches!(self, Self::And(_))
+    }
+
+    fn is_or(&self) -> bool {
+        matches!(self, Self::Or(_))
+    }
+}
+
+impl<P: Clone> Expression<P> {
+    /// Returns an equivalent expression in which only primitives are negated.
+    fn normalize(&self, negate: bool) -> Self {
+        match self {
+            Self::Primitive(_) => {
+                if negate {
+                    Self::Not(Box::new(self.clone()))
+                } else {
+                    self.clone()
+                }
+            }
+            Self::Not(inner) => inner.normalize(!negate),
+            Self::And(terms) | Self::Or(terms) => {
+                let terms = terms
+                    .iter()
+                    .map(|term| term.normalize(negate))
+                    .collect::<Vec<_>>();
+
+                if self.is_and() != negate {
+                    Self::and(terms)
+                } else {
+                    Self::or(terms)
+                }
+            }
+        }
+    }
+
+    /// Returns the disjunctive normal form of a normalized expression as a
+    /// list of conjunctions of possibly negated primitives.
+    fn dnf(&self) -> Vec<Vec<&Self>> {
+        match self {
+            Self::Primitive(_) | Self::Not(_) => vec![vec![self]],
+            Self::Or(terms) => terms.iter().flat_map(Self::dnf).collect(),
+            Self::And(terms) => {
+                let mut result = vec![vec![]];
+
+                for term in terms {
+                    let mut next = Vec::new();
+
+                    for conjunction in &result {
+                        for alternative in term.dnf() {
+                            let mut conjunction: Vec<&Self> =
+                                conjunction.clone();
+
+                            conjunction.extend(alternative);
+                            next.push(conjunction)
+                        }
+                    }
+
+                    result = next;
+                }
+
+                result
+            }
+        }
+    }
+}
+
+fn flatten<P>(
+    terms: Vec<Expression<P>>,
+    same: fn(&Expression<P>) -> bool,
+) -> Vec<Expression<P>> {
+    let mut result = Vec::new();
+
+    for term in terms {
+        if same(&term) {
+            match term {
+                Expression::And(inner) | Expression::Or(inner) => {
+                    result.extend(inner)
+                }
+                _ => unreachable!(),
+            }
+        } else {
+            result.push(term)
+        }
+    }
+
+    result
+}
+
+/// Writes the expression with `&` joining conjunctions, `,` joining
+/// disjunctions, and `;` joining disjunctions into a conjunction. Negations
+/// are pushed down to primitives, and terms that can't be written otherwise
+/// are expanded into disjunctive normal form.
+impl<P: fmt::Display + Clone> fmt::Display for Expression<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let normal = self.normalize(false);
+
+        match &normal {
+            Self::And(terms) if terms.iter().any(Self::is_or) => {
+                for (i, term) in terms.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(';')?
+                    }
+
+                    write_dnf(term, f)?
+                }
+
+                Ok(())
+            }
+            _ => write_dnf(&normal, f),
+        }
+    }
+}
+
+fn write_dnf<P: fmt::Display + Clone>(
+    expression: &Expression<P>,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    for (i, conjunction) in expression.dnf().into_iter().enumerate() {
+        if i > 0 {
+            f.write_char(',')?
+        }
+
+        for (j, literal) in conjunction.into_iter().enumerate() {
+            if j > 0 {
+                f.write_char('&')?
+            }
+
+            match literal {
+                Expression::Primitive(primitive) => primitive.fmt(f)?,
+                Expression::Not(inner) => write!(f, "!{}", inner)?,
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod eval {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn nested() {
+        let expression = Expression::And(vec![
+            Expression::Primitive(1),
+            Expression::Not(Box::new(Expression::Or(vec![
+                Expression::Primitive(2),
+                Expression::Primitive(3),
+            ]))),
+        ]);
+
+        assert_eq!(
+            (1..=4)
+                .map(|value| expression.eval(&|p| *p <= value))
+                .collect::<Vec<_>>(),
+            vec![true, false, false, false]
+        )
+    }
+}
+
+#[cfg(test)]
+mod to_string {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn primitive(value: u8) -> Expression<u8> {
+        Expression::Primitive(value)
+    }
+
+    #[test]
+    fn primitive_only() {
+        assert_eq!(primitive(1).to_string(), "1")
+    }
+
+    #[test]
+    fn and() {
+        assert_eq!(
+            Expression::and(vec![primitive(1), primitive(2)]).to_string(),
+            "1&2"
+        )
+    }
+
+    #[test]
+    fn or_of_and() {
+        assert_eq!(
+            Expression::or(vec![
+                Expression::and(vec![primitive(1), primitive(2)]),
+                primitive(3)
+            ])
+            .to_string(),
+            "1&2,3"
+        )
+    }
+
+    #[test]
+    fn and_of_or() {
+        assert_eq!(
+            Expression::and(vec![
+                Expression::or(vec![primitive(1), primitive(2)]),
+                primitive(3)
+            ])
+            .to_string(),
+            "1,2;3"
+        )
+    }
+
+    #[test]
+    fn or_of_and_of_or() {
+        assert_eq!(
+            Expression::or(vec![
+                Expression::and(vec![
+                    Expression::or(vec![primitive(1), primitive(2)]),
+                    primitive(3)
+                ]),
+                primitive(4)
+            ])
+            .to_string(),
+            "1&3,2&3,4"
+        )
+    }
+
+    #[test]
+    fn not_primitive() {
+        assert_eq!(Expression::Not(Box::new(primitive(1))).to_string(), "!1")
+    }
+
+    #[test]
+    fn not_or() {
+        assert_eq!(
+            Expression::Not(Box::new(Expression::or(vec![
+                primitive(1),
+                primitive(2)
+            ])))
+            .to_string(),
+            "!1&!2"
+        )
+    }
+
+    #[test]
+    fn double_negation() {
+        assert_eq!(
+            Expression::Not(Box::new(Expression::Not(Box::new(primitive(1)))))
+                .to_string(),
+            "1"
+        )
+    }
+}
@@ -0,0 +1,226 @@
+use super::{AtomPrimitive, BondPrimitive, Query};
+use crate::{
+    feature::{AtomKind, Element},
+    graph::{ring_bonds, Atom, Bond},
+    search::{search, BondClass, MatchOptions, Matcher},
+};
+
+/// Returns the mappings of query onto target, as with search::matches.
+/// Query atoms carry no parity, so the stereo option has no effect.
+pub fn matches(
+    query: &Query,
+    target: &[Atom],
+    options: &MatchOptions,
+) -> Vec<Vec<usize>> {
+    let rings = ring_bonds(target);
+
+    search(&QueryMatcher { query, rings }, target, options)
+}
+
+struct QueryMatcher<'a> {
+    query: &'a Query,
+    rings: Vec<Vec<bool>>,
+}
+
+impl<'a> Matcher for QueryMatcher<'a> {
+    fn size(&self) -> usize {
+        self.query.atoms.len()
+    }
+
+    fn neighbors(&self, query: usize) -> Vec<usize> {
+        self.query.atoms[query]
+            .bonds
+            .iter()
+            .map(|bond| bond.tid)
+            .collect()
+    }
+
+    fn atom(&self, query: usize, target: &[Atom], index: usize) -> bool {
+        self.query.atoms[query]
+            .expression
+            .eval(&|primitive| self.atom_primitive(primitive, target, index))
+    }
+
+    fn bond(
+        &self,
+        sid: usize,
+        tid: usize,
+        target: &[Atom],
+        index: usize,
+        bond: &Bond,
+    ) -> bool {
+        let query = self.query.atoms[sid]
+            .bonds
+            .iter()
+            .find(|bond| bond.tid == tid)
+            .expect("query bond");
+        let class = BondClass::new(target, index, bond);
+
+        match &query.expression {
+            Some(expression) => expression.eval(&|primitive| match primitive {
+                BondPrimitive::Single => class == BondClass::Single,
+                BondPrimitive::Double => class == BondClass::Double,
+                BondPrimitive::Triple => class == BondClass::Triple,
+                BondPrimitive::Aromatic => class == BondClass::Aromatic,
+                BondPrimitive::Any => true,
+                BondPrimitive::Ring => self.ring_bond(target, index, bond),
+            }),
+            None => class == BondClass::Single || class == BondClass::Aromatic,
+        }
+    }
+}
+
+impl<'a> QueryMatcher<'a> {
+    fn atom_primitive(
+        &self,
+        primitive: &AtomPrimitive,
+        target: &[Atom],
+        index: usize,
+    ) -> bool {
+        let atom = &target[index];
+        let kind = &atom.kind;
+
+        match primitive {
+            AtomPrimitive::Star => true,
+            AtomPrimitive::Element(element) => {
+                !kind.is_selected() && kind.element().as_ref() == Some(element)
+            }
+            AtomPrimitive::Selection(selection) => {
+                kind.is_selected() && kind.element() == Some(selection.into())
+            }
+            AtomPrimitive::AtomicNumber(number) => match kind.element() {
+                Some(element) => element.atomic_number() == *number,
+                None => false,
+            },
+            AtomPrimitive::Aromatic => kind.is_selected(),
+            AtomPrimitive::Aliphatic => !kind.is_selected(),
+            AtomPrimitive::Degree(degree) => {
+                atom.bonds.len() == *degree as usize
+            }
+            AtomPrimitive::Hydrogens(hydrogens) => {
+                let bonded = atom
+                    .bonds
+                    .iter()
+                    .filter(|bond| {
+                        target[bond.tid].kind.element() == Some(Element::H)
+                    })
+                    .count();
+
+                atom.hydrogens() as usize + bonded == *hydrogens as usize
+            }
+            AtomPrimitive::Ring => self.rings[index].contains(&true),
+            AtomPrimitive::Charge(charge) => match kind {
+                AtomKind::Bracket(bracket) => bracket.charge() == *charge,
+                _ => *charge == 0,
+            },
+        }
+    }
+
+    fn ring_bond(&self, target: &[Atom], index: usize, bond: &Bond) -> bool {
+        target[index]
+            .bonds
+            .iter()
+            .position(|other| std::ptr::eq(other, bond))
+            .map(|position| self.rings[index][position])
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn matches_of(query: &str, target: &str) -> Vec<Vec<usize>> {
+        matches(
+            &query.parse().unwrap(),
+            &atoms_of(target),
+            &MatchOptions::default(),
+        )
+    }
+
+    #[test]
+    fn any_halogen() {
+        assert_eq!(
+            matches_of("[F,Cl,Br,I]", "FC(Cl)CBr"),
+            vec![vec![0], vec![2], vec![4]]
+        )
+    }
+
+    #[test]
+    fn aromatic_nitrogen_with_hydrogen() {
+        assert_eq!(matches_of("[n&H1]", "c1cc[nH]c1.c1ccncc1"), vec![vec![3]])
+    }
+
+    #[test]
+    fn not_oxygen() {
+        assert_eq!(matches_of("[!O]", "CCO"), vec![vec![0], vec![1]])
+    }
+
+    #[test]
+    fn ring_atom() {
+        assert_eq!(
+            matches_of("[C;R]", "CC1CC1"),
+            vec![vec![1], vec![2], vec![3]]
+        )
+    }
+
+    #[test]
+    fn ring_bond() {
+        assert_eq!(matches_of("C@C", "CC1CC1").len(), 3)
+    }
+
+    #[test]
+    fn chain_bond() {
+        assert_eq!(matches_of("C!@C", "CC1CC1"), vec![vec![0, 1]])
+    }
+
+    #[test]
+    fn any_bond() {
+        assert_eq!(matches_of("C~O", "CC=O.CO").len(), 2)
+    }
+
+    #[test]
+    fn default_bond_aromatic() {
+        assert_eq!(matches_of("cc", "c1ccccc1").len(), 6)
+    }
+
+    #[test]
+    fn default_bond_not_double() {
+        assert!(matches_of("CO", "C=O").is_empty())
+    }
+
+    #[test]
+    fn atomic_number_either_selection() {
+        assert_eq!(matches_of("[#7]", "CN.c1ccncc1"), vec![vec![1], vec![5]])
+    }
+
+    #[test]
+    fn aliphatic() {
+        assert_eq!(matches_of("[A]", "Cc1ccccc1"), vec![vec![0]])
+    }
+
+    #[test]
+    fn degree() {
+        assert_eq!(matches_of("[D3]", "CC(C)C"), vec![vec![1]])
+    }
+
+    #[test]
+    fn hydrogens_include_bonded() {
+        assert_eq!(matches_of("[C&H4]", "[H]C([H])([H])[H]"), vec![vec![1]])
+    }
+
+    #[test]
+    fn charge() {
+        assert_eq!(matches_of("[O&-1]", "CC(=O)[O-]"), vec![vec![3]])
+    }
+
+    #[test]
+    fn carboxylic_acid() {
+        assert_eq!(
+            matches_of("[#6]C(=O)[O&H1]", "CC(=O)O.CC(=O)OC"),
+            vec![vec![0, 1, 2, 3]]
+        )
+    }
+}
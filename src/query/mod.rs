@@ -0,0 +1,20 @@
+mod atom_primitive;
+mod bond_primitive;
+mod error;
+mod expression;
+mod matches;
+mod parse;
+#[allow(clippy::module_inception)]
+mod query;
+mod query_atom;
+mod query_bond;
+mod write;
+
+pub use atom_primitive::AtomPrimitive;
+pub use bond_primitive::BondPrimitive;
+pub use error::Error;
+pub use expression::Expression;
+pub use matches::matches;
+pub use query::Query;
+pub use query_atom::QueryAtom;
+pub use query_bond::QueryBond;
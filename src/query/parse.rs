@@ -0,0 +1,699 @@
+use std::{collections::HashMap, str::FromStr};
+
+use lyn::{Action, Scanner};
+
+use super::{
+    AtomPrimitive, BondPrimitive, Error, Expression, Query, QueryAtom,
+    QueryBond,
+};
+use crate::{
+    feature::{Bridge, Element},
+    read::{bridge, digit, missing_character, selection, shortcut},
+};
+
+impl FromStr for Query {
+    type Err = Error;
+
+    /// Parses a query. A bridge that would bond an atom to itself, or join
+    /// two atoms already bonded, is an error at the closing bridge.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut scanner = Scanner::new(string);
+        let mut state = State::default();
+
+        sequence(None, &mut scanner, &mut state)?;
+
+        if !scanner.is_done() {
+            return Err(Error::Character(scanner.cursor()));
+        }
+
+        match state.bridges.into_iter().min_by_key(|(_, open)| open.order) {
+            Some((bridge, _)) => Err(Error::OpenBridge(bridge)),
+            None => Ok(Query { atoms: state.atoms }),
+        }
+    }
+}
+
+type AtomExpression = Expression<AtomPrimitive>;
+type BondExpression = Expression<BondPrimitive>;
+
+#[derive(Default)]
+struct State {
+    atoms: Vec<QueryAtom>,
+    head: Option<usize>,
+    stack: Vec<usize>,
+    bridges: HashMap<Bridge, Open>,
+    opened: usize,
+}
+
+struct Open {
+    sid: usize,
+    insertion: usize,
+    expression: Option<BondExpression>,
+    order: usize,
+}
+
+impl State {
+    fn root(&mut self, expression: AtomExpression) {
+        self.head.replace(self.atoms.len());
+        self.atoms.push(QueryAtom {
+            expression,
+            bonds: Vec::new(),
+        })
+    }
+
+    fn extend(
+        &mut self,
+        bond: Option<BondExpression>,
+        expression: AtomExpression,
+    ) {
+        let id = self.atoms.len();
+        let sid = self.head.replace(id).expect("head");
+
+        self.atoms[sid].bonds.push(QueryBond {
+            expression: bond.clone(),
+            tid: id,
+        });
+        self.atoms.push(QueryAtom {
+            expression,
+            bonds: vec![QueryBond {
+                expression: bond,
+                tid: sid,
+            }],
+        })
+    }
+
+    fn bridge(
+        &mut self,
+        bond: Option<BondExpression>,
+        bridge: Bridge,
+        cursor: usize,
+    ) -> Result<(), Error> {
+        let head = self.head.expect("head");
+
+        match self.bridges.remove(&bridge) {
+            Some(open) => {
+                if open.sid == head
+                    || self.atoms[head]
+                        .bonds
+                        .iter()
+                        .any(|bond| bond.tid == open.sid)
+                {
+                    return Err(Error::Character(cursor));
+                }
+
+                let expression = match (open.expression, bond) {
+                    (Some(left), Some(right)) if left != right => {
+                        return Err(Error::Character(cursor))
+                    }
+                    (left, right) => left.or(right),
+                };
+
+                self.atoms[head].bonds.push(QueryBond {
+                    expression: expression.clone(),
+                    tid: open.sid,
+                });
+                self.atoms[open.sid].bonds.insert(
+                    open.insertion,
+                    QueryBond {
+                        expression,
+                        tid: head,
+                    },
+                )
+            }
+            None => {
+                self.bridges.insert(
+                    bridge,
+                    Open {
+                        sid: head,
+                        insertion: self.atoms[head].bonds.len(),
+                        expression: bond,
+                        order: self.opened,
+                    },
+                );
+                self.opened += 1;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn sequence(
+    input: Option<Option<BondExpression>>,
+    scanner: &mut Scanner,
+    state: &mut State,
+) -> Result<bool, Error> {
+    let expression = match atom(scanner)? {
+        Some(expression) => expression,
+        None => return Ok(false),
+    };
+
+    match input {
+        Some(bond) => state.extend(bond, expression),
+        None => state.root(expression),
+    }
+
+    loop {
+        if union(scanner, state)?
+            || branch(scanner, state)?
+            || gap(scanner, state)?
+        {
+            continue;
+        }
+
+        break Ok(true);
+    }
+}
+
+fn union(scanner: &mut Scanner, state: &mut State) -> Result<bool, Error> {
+    let cursor = scanner.cursor();
+
+    match bond(scanner)? {
+        Some(bond) => {
+            if bridge_or_sequence(Some(bond), cursor, scanner, state)? {
+                Ok(true)
+            } else {
+                Err(missing_character(scanner).into())
+            }
+        }
+        None => bridge_or_sequence(None, cursor, scanner, state),
+    }
+}
+
+/// Reads a bridge or sequence following bond, which starts at cursor.
+fn bridge_or_sequence(
+    bond: Option<BondExpression>,
+    cursor: usize,
+    scanner: &mut Scanner,
+    state: &mut State,
+) -> Result<bool, Error> {
+    if let Some(bridge) = bridge(scanner)? {
+        state.bridge(bond, bridge, cursor)?;
+
+        Ok(true)
+    } else {
+        sequence(Some(bond), scanner, state)
+    }
+}
+
+fn branch(scanner: &mut Scanner, state: &mut State) -> Result<bool, Error> {
+    if !scanner.take(&'(') {
+        return Ok(false);
+    }
+
+    state.stack.push(state.head.expect("head"));
+
+    let input = if scanner.take(&'.') {
+        None
+    } else {
+        Some(bond(scanner)?)
+    };
+
+    if !sequence(input, scanner, state)? || !scanner.take(&')') {
+        return Err(missing_character(scanner).into());
+    }
+
+    state.head.replace(state.stack.pop().expect("head"));
+
+    if union(scanner, state)? || branch(scanner, state)? {
+        Ok(true)
+    } else {
+        Err(missing_character(scanner).into())
+    }
+}
+
+fn gap(scanner: &mut Scanner, state: &mut State) -> Result<bool, Error> {
+    if !scanner.take(&'.') {
+        return Ok(false);
+    }
+
+    if sequence(None, scanner, state)? {
+        Ok(true)
+    } else {
+        Err(missing_character(scanner).into())
+    }
+}
+
+fn atom(scanner: &mut Scanner) -> Result<Option<AtomExpression>, Error> {
+    let primitive = if scanner.take(&'*') {
+        AtomPrimitive::Star
+    } else if let Some(shortcut) = shortcut(scanner)? {
+        AtomPrimitive::Element((&shortcut).into())
+    } else if let Some(selection) = selection(scanner) {
+        AtomPrimitive::Selection(selection)
+    } else if scanner.take(&'[') {
+        let result = required(expression(scanner, atom_primitive)?, scanner)?;
+
+        return if scanner.take(&']') {
+            Ok(Some(result))
+        } else {
+            Err(missing_character(scanner).into())
+        };
+    } else {
+        return Ok(None);
+    };
+
+    Ok(Some(Expression::Primitive(primitive)))
+}
+
+fn bond(scanner: &mut Scanner) -> Result<Option<BondExpression>, Error> {
+    expression(scanner, bond_primitive)
+}
+
+type Primitive<P> = fn(&mut Scanner) -> Result<Option<P>, Error>;
+
+/// Reads an expression at the lowest precedence, joined by `;`.
+fn expression<P>(
+    scanner: &mut Scanner,
+    primitive: Primitive<P>,
+) -> Result<Option<Expression<P>>, Error> {
+    let mut terms = match disjunction(scanner, primitive)? {
+        Some(term) => vec![term],
+        None => return Ok(None),
+    };
+
+    while scanner.take(&';') {
+        terms.push(required(disjunction(scanner, primitive)?, scanner)?)
+    }
+
+    Ok(Some(Expression::and(terms)))
+}
+
+fn disjunction<P>(
+    scanner: &mut Scanner,
+    primitive: Primitive<P>,
+) -> Result<Option<Expression<P>>, Error> {
+    let mut terms = match conjunction(scanner, primitive)? {
+        Some(term) => vec![term],
+        None => return Ok(None),
+    };
+
+    while scanner.take(&',') {
+        terms.push(required(conjunction(scanner, primitive)?, scanner)?)
+    }
+
+    Ok(Some(Expression::or(terms)))
+}
+
+fn conjunction<P>(
+    scanner: &mut Scanner,
+    primitive: Primitive<P>,
+) -> Result<Option<Expression<P>>, Error> {
+    let mut terms = match negation(scanner, primitive)? {
+        Some(term) => vec![term],
+        None => return Ok(None),
+    };
+
+    loop {
+        if scanner.take(&'&') {
+            terms.push(required(negation(scanner, primitive)?, scanner)?)
+        } else if let Some(term) = negation(scanner, primitive)? {
+            terms.push(term)
+        } else {
+            break;
+        }
+    }
+
+    Ok(Some(Expression::and(terms)))
+}
+
+fn negation<P>(
+    scanner: &mut Scanner,
+    primitive: Primitive<P>,
+) -> Result<Option<Expression<P>>, Error> {
+    if scanner.take(&'!') {
+        let inner = required(negation(scanner, primitive)?, scanner)?;
+
+        Ok(Some(Expression::Not(Box::new(inner))))
+    } else {
+        Ok(primitive(scanner)?.map(Expression::Primitive))
+    }
+}
+
+fn required<T>(value: Option<T>, scanner: &mut Scanner) -> Result<T, Error> {
+    match value {
+        Some(value) => Ok(value),
+        None => Err(missing_character(scanner).into()),
+    }
+}
+
+enum Letter {
+    Aliphatic,
+    Degree,
+    Hydrogens,
+    Ring,
+    Element(Element),
+}
+
+fn atom_primitive(
+    scanner: &mut Scanner,
+) -> Result<Option<AtomPrimitive>, Error> {
+    let start = scanner.cursor();
+
+    if scanner.take(&'*') {
+        return Ok(Some(AtomPrimitive::Star));
+    } else if scanner.take(&'a') {
+        return Ok(Some(AtomPrimitive::Aromatic));
+    } else if scanner.take(&'#') {
+        let number = required(number(scanner), scanner)?;
+
+        return match u8::try_from(number) {
+            Ok(number) if Element::try_from(number).is_ok() => {
+                Ok(Some(AtomPrimitive::AtomicNumber(number)))
+            }
+            _ => Err(Error::Character(start + 1)),
+        };
+    } else if let Some(sign) = sign(scanner) {
+        let magnitude = number(scanner).unwrap_or(1);
+
+        return match i8::try_from(magnitude) {
+            Ok(magnitude) => Ok(Some(AtomPrimitive::Charge(sign * magnitude))),
+            Err(_) => Err(Error::Character(start + 1)),
+        };
+    } else if let Some(selection) = selection(scanner) {
+        return Ok(Some(AtomPrimitive::Selection(selection)));
+    }
+
+    let letter = scanner.scan(|symbol| match symbol {
+        "A" => Some(Action::Request(Letter::Aliphatic)),
+        "D" => Some(Action::Request(Letter::Degree)),
+        "H" => Some(Action::Request(Letter::Hydrogens)),
+        "R" => Some(Action::Request(Letter::Ring)),
+        _ => match symbol.parse::<Element>() {
+            Ok(element) if symbol.len() == 1 => {
+                Some(Action::Request(Letter::Element(element)))
+            }
+            Ok(element) => Some(Action::Return(Letter::Element(element))),
+            Err(_) if symbol.len() == 1 => symbol
+                .starts_with(|c: char| c.is_ascii_uppercase())
+                .then_some(Action::Require),
+            Err(_) => None,
+        },
+    })?;
+
+    Ok(match letter {
+        Some(Letter::Aliphatic) => Some(AtomPrimitive::Aliphatic),
+        Some(Letter::Degree) => Some(AtomPrimitive::Degree(count(scanner)?)),
+        Some(Letter::Hydrogens) => {
+            Some(AtomPrimitive::Hydrogens(count(scanner)?))
+        }
+        Some(Letter::Ring) => Some(AtomPrimitive::Ring),
+        Some(Letter::Element(element)) => Some(AtomPrimitive::Element(element)),
+        None => None,
+    })
+}
+
+fn bond_primitive(
+    scanner: &mut Scanner,
+) -> Result<Option<BondPrimitive>, Error> {
+    Ok(scanner.transform(|target| match target {
+        '-' => Some(BondPrimitive::Single),
+        '=' => Some(BondPrimitive::Double),
+        '#' => Some(BondPrimitive::Triple),
+        ':' => Some(BondPrimitive::Aromatic),
+        '~' => Some(BondPrimitive::Any),
+        '@' => Some(BondPrimitive::Ring),
+        _ => None,
+    }))
+}
+
+fn sign(scanner: &mut Scanner) -> Option<i8> {
+    if scanner.take(&'+') {
+        Some(1)
+    } else if scanner.take(&'-') {
+        Some(-1)
+    } else {
+        None
+    }
+}
+
+/// Reads an optional count, defaulting to one.
+fn count(scanner: &mut Scanner) -> Result<u8, Error> {
+    let start = scanner.cursor();
+
+    match number(scanner) {
+        Some(number) => {
+            u8::try_from(number).map_err(|_| Error::Character(start))
+        }
+        None => Ok(1),
+    }
+}
+
+fn number(scanner: &mut Scanner) -> Option<u16> {
+    let mut result = digit(scanner)? as u16;
+
+    for _ in 0..2 {
+        match digit(scanner) {
+            Some(digit) => result = result * 10 + digit as u16,
+            None => break,
+        }
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod from_str {
+    use super::*;
+    use crate::feature::Selection;
+    use pretty_assertions::assert_eq;
+
+    fn primitive(primitive: AtomPrimitive) -> AtomExpression {
+        Expression::Primitive(primitive)
+    }
+
+    fn expression_of(string: &str) -> AtomExpression {
+        let mut query = string.parse::<Query>().unwrap();
+
+        query.atoms.remove(0).expression
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!("".parse::<Query>(), Ok(Query::default()))
+    }
+
+    #[test]
+    fn empty_bracket() {
+        assert_eq!("[]".parse::<Query>(), Err(Error::Character(1)))
+    }
+
+    #[test]
+    fn unclosed_bracket() {
+        assert_eq!("[C".parse::<Query>(), Err(Error::EndOfLine))
+    }
+
+    #[test]
+    fn dangling_operator() {
+        assert_eq!("[C,]".parse::<Query>(), Err(Error::Character(3)))
+    }
+
+    #[test]
+    fn unknown_element() {
+        assert_eq!("[Xx]".parse::<Query>(), Err(Error::Character(2)))
+    }
+
+    #[test]
+    fn unknown_atomic_number() {
+        assert_eq!("[#200]".parse::<Query>(), Err(Error::Character(2)))
+    }
+
+    #[test]
+    fn open_bridge() {
+        assert_eq!(
+            "C1CC2CC1".parse::<Query>(),
+            Err(Error::OpenBridge(Bridge::B2))
+        )
+    }
+
+    #[test]
+    fn conflicting_bridge_bonds() {
+        assert_eq!("C=1CC-1".parse::<Query>(), Err(Error::Character(5)))
+    }
+
+    #[test]
+    fn self_loop() {
+        assert_eq!("O88".parse::<Query>(), Err(Error::Character(2)))
+    }
+
+    #[test]
+    fn self_loop_with_bond() {
+        assert_eq!("N=44N".parse::<Query>(), Err(Error::Character(3)))
+    }
+
+    #[test]
+    fn repeated_bond() {
+        assert_eq!("C@1N1".parse::<Query>(), Err(Error::Character(4)))
+    }
+
+    #[test]
+    fn repeated_bridge_bond() {
+        assert_eq!("C12C12".parse::<Query>(), Err(Error::Character(4)))
+    }
+
+    #[test]
+    fn trailing_character() {
+        assert_eq!("C)".parse::<Query>(), Err(Error::Character(1)))
+    }
+
+    #[test]
+    fn shortcut() {
+        assert_eq!(
+            expression_of("Cl"),
+            primitive(AtomPrimitive::Element(Element::Cl))
+        )
+    }
+
+    #[test]
+    fn selection() {
+        assert_eq!(
+            expression_of("n"),
+            primitive(AtomPrimitive::Selection(Selection::N))
+        )
+    }
+
+    #[test]
+    fn star() {
+        assert_eq!(expression_of("*"), primitive(AtomPrimitive::Star))
+    }
+
+    #[test]
+    fn halogen() {
+        assert_eq!(
+            expression_of("[F,Cl,Br,I]"),
+            Expression::Or(vec![
+                primitive(AtomPrimitive::Element(Element::F)),
+                primitive(AtomPrimitive::Element(Element::Cl)),
+                primitive(AtomPrimitive::Element(Element::Br)),
+                primitive(AtomPrimitive::Element(Element::I)),
+            ])
+        )
+    }
+
+    #[test]
+    fn implicit_and() {
+        assert_eq!(
+            expression_of("[nH1]"),
+            Expression::And(vec![
+                primitive(AtomPrimitive::Selection(Selection::N)),
+                primitive(AtomPrimitive::Hydrogens(1)),
+            ])
+        )
+    }
+
+    #[test]
+    fn element_after_hydrogens() {
+        assert_eq!(
+            expression_of("[Hg]"),
+            primitive(AtomPrimitive::Element(Element::Hg))
+        )
+    }
+
+    #[test]
+    fn default_counts() {
+        assert_eq!(
+            expression_of("[CHD]"),
+            Expression::And(vec![
+                primitive(AtomPrimitive::Element(Element::C)),
+                primitive(AtomPrimitive::Hydrogens(1)),
+                primitive(AtomPrimitive::Degree(1)),
+            ])
+        )
+    }
+
+    #[test]
+    fn not() {
+        assert_eq!(
+            expression_of("[!O]"),
+            Expression::Not(Box::new(primitive(AtomPrimitive::Element(
+                Element::O
+            ))))
+        )
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(
+            expression_of("[N,O&R;+]"),
+            Expression::And(vec![
+                Expression::Or(vec![
+                    primitive(AtomPrimitive::Element(Element::N)),
+                    Expression::And(vec![
+                        primitive(AtomPrimitive::Element(Element::O)),
+                        primitive(AtomPrimitive::Ring),
+                    ]),
+                ]),
+                primitive(AtomPrimitive::Charge(1)),
+            ])
+        )
+    }
+
+    #[test]
+    fn charge() {
+        assert_eq!(expression_of("[-2]"), primitive(AtomPrimitive::Charge(-2)))
+    }
+
+    #[test]
+    fn atomic_number_and_aromatic() {
+        assert_eq!(
+            expression_of("[#7a]"),
+            Expression::And(vec![
+                primitive(AtomPrimitive::AtomicNumber(7)),
+                primitive(AtomPrimitive::Aromatic),
+            ])
+        )
+    }
+
+    #[test]
+    fn aliphatic() {
+        assert_eq!(
+            expression_of("[A;!R]"),
+            Expression::And(vec![
+                primitive(AtomPrimitive::Aliphatic),
+                Expression::Not(Box::new(primitive(AtomPrimitive::Ring))),
+            ])
+        )
+    }
+
+    #[test]
+    fn bonds() {
+        let query = "C!@C=,:[#6]~*".parse::<Query>().unwrap();
+
+        assert_eq!(
+            query
+                .atoms
+                .iter()
+                .map(|atom| atom.bonds.last().unwrap().expression.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                Some(Expression::Not(Box::new(Expression::Primitive(
+                    BondPrimitive::Ring
+                )))),
+                Some(Expression::Or(vec![
+                    Expression::Primitive(BondPrimitive::Double),
+                    Expression::Primitive(BondPrimitive::Aromatic),
+                ])),
+                Some(Expression::Primitive(BondPrimitive::Any)),
+                Some(Expression::Primitive(BondPrimitive::Any)),
+            ]
+        )
+    }
+
+    #[test]
+    fn bridge_and_branch() {
+        let query = "C1CC(O)C1".parse::<Query>().unwrap();
+
+        assert_eq!(
+            query
+                .atoms
+                .iter()
+                .map(|atom| atom
+                    .bonds
+                    .iter()
+                    .map(|bond| bond.tid)
+                    .collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            vec![vec![4, 1], vec![0, 2], vec![1, 3, 4], vec![2], vec![2, 0]]
+        )
+    }
+}
@@ -0,0 +1,14 @@
+use super::QueryAtom;
+
+/// A query graph. Query atoms reference each other by index, as with
+/// graph::Atom. Queries are read with FromStr and written with Display.
+///
+/// The notation extends Balsa. Atoms outside brackets are Star, unselected
+/// element, or selected element primitives. Brackets hold an expression of
+/// atom primitives joined by `!` (not), `&` or juxtaposition (and), `,`
+/// (or), and `;` (low-precedence and), in order of decreasing precedence.
+/// Bonds take expressions of bond primitives with the same operators.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Query {
+    pub atoms: Vec<QueryAtom>,
+}
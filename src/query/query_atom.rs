@@ -0,0 +1,7 @@
+use super::{AtomPrimitive, Expression, QueryBond};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct QueryAtom {
+    pub expression: Expression<AtomPrimitive>,
+    pub bonds: Vec<QueryBond>,
+}
@@ -0,0 +1,8 @@
+use super::{BondPrimitive, Expression};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct QueryBond {
+    /// None matches single and aromatic bonds, like an elided bond.
+    pub expression: Option<Expression<BondPrimitive>>,
+    pub tid: usize,
+}
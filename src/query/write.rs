@@ -0,0 +1,279 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    fmt,
+    fmt::Write,
+};
+
+use super::{AtomPrimitive, Expression, Query, QueryBond};
+use crate::feature::Element;
+
+/// Writes the query depth-first from its lowest-indexed atoms, following
+/// neighbors in order of index. Bridges are numbered from 1, reusing closed numbers.
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plan = Plan::new(self);
+        let mut free = BTreeSet::new();
+        let mut next = 1;
+        let mut numbers = vec![0; plan.bridges.len()];
+
+        for (i, &root) in plan.roots.iter().enumerate() {
+            if i > 0 {
+                f.write_char('.')?
+            }
+
+            self.write_atom(root, &plan, &mut free, &mut next, &mut numbers, f)?
+        }
+
+        Ok(())
+    }
+}
+
+/// Spanning forest of a query with bridges for the remaining bonds.
+struct Plan<'a> {
+    roots: Vec<usize>,
+    children: Vec<Vec<&'a QueryBond>>,
+    /// Bridges as opening atom, closing atom, and bond.
+    bridges: Vec<(usize, usize, &'a QueryBond)>,
+}
+
+impl<'a> Plan<'a> {
+    fn new(query: &'a Query) -> Self {
+        let atoms = &query.atoms;
+        let mut result = Plan {
+            roots: Vec::new(),
+            children: vec![Vec::new(); atoms.len()],
+            bridges: Vec::new(),
+        };
+        let mut visited = vec![false; atoms.len()];
+        let mut closed = HashSet::new();
+        // Following neighbors by index retraces the order in which a parsed
+        // query was written.
+        let sorted = atoms
+            .iter()
+            .map(|atom| {
+                let mut bonds = atom.bonds.iter().collect::<Vec<_>>();
+
+                bonds.sort_by_key(|bond| bond.tid);
+
+                bonds
+            })
+            .collect::<Vec<_>>();
+
+        for root in 0..atoms.len() {
+            if visited[root] {
+                continue;
+            }
+
+            result.roots.push(root);
+            visited[root] = true;
+
+            // Entries hold an atom, its parent, and the next bond to follow.
+            let mut stack = vec![(root, usize::MAX, 0)];
+
+            while let Some((id, parent, next)) = stack.last_mut() {
+                let (id, parent) = (*id, *parent);
+                let bond = match sorted[id].get(*next) {
+                    Some(bond) => *bond,
+                    None => {
+                        stack.pop();
+
+                        continue;
+                    }
+                };
+
+                *next += 1;
+
+                if bond.tid == parent || closed.contains(&(id, bond.tid)) {
+                    continue;
+                } else if visited[bond.tid] {
+                    closed.insert((bond.tid, id));
+                    result.bridges.push((bond.tid, id, bond));
+                } else {
+                    visited[bond.tid] = true;
+                    result.children[id].push(bond);
+                    stack.push((bond.tid, id, 0));
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl Query {
+    fn write_atom(
+        &self,
+        id: usize,
+        plan: &Plan,
+        free: &mut BTreeSet<usize>,
+        next: &mut usize,
+        numbers: &mut [usize],
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write_atom_expression(&self.atoms[id].expression, f)?;
+
+        for (index, (sid, tid, bond)) in plan.bridges.iter().enumerate() {
+            if *tid == id {
+                write_bridge(numbers[index], f)?;
+                free.insert(numbers[index]);
+            } else if *sid == id {
+                let number = match free.pop_first() {
+                    Some(number) => number,
+                    None => {
+                        *next += 1;
+
+                        *next - 1
+                    }
+                };
+
+                numbers[index] = number;
+                write_bond(bond, f)?;
+                write_bridge(number, f)?;
+            }
+        }
+
+        let children = &plan.children[id];
+
+        for (i, bond) in children.iter().enumerate() {
+            let branch = i + 1 < children.len();
+
+            if branch {
+                f.write_char('(')?
+            }
+
+            write_bond(bond, f)?;
+            self.write_atom(bond.tid, plan, free, next, numbers, f)?;
+
+            if branch {
+                f.write_char(')')?
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn write_atom_expression(
+    expression: &Expression<AtomPrimitive>,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    match expression {
+        Expression::Primitive(AtomPrimitive::Star) => f.write_char('*'),
+        Expression::Primitive(AtomPrimitive::Selection(selection)) => {
+            write!(f, "{}", selection)
+        }
+        Expression::Primitive(AtomPrimitive::Element(
+            element @ (Element::B
+            | Element::C
+            | Element::N
+            | Element::O
+            | Element::P
+            | Element::S
+            | Element::F
+            | Element::Cl
+            | Element::Br
+            | Element::I),
+        )) => write!(f, "{}", element),
+        _ => write!(f, "[{}]", expression),
+    }
+}
+
+fn write_bond(bond: &QueryBond, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match &bond.expression {
+        Some(expression) => write!(f, "{}", expression),
+        None => Ok(()),
+    }
+}
+
+fn write_bridge(number: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if number < 10 {
+        write!(f, "{}", number)
+    } else {
+        write!(f, "%{}", number)
+    }
+}
+
+#[cfg(test)]
+mod to_string {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn round_trip(string: &str) -> String {
+        string.parse::<Query>().unwrap().to_string()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(round_trip(""), "")
+    }
+
+    #[test]
+    fn chain() {
+        assert_eq!(round_trip("CC(=O)O"), "CC(=O)O")
+    }
+
+    #[test]
+    fn bracket_shortcut() {
+        assert_eq!(round_trip("[C]"), "C")
+    }
+
+    #[test]
+    fn bracket_element() {
+        assert_eq!(round_trip("[Na]"), "[Na]")
+    }
+
+    #[test]
+    fn hydrogen() {
+        assert_eq!(round_trip("[#1]"), "[#1]")
+    }
+
+    #[test]
+    fn implicit_and() {
+        assert_eq!(round_trip("[nH]"), "[n&H1]")
+    }
+
+    #[test]
+    fn halogen() {
+        assert_eq!(round_trip("C[F,Cl,Br,I]"), "C[F,Cl,Br,I]")
+    }
+
+    #[test]
+    fn low_precedence() {
+        assert_eq!(round_trip("[N,O;R;!+]"), "[N,O;R;!+1]")
+    }
+
+    #[test]
+    fn bond_expressions() {
+        assert_eq!(round_trip("C!@C=,:C~*"), "C!@C=,:C~*")
+    }
+
+    #[test]
+    fn ring() {
+        assert_eq!(round_trip("c1ccccc1"), "c1ccccc1")
+    }
+
+    #[test]
+    fn ring_bond() {
+        assert_eq!(round_trip("C1CC@1"), "C@1CC1")
+    }
+
+    #[test]
+    fn fused_rings() {
+        assert_eq!(round_trip("c1ccc2ccccc2c1"), "c1ccc2ccccc2c1")
+    }
+
+    #[test]
+    fn reused_bridge() {
+        assert_eq!(round_trip("C1CC1C2CC2"), "C1CC1C1CC1")
+    }
+
+    #[test]
+    fn components() {
+        assert_eq!(round_trip("[Na+].[Cl-]"), "[Na&+1].[Cl&-1]")
+    }
+
+    #[test]
+    fn two_digit_bridge() {
+        assert_eq!(round_trip("C%12CC%12"), "C1CC1")
+    }
+}
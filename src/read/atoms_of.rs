@@ -0,0 +1,11 @@
+use super::read;
+use crate::graph::{Atom, Builder};
+
+/// Returns the graph of string, which must read without error. For tests.
+pub fn atoms_of(string: &str) -> Vec<Atom> {
+    let mut builder = Builder::new();
+
+    read(string, &mut builder).expect("valid string");
+
+    builder.build()
+}
@@ -91,7 +91,7 @@ fn charge(scanner: &mut Scanner) -> Option<Charge> {
         }
     } else if scanner.take(&'-') {
         match nonzero(scanner) {
-            Some(digit) => Some(Charge::new(digit as i8 * -1).expect("charge")),
+            Some(digit) => Some(Charge::new(-(digit as i8)).expect("charge")),
             None => Some(Charge::Minus),
         }
     } else {
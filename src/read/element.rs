@@ -3,7 +3,7 @@ use lyn::{Action, Error, Scanner};
 use crate::feature::Element;
 
 pub fn element(scanner: &mut Scanner) -> Result<Option<Element>, Error> {
-    Ok(scanner.scan(|symbol| match symbol {
+    scanner.scan(|symbol| match symbol {
         "A" => Some(Action::Require),
         "Ac" => Some(Action::Return(Element::Ac)),
         "Ag" => Some(Action::Return(Element::Ag)),
@@ -112,13 +112,13 @@ pub fn element(scanner: &mut Scanner) -> Result<Option<Element>, Error> {
         "W" => Some(Action::Return(Element::W)),
         "X" => Some(Action::Require),
         "Xe" => Some(Action::Return(Element::Xe)),
-        "Y" => Some(Action::Require),
+        "Y" => Some(Action::Request(Element::Y)),
         "Yb" => Some(Action::Return(Element::Yb)),
         "Z" => Some(Action::Require),
         "Zn" => Some(Action::Return(Element::Zn)),
         "Zr" => Some(Action::Return(Element::Zr)),
         _ => None,
-    })?)
+    })
 }
 
 #[cfg(test)]
@@ -147,6 +147,13 @@ mod tests {
         assert_eq!(element(&mut scanner), Ok(Some(Element::B)))
     }
 
+    #[test]
+    fn yttrium() {
+        let mut scanner = Scanner::new("Y]");
+
+        assert_eq!(element(&mut scanner), Ok(Some(Element::Y)))
+    }
+
     #[test]
     fn cap_found_lower_not_found() {
         let mut scanner = Scanner::new("Br");
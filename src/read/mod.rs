@@ -1,4 +1,6 @@
 mod atom;
+#[cfg(test)]
+mod atoms_of;
 mod bond;
 mod bracket;
 mod bridge;
@@ -7,6 +9,7 @@ mod element;
 mod error;
 mod missing_character;
 mod nonzero;
+#[allow(clippy::module_inception)]
 mod read;
 mod selection;
 mod shortcut;
@@ -25,3 +28,6 @@ pub use read::read;
 pub use selection::selection;
 pub use shortcut::shortcut;
 pub use uint16::uint16;
+
+#[cfg(test)]
+pub(crate) use atoms_of::atoms_of;
@@ -0,0 +1,34 @@
+use crate::{
+    feature::{AtomKind, Element},
+    graph::Atom,
+};
+
+/// Features of an atom that don't depend on how it was written. Star atoms
+/// have no element.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub(crate) struct AtomKey {
+    pub element: Option<Element>,
+    pub selected: bool,
+    pub isotope: Option<u16>,
+    pub charge: i8,
+    pub hydrogens: u8,
+}
+
+impl AtomKey {
+    pub fn new(atom: &Atom) -> Self {
+        let (isotope, charge) = match &atom.kind {
+            AtomKind::Bracket(bracket) => {
+                (bracket.isotope.as_ref().map(u16::from), bracket.charge())
+            }
+            _ => (None, 0),
+        };
+
+        Self {
+            element: atom.kind.element(),
+            selected: atom.kind.is_selected(),
+            isotope,
+            charge,
+            hydrogens: atom.hydrogens(),
+        }
+    }
+}
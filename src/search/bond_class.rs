@@ -0,0 +1,79 @@
+use crate::{
+    feature::BondKind,
+    graph::{Atom, Bond},
+};
+
+/// Bond type as seen by comparisons between molecules, independent of how
+/// the bond was written.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum BondClass {
+    Single,
+    Double,
+    Triple,
+    Aromatic,
+}
+
+impl BondClass {
+    /// Returns the class of bond leaving the atom at sid. Elided bonds
+    /// between selected atoms are aromatic, other elided bonds single.
+    /// Directional bonds are single.
+    pub fn new(atoms: &[Atom], sid: usize, bond: &Bond) -> Self {
+        match bond.kind {
+            BondKind::Elided => {
+                if atoms[sid].kind.is_selected()
+                    && atoms[bond.tid].kind.is_selected()
+                {
+                    Self::Aromatic
+                } else {
+                    Self::Single
+                }
+            }
+            BondKind::Single | BondKind::Up | BondKind::Down => Self::Single,
+            BondKind::Double => Self::Double,
+            BondKind::Triple => Self::Triple,
+        }
+    }
+}
+
+#[cfg(test)]
+mod new {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn class_of(string: &str, sid: usize, index: usize) -> BondClass {
+        let atoms = atoms_of(string);
+
+        BondClass::new(&atoms, sid, &atoms[sid].bonds[index])
+    }
+
+    #[test]
+    fn elided_aliphatic() {
+        assert_eq!(class_of("CC", 0, 0), BondClass::Single)
+    }
+
+    #[test]
+    fn elided_mixed() {
+        assert_eq!(class_of("Cc1ccccc1", 0, 0), BondClass::Single)
+    }
+
+    #[test]
+    fn elided_selected() {
+        assert_eq!(class_of("c1ccccc1", 0, 0), BondClass::Aromatic)
+    }
+
+    #[test]
+    fn single_selected() {
+        assert_eq!(class_of("c1ccccc1-c1ccccc1", 5, 2), BondClass::Single)
+    }
+
+    #[test]
+    fn up() {
+        assert_eq!(class_of("F/C=C/F", 0, 0), BondClass::Single)
+    }
+
+    #[test]
+    fn double() {
+        assert_eq!(class_of("C=C", 0, 0), BondClass::Double)
+    }
+}
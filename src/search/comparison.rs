@@ -0,0 +1,12 @@
+/// How atoms and bonds are compared when finding a maximum common
+/// substructure.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Comparison {
+    /// Atoms of the same element match, with any bonds.
+    Element,
+    /// Atoms of the same element match, with bonds of the same BondClass.
+    #[default]
+    ElementAndBondOrder,
+    /// All atoms and bonds match.
+    Any,
+}
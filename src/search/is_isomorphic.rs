@@ -0,0 +1,246 @@
+use super::{
+    parity, same_configuration, search, AtomKey, BondClass, MatchOptions,
+    Matcher,
+};
+use crate::{
+    graph::{Atom, Bond},
+    stereo::{double_bonds, DoubleBond},
+};
+
+/// Returns true if first and second describe the same molecule regardless
+/// of atom order. Atoms are equivalent if they agree in element, selection,
+/// isotope, charge, and hydrogen count, however these were written. Bonds
+/// are equivalent if they have the same BondClass. If stereo is true,
+/// tetrahedral parities and the double bond configurations given by
+/// stereo::double_bonds must also agree.
+pub fn is_isomorphic(first: &[Atom], second: &[Atom], stereo: bool) -> bool {
+    if first.len() != second.len() || bond_count(first) != bond_count(second) {
+        return false;
+    }
+
+    if first.is_empty() {
+        return true;
+    }
+
+    let isomorphism = Isomorphism::new(first, second, stereo);
+
+    if isomorphism.double_bonds.len() != isomorphism.target_double_bonds.len() {
+        return false;
+    }
+
+    let options = MatchOptions {
+        unique: false,
+        max_matches: Some(1),
+        stereo,
+    };
+
+    !search(&isomorphism, second, &options).is_empty()
+}
+
+fn bond_count(atoms: &[Atom]) -> usize {
+    atoms.iter().map(|atom| atom.bonds.len()).sum()
+}
+
+struct Isomorphism<'a> {
+    atoms: &'a [Atom],
+    keys: Vec<AtomKey>,
+    stereo: bool,
+    double_bonds: Vec<DoubleBond>,
+    target_double_bonds: Vec<DoubleBond>,
+}
+
+impl<'a> Isomorphism<'a> {
+    fn new(atoms: &'a [Atom], target: &[Atom], stereo: bool) -> Self {
+        let (double_bonds, target_double_bonds) = if stereo {
+            (double_bonds(atoms), double_bonds(target))
+        } else {
+            (Vec::new(), Vec::new())
+        };
+
+        Self {
+            atoms,
+            keys: atoms.iter().map(AtomKey::new).collect(),
+            stereo,
+            double_bonds,
+            target_double_bonds,
+        }
+    }
+
+    fn same_geometry(
+        &self,
+        double_bond: &DoubleBond,
+        mapping: &[usize],
+    ) -> bool {
+        let source = mapping[double_bond.source];
+        let target = mapping[double_bond.target];
+
+        self.target_double_bonds.iter().any(|other| {
+            other.configuration_between(
+                source,
+                mapping[double_bond.first],
+                mapping[double_bond.second],
+            ) == Some(double_bond.configuration)
+                && (other.source == target || other.target == target)
+        })
+    }
+}
+
+impl<'a> Matcher for Isomorphism<'a> {
+    fn size(&self) -> usize {
+        self.atoms.len()
+    }
+
+    fn neighbors(&self, query: usize) -> Vec<usize> {
+        self.atoms[query]
+            .bonds
+            .iter()
+            .map(|bond| bond.tid)
+            .collect()
+    }
+
+    fn atom(&self, query: usize, target: &[Atom], index: usize) -> bool {
+        self.atoms[query].bonds.len() == target[index].bonds.len()
+            && self.keys[query] == AtomKey::new(&target[index])
+            && (!self.stereo
+                || parity(&self.atoms[query].kind).is_some()
+                    == parity(&target[index].kind).is_some())
+    }
+
+    fn bond(
+        &self,
+        sid: usize,
+        tid: usize,
+        target: &[Atom],
+        index: usize,
+        bond: &Bond,
+    ) -> bool {
+        let query = self.atoms[sid]
+            .bonds
+            .iter()
+            .find(|bond| bond.tid == tid)
+            .expect("query bond");
+
+        BondClass::new(self.atoms, sid, query)
+            == BondClass::new(target, index, bond)
+    }
+
+    fn accept(&self, target: &[Atom], mapping: &[usize]) -> bool {
+        !self.stereo
+            || (0..self.atoms.len()).all(|index| {
+                match parity(&self.atoms[index].kind) {
+                    Some(parity) => same_configuration(
+                        self.atoms, index, parity, target, mapping,
+                    ),
+                    None => true,
+                }
+            }) && self
+                .double_bonds
+                .iter()
+                .all(|double_bond| self.same_geometry(double_bond, mapping))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+
+    fn isomorphic(first: &str, second: &str, stereo: bool) -> bool {
+        is_isomorphic(&atoms_of(first), &atoms_of(second), stereo)
+    }
+
+    #[test]
+    fn empty() {
+        assert!(isomorphic("", "", false))
+    }
+
+    #[test]
+    fn reordered() {
+        assert!(isomorphic("OCC", "CCO", false))
+    }
+
+    #[test]
+    fn constitutional_isomers() {
+        assert!(!isomorphic("CCO", "COC", false))
+    }
+
+    #[test]
+    fn different_size() {
+        assert!(!isomorphic("CC", "CCC", false))
+    }
+
+    #[test]
+    fn ring_versus_chain() {
+        assert!(!isomorphic("C1CC1", "C=CC", false))
+    }
+
+    #[test]
+    fn bracket_versus_shortcut() {
+        assert!(isomorphic("C", "[CH4]", false))
+    }
+
+    #[test]
+    fn minus_versus_minus1() {
+        assert!(isomorphic("CC(=O)[O-]", "[O-1]C(C)=O", false))
+    }
+
+    #[test]
+    fn h_versus_h1() {
+        assert!(isomorphic("[CH](C)(C)C", "C[CH1](C)C", false))
+    }
+
+    #[test]
+    fn isotope() {
+        assert!(!isomorphic("[13CH4]", "C", false))
+    }
+
+    #[test]
+    fn elided_versus_single() {
+        assert!(isomorphic("CC", "C-C", false))
+    }
+
+    #[test]
+    fn stereo_ignored() {
+        assert!(isomorphic("N[C@@H](C)C(=O)O", "N[C@H](C)C(=O)O", false))
+    }
+
+    #[test]
+    fn enantiomers() {
+        assert!(!isomorphic("N[C@@H](C)C(=O)O", "N[C@H](C)C(=O)O", true))
+    }
+
+    #[test]
+    fn same_enantiomer_reordered() {
+        assert!(isomorphic("N[C@@H](C)C(=O)O", "OC(=O)[C@@H](N)C", true))
+    }
+
+    #[test]
+    fn specified_versus_unspecified() {
+        assert!(!isomorphic("N[C@@H](C)C(=O)O", "N[CH](C)C(=O)O", true))
+    }
+
+    #[test]
+    fn double_bond_isomers() {
+        assert!(!isomorphic("C/C=C/C", "C/C=C\\C", true))
+    }
+
+    #[test]
+    fn double_bond_isomers_without_stereo() {
+        assert!(isomorphic("C/C=C/C", "C/C=C\\C", false))
+    }
+
+    #[test]
+    fn same_double_bond_reordered() {
+        assert!(isomorphic("F/C=C/Cl", "Cl/C=C/F", true))
+    }
+
+    #[test]
+    fn same_double_bond_other_neighbor() {
+        assert!(isomorphic("C/C(F)=C/C", "C/C=C(\\F)C", true))
+    }
+
+    #[test]
+    fn specified_versus_unspecified_double_bond() {
+        assert!(!isomorphic("C/C=C/C", "CC=CC", true))
+    }
+}
@@ -0,0 +1,22 @@
+/// Controls substructure searches.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MatchOptions {
+    /// Report only one mapping per set of target atoms, so that symmetric
+    /// queries such as benzene aren't reported once per automorphism.
+    pub unique: bool,
+    /// Stop after this many mappings.
+    pub max_matches: Option<usize>,
+    /// Require query atoms with a parity to map onto target atoms with the
+    /// same configuration.
+    pub stereo: bool,
+}
+
+impl Default for MatchOptions {
+    fn default() -> Self {
+        Self {
+            unique: true,
+            max_matches: None,
+            stereo: false,
+        }
+    }
+}
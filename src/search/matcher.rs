@@ -0,0 +1,192 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::MatchOptions;
+use crate::graph::{Atom, Bond};
+
+/// Query side of a substructure search. Query atoms are identified by index
+/// from zero up to size.
+pub(crate) trait Matcher {
+    fn size(&self) -> usize;
+
+    fn neighbors(&self, query: usize) -> Vec<usize>;
+
+    /// Returns true if query atom can map onto the target atom at index.
+    fn atom(&self, query: usize, target: &[Atom], index: usize) -> bool;
+
+    /// Returns true if the bond between query atoms sid and tid can map onto
+    /// bond, which leaves the target atom at index.
+    fn bond(
+        &self,
+        sid: usize,
+        tid: usize,
+        target: &[Atom],
+        index: usize,
+        bond: &Bond,
+    ) -> bool;
+
+    /// Returns true if a complete mapping is acceptable.
+    fn accept(&self, _target: &[Atom], _mapping: &[usize]) -> bool {
+        true
+    }
+}
+
+/// Returns mappings of the query atoms of matcher onto target atoms, found
+/// by depth-first extension in the manner of VF2. Each mapping is indexed by
+/// query atom.
+pub(crate) fn search(
+    matcher: &impl Matcher,
+    target: &[Atom],
+    options: &MatchOptions,
+) -> Vec<Vec<usize>> {
+    let mut state = State {
+        matcher,
+        target,
+        options,
+        order: order(matcher),
+        mapping: vec![usize::MAX; matcher.size()],
+        used: vec![false; target.len()],
+        seen: HashSet::new(),
+        result: Vec::new(),
+    };
+
+    if matcher.size() > 0 {
+        state.extend(0);
+    }
+
+    state.result
+}
+
+struct State<'a, M: Matcher> {
+    matcher: &'a M,
+    target: &'a [Atom],
+    options: &'a MatchOptions,
+    order: Vec<(usize, Option<usize>)>,
+    mapping: Vec<usize>,
+    used: Vec<bool>,
+    seen: HashSet<Vec<usize>>,
+    result: Vec<Vec<usize>>,
+}
+
+impl<'a, M: Matcher> State<'a, M> {
+    fn done(&self) -> bool {
+        match self.options.max_matches {
+            Some(max) => self.result.len() >= max,
+            None => false,
+        }
+    }
+
+    fn extend(&mut self, depth: usize) {
+        if depth == self.order.len() {
+            return self.report();
+        }
+
+        let (query, parent) = self.order[depth];
+        let candidates = match parent {
+            Some(parent) => self.target[self.mapping[parent]]
+                .bonds
+                .iter()
+                .map(|bond| bond.tid)
+                .collect::<Vec<_>>(),
+            None => (0..self.target.len()).collect(),
+        };
+
+        for candidate in candidates {
+            if self.feasible(query, candidate) {
+                self.mapping[query] = candidate;
+                self.used[candidate] = true;
+                self.extend(depth + 1);
+                self.used[candidate] = false;
+                self.mapping[query] = usize::MAX;
+
+                if self.done() {
+                    return;
+                }
+            }
+        }
+    }
+
+    fn feasible(&self, query: usize, candidate: usize) -> bool {
+        if self.used[candidate] {
+            return false;
+        }
+
+        let neighbors = self.matcher.neighbors(query);
+
+        if self.target[candidate].bonds.len() < neighbors.len()
+            || !self.matcher.atom(query, self.target, candidate)
+        {
+            return false;
+        }
+
+        neighbors.into_iter().all(|neighbor| {
+            let mapped = self.mapping[neighbor];
+
+            if mapped == usize::MAX {
+                return true;
+            }
+
+            match self.target[candidate]
+                .bonds
+                .iter()
+                .find(|bond| bond.tid == mapped)
+            {
+                Some(bond) => self.matcher.bond(
+                    query,
+                    neighbor,
+                    self.target,
+                    candidate,
+                    bond,
+                ),
+                None => false,
+            }
+        })
+    }
+
+    fn report(&mut self) {
+        if !self.matcher.accept(self.target, &self.mapping) {
+            return;
+        }
+
+        if self.options.unique {
+            let mut key = self.mapping.clone();
+
+            key.sort_unstable();
+
+            if !self.seen.insert(key) {
+                return;
+            }
+        }
+
+        self.result.push(self.mapping.clone())
+    }
+}
+
+/// Orders query atoms breadth-first, component by component, pairing each
+/// with an already ordered neighbor to draw candidates from.
+fn order(matcher: &impl Matcher) -> Vec<(usize, Option<usize>)> {
+    let mut result = Vec::with_capacity(matcher.size());
+    let mut visited = vec![false; matcher.size()];
+
+    for root in 0..matcher.size() {
+        if visited[root] {
+            continue;
+        }
+
+        let mut queue = VecDeque::from([(root, None)]);
+
+        visited[root] = true;
+
+        while let Some((query, parent)) = queue.pop_front() {
+            result.push((query, parent));
+
+            for neighbor in matcher.neighbors(query) {
+                if !visited[neighbor] {
+                    visited[neighbor] = true;
+                    queue.push_back((neighbor, Some(query)));
+                }
+            }
+        }
+    }
+
+    result
+}
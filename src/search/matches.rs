@@ -0,0 +1,351 @@
+use super::{
+    parity, same_configuration, search, BondClass, MatchOptions, Matcher,
+};
+use crate::{
+    feature::{AtomKind, Isotope, Symbol},
+    graph::{Atom, Bond},
+};
+
+/// Returns the mappings of query onto target as subgraph isomorphisms.
+/// Each mapping gives the target index of every query atom in query order.
+///
+/// Query atoms match target atoms of the same element, selection, and
+/// charge. Bracket query atoms also require the same isotope and hydrogen
+/// count, where the target count includes implicit hydrogens. Star atoms
+/// match any element, and bracket Star atoms check only the features they
+/// write out. Bonds match by BondClass.
+pub fn matches(
+    query: &[Atom],
+    target: &[Atom],
+    options: &MatchOptions,
+) -> Vec<Vec<usize>> {
+    search(
+        &Query {
+            atoms: query,
+            stereo: options.stereo,
+        },
+        target,
+        options,
+    )
+}
+
+struct Query<'a> {
+    atoms: &'a [Atom],
+    stereo: bool,
+}
+
+impl<'a> Matcher for Query<'a> {
+    fn size(&self) -> usize {
+        self.atoms.len()
+    }
+
+    fn neighbors(&self, query: usize) -> Vec<usize> {
+        self.atoms[query]
+            .bonds
+            .iter()
+            .map(|bond| bond.tid)
+            .collect()
+    }
+
+    fn atom(&self, query: usize, target: &[Atom], index: usize) -> bool {
+        let query = &self.atoms[query].kind;
+        let target = &target[index];
+
+        match query {
+            AtomKind::Star => true,
+            AtomKind::Shortcut(_) | AtomKind::Selection(_) => {
+                same_element(query, &target.kind) && charge(&target.kind) == 0
+            }
+            AtomKind::Bracket(bracket) => {
+                if bracket.symbol == Symbol::Star {
+                    if bracket.hydrogens.is_some()
+                        && bracket.hydrogens() != target.hydrogens()
+                    {
+                        return false;
+                    }
+                } else if !same_element(query, &target.kind)
+                    || bracket.hydrogens() != target.hydrogens()
+                {
+                    return false;
+                }
+
+                bracket.charge() == charge(&target.kind)
+                    && bracket.isotope.as_ref() == isotope(&target.kind)
+            }
+        }
+    }
+
+    fn bond(
+        &self,
+        sid: usize,
+        tid: usize,
+        target: &[Atom],
+        index: usize,
+        bond: &Bond,
+    ) -> bool {
+        let query = self.atoms[sid]
+            .bonds
+            .iter()
+            .find(|bond| bond.tid == tid)
+            .expect("query bond");
+
+        BondClass::new(self.atoms, sid, query)
+            == BondClass::new(target, index, bond)
+    }
+
+    fn accept(&self, target: &[Atom], mapping: &[usize]) -> bool {
+        if !self.stereo {
+            return true;
+        }
+
+        (0..self.atoms.len()).all(|query| {
+            match parity(&self.atoms[query].kind) {
+                Some(parity) => same_configuration(
+                    self.atoms, query, parity, target, mapping,
+                ),
+                None => true,
+            }
+        })
+    }
+}
+
+fn same_element(query: &AtomKind, target: &AtomKind) -> bool {
+    query.element() == target.element()
+        && query.is_selected() == target.is_selected()
+}
+
+fn charge(kind: &AtomKind) -> i8 {
+    match kind {
+        AtomKind::Bracket(bracket) => bracket.charge(),
+        _ => 0,
+    }
+}
+
+fn isotope(kind: &AtomKind) -> Option<&Isotope> {
+    match kind {
+        AtomKind::Bracket(bracket) => bracket.isotope.as_ref(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn matches_of(
+        query: &str,
+        target: &str,
+        options: &MatchOptions,
+    ) -> Vec<Vec<usize>> {
+        matches(&atoms_of(query), &atoms_of(target), options)
+    }
+
+    #[test]
+    fn empty_query() {
+        assert_eq!(
+            matches_of("", "CC", &MatchOptions::default()),
+            Vec::<Vec<usize>>::new()
+        )
+    }
+
+    #[test]
+    fn single_atom() {
+        assert_eq!(
+            matches_of("O", "CCO", &MatchOptions::default()),
+            vec![vec![2]]
+        )
+    }
+
+    #[test]
+    fn unique() {
+        assert_eq!(
+            matches_of("CC", "CCO", &MatchOptions::default()),
+            vec![vec![0, 1]]
+        )
+    }
+
+    #[test]
+    fn not_unique() {
+        let options = MatchOptions {
+            unique: false,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            matches_of("CC", "CCO", &options),
+            vec![vec![0, 1], vec![1, 0]]
+        )
+    }
+
+    #[test]
+    fn benzene_in_toluene() {
+        assert_eq!(
+            matches_of("c1ccccc1", "Cc1ccccc1", &MatchOptions::default()),
+            vec![vec![1, 2, 3, 4, 5, 6]]
+        )
+    }
+
+    #[test]
+    fn benzene_automorphisms() {
+        let options = MatchOptions {
+            unique: false,
+            ..Default::default()
+        };
+
+        assert_eq!(matches_of("c1ccccc1", "c1ccccc1", &options).len(), 12)
+    }
+
+    #[test]
+    fn max_matches() {
+        let options = MatchOptions {
+            unique: false,
+            max_matches: Some(2),
+            ..Default::default()
+        };
+
+        assert_eq!(matches_of("c1ccccc1", "c1ccccc1", &options).len(), 2)
+    }
+
+    #[test]
+    fn aliphatic_query_aromatic_target() {
+        assert!(matches_of("C1CCCCC1", "c1ccccc1", &MatchOptions::default())
+            .is_empty())
+    }
+
+    #[test]
+    fn aromatic_bond_query_single_target() {
+        assert!(
+            matches_of("cc", "c1ccccc1-c1ccccc1", &MatchOptions::default())
+                .iter()
+                .all(|mapping| mapping != &vec![5, 6])
+        )
+    }
+
+    #[test]
+    fn bond_order() {
+        assert!(matches_of("C=O", "CCO", &MatchOptions::default()).is_empty())
+    }
+
+    #[test]
+    fn charge() {
+        assert_eq!(
+            matches_of("C(=O)[O-]", "CC(=O)[O-]", &MatchOptions::default()),
+            vec![vec![1, 2, 3]]
+        )
+    }
+
+    #[test]
+    fn uncharged_query_charged_target() {
+        assert!(matches_of("C(=O)O", "CC(=O)[O-]", &MatchOptions::default())
+            .is_empty())
+    }
+
+    #[test]
+    fn star() {
+        assert_eq!(
+            matches_of("*C(=O)O", "CC(=O)O", &MatchOptions::default()),
+            vec![vec![0, 1, 2, 3]]
+        )
+    }
+
+    #[test]
+    fn bracket_star_charge() {
+        assert_eq!(
+            matches_of("[*-]", "CC(=O)[O-]", &MatchOptions::default()),
+            vec![vec![3]]
+        )
+    }
+
+    #[test]
+    fn hydrogen_count() {
+        assert_eq!(
+            matches_of("[CH3]", "CCO", &MatchOptions::default()),
+            vec![vec![0]]
+        )
+    }
+
+    #[test]
+    fn isotope_mismatch() {
+        assert!(matches_of("[13CH4]", "C", &MatchOptions::default()).is_empty())
+    }
+
+    #[test]
+    fn isotope_match() {
+        assert_eq!(
+            matches_of("[13CH4]", "[13CH4]", &MatchOptions::default()),
+            vec![vec![0]]
+        )
+    }
+
+    #[test]
+    fn disconnected_query() {
+        assert_eq!(
+            matches_of("C.O", "CCO", &MatchOptions::default()),
+            vec![vec![0, 2], vec![1, 2]]
+        )
+    }
+
+    #[test]
+    fn stereo_ignored() {
+        assert_eq!(
+            matches_of(
+                "N[C@@H](C)C(=O)O",
+                "N[C@H](C)C(=O)O",
+                &MatchOptions::default()
+            )
+            .len(),
+            1
+        )
+    }
+
+    #[test]
+    fn stereo_mismatch() {
+        let options = MatchOptions {
+            stereo: true,
+            ..Default::default()
+        };
+
+        assert!(matches_of("N[C@@H](C)C(=O)O", "N[C@H](C)C(=O)O", &options)
+            .is_empty())
+    }
+
+    #[test]
+    fn stereo_reordered() {
+        let options = MatchOptions {
+            stereo: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            matches_of("N[C@@H](C)C(=O)O", "C[C@H](N)C(=O)O", &options),
+            vec![vec![2, 1, 0, 3, 4, 5]]
+        )
+    }
+
+    #[test]
+    fn stereo_unspecified_target() {
+        let options = MatchOptions {
+            stereo: true,
+            ..Default::default()
+        };
+
+        assert!(
+            matches_of("N[C@@H](C)C(=O)O", "NC(C)C(=O)O", &options).is_empty()
+        )
+    }
+
+    #[test]
+    fn stereo_partial_query() {
+        let options = MatchOptions {
+            stereo: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            matches_of("N[C@@H](C)C", "N[C@@H](C)C(=O)O", &options),
+            vec![vec![0, 1, 2, 3]]
+        )
+    }
+}
@@ -0,0 +1,456 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{BondClass, Comparison, McsOptions};
+use crate::{
+    follow::Writer,
+    graph::{walk, Atom, Bond},
+};
+
+/// A common substructure of two molecules.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Mcs {
+    /// Pairs of first and second atom indexes, ordered by the first.
+    pub atoms: Vec<(usize, usize)>,
+    /// Common bonds as pairs of first atom indexes, lower index first.
+    pub bonds: Vec<(usize, usize)>,
+    /// False if the search stopped at its step limit, in which case the
+    /// substructure may not be maximal.
+    pub complete: bool,
+}
+
+impl Mcs {
+    /// Returns the common substructure as it appears in first, written as a
+    /// Balsa string.
+    pub fn to_balsa(&self, first: &[Atom]) -> String {
+        let index = self
+            .atoms
+            .iter()
+            .enumerate()
+            .map(|(new, (old, _))| (*old, new))
+            .collect::<HashMap<_, _>>();
+        let bonds = self.bonds.iter().collect::<HashSet<_>>();
+        let atoms = self
+            .atoms
+            .iter()
+            .map(|(old, _)| Atom {
+                kind: first[*old].kind.clone(),
+                bonds: first[*old]
+                    .bonds
+                    .iter()
+                    .filter(|bond| {
+                        bonds.contains(&(
+                            *old.min(&bond.tid),
+                            *old.max(&bond.tid),
+                        ))
+                    })
+                    .map(|bond| Bond {
+                        kind: bond.kind.clone(),
+                        tid: index[&bond.tid],
+                    })
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+        let mut writer = Writer::new();
+
+        walk(&atoms, &mut writer);
+
+        writer.write()
+    }
+}
+
+/// Returns a maximum common substructure of first and second, the one with
+/// the most common bonds and, among those, the most atoms.
+///
+/// Connected searches grow substructures from each seed pair of atoms.
+/// Disconnected searches assign each atom of first in turn. Both prune
+/// branches that can't beat the best substructure found so far. Without a
+/// common bond, the result is a single pair of matching atoms.
+pub fn mcs(first: &[Atom], second: &[Atom], options: &McsOptions) -> Mcs {
+    let mut state = State {
+        first,
+        second,
+        options,
+        mapping: vec![None; first.len()],
+        used: vec![false; second.len()],
+        excluded: vec![false; first.len()],
+        bonds: 0,
+        steps: 0,
+        complete: true,
+        best: Mcs::default(),
+    };
+
+    if options.connected {
+        for seed in 0..first.len() {
+            for candidate in 0..second.len() {
+                if state.complete && state.atom_eq(seed, candidate) {
+                    state.map(seed, candidate);
+                    state.grow();
+                    state.unmap(seed, candidate);
+                }
+            }
+
+            state.excluded[seed] = true;
+        }
+    } else {
+        state.assign(0);
+    }
+
+    Mcs {
+        complete: state.complete,
+        ..state.best
+    }
+}
+
+struct State<'a> {
+    first: &'a [Atom],
+    second: &'a [Atom],
+    options: &'a McsOptions,
+    mapping: Vec<Option<usize>>,
+    used: Vec<bool>,
+    excluded: Vec<bool>,
+    bonds: usize,
+    steps: usize,
+    complete: bool,
+    best: Mcs,
+}
+
+impl<'a> State<'a> {
+    fn atom_eq(&self, first: usize, second: usize) -> bool {
+        match self.options.comparison {
+            Comparison::Any => true,
+            Comparison::Element | Comparison::ElementAndBondOrder => {
+                self.first[first].kind.element()
+                    == self.second[second].kind.element()
+            }
+        }
+    }
+
+    fn bond_eq(
+        &self,
+        sid: usize,
+        first: &Bond,
+        tid: usize,
+        second: &Bond,
+    ) -> bool {
+        match self.options.comparison {
+            Comparison::Any | Comparison::Element => true,
+            Comparison::ElementAndBondOrder => {
+                BondClass::new(self.first, sid, first)
+                    == BondClass::new(self.second, tid, second)
+            }
+        }
+    }
+
+    /// Returns the common bonds gained by mapping first onto second.
+    fn gain(&self, first: usize, second: usize) -> usize {
+        self.first[first]
+            .bonds
+            .iter()
+            .filter(|bond| match self.mapping[bond.tid] {
+                Some(mapped) => self.second[second]
+                    .bonds
+                    .iter()
+                    .find(|other| other.tid == mapped)
+                    .is_some_and(|other| {
+                        self.bond_eq(first, bond, second, other)
+                    }),
+                None => false,
+            })
+            .count()
+    }
+
+    fn map(&mut self, first: usize, second: usize) {
+        self.bonds += self.gain(first, second);
+        self.mapping[first] = Some(second);
+        self.used[second] = true;
+    }
+
+    fn unmap(&mut self, first: usize, second: usize) {
+        self.mapping[first] = None;
+        self.used[second] = false;
+        self.bonds -= self.gain(first, second);
+    }
+
+    /// Counts a step, returning false once the step limit is exceeded.
+    fn step(&mut self) -> bool {
+        self.steps += 1;
+
+        if let Some(max) = self.options.max_steps {
+            if self.steps > max {
+                self.complete = false;
+            }
+        }
+
+        self.complete
+    }
+
+    fn undecided(&self, first: usize) -> bool {
+        self.mapping[first].is_none() && !self.excluded[first]
+    }
+
+    /// Returns true if extending the current mapping could beat the best.
+    fn promising(&self) -> bool {
+        let mut first_bonds = 0;
+        let mut second_bonds = 0;
+
+        for (sid, atom) in self.first.iter().enumerate() {
+            for bond in atom.bonds.iter().filter(|bond| bond.tid > sid) {
+                if !self.excluded[sid]
+                    && !self.excluded[bond.tid]
+                    && (self.undecided(sid) || self.undecided(bond.tid))
+                {
+                    first_bonds += 1
+                }
+            }
+        }
+
+        for (sid, atom) in self.second.iter().enumerate() {
+            for bond in atom.bonds.iter().filter(|bond| bond.tid > sid) {
+                if !self.used[sid] || !self.used[bond.tid] {
+                    second_bonds += 1
+                }
+            }
+        }
+
+        let bonds = self.bonds + first_bonds.min(second_bonds);
+        let atoms = (0..self.first.len())
+            .filter(|&id| self.mapping[id].is_some() || self.undecided(id))
+            .count();
+
+        bonds > self.best.bonds.len()
+            || (bonds == self.best.bonds.len() && atoms > self.best.atoms.len())
+    }
+
+    fn record(&mut self) {
+        let mut bonds = Vec::new();
+
+        for (sid, atom) in self.first.iter().enumerate() {
+            let mapped = match self.mapping[sid] {
+                Some(mapped) => mapped,
+                None => continue,
+            };
+
+            for bond in atom.bonds.iter().filter(|bond| bond.tid > sid) {
+                if let Some(other) = self.mapping[bond.tid] {
+                    if let Some(second) = self.second[mapped]
+                        .bonds
+                        .iter()
+                        .find(|second| second.tid == other)
+                    {
+                        if self.bond_eq(sid, bond, mapped, second) {
+                            bonds.push((sid, bond.tid))
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut atoms = self
+            .mapping
+            .iter()
+            .enumerate()
+            .filter_map(|(first, second)| second.map(|second| (first, second)))
+            .filter(|(first, _)| {
+                bonds.is_empty()
+                    || bonds.iter().any(|(s, t)| s == first || t == first)
+            })
+            .collect::<Vec<_>>();
+
+        atoms.truncate(if bonds.is_empty() { 1 } else { atoms.len() });
+
+        if bonds.len() > self.best.bonds.len()
+            || (bonds.len() == self.best.bonds.len()
+                && atoms.len() > self.best.atoms.len())
+        {
+            self.best = Mcs {
+                atoms,
+                bonds,
+                complete: true,
+            }
+        }
+    }
+
+    fn grow(&mut self) {
+        if !self.step() {
+            return;
+        }
+
+        self.record();
+
+        if !self.promising() {
+            return;
+        }
+
+        let frontier = (0..self.first.len()).find(|&id| {
+            self.undecided(id)
+                && self.first[id]
+                    .bonds
+                    .iter()
+                    .any(|bond| self.mapping[bond.tid].is_some())
+        });
+        let frontier = match frontier {
+            Some(frontier) => frontier,
+            None => return,
+        };
+
+        for candidate in 0..self.second.len() {
+            if !self.used[candidate]
+                && self.atom_eq(frontier, candidate)
+                && self.gain(frontier, candidate) > 0
+            {
+                self.map(frontier, candidate);
+                self.grow();
+                self.unmap(frontier, candidate);
+
+                if !self.complete {
+                    return;
+                }
+            }
+        }
+
+        self.excluded[frontier] = true;
+        self.grow();
+        self.excluded[frontier] = false;
+    }
+
+    fn assign(&mut self, first: usize) {
+        if !self.step() {
+            return;
+        }
+
+        self.record();
+
+        if first == self.first.len() || !self.promising() {
+            return;
+        }
+
+        for candidate in 0..self.second.len() {
+            if !self.used[candidate] && self.atom_eq(first, candidate) {
+                self.map(first, candidate);
+                self.assign(first + 1);
+                self.unmap(first, candidate);
+
+                if !self.complete {
+                    return;
+                }
+            }
+        }
+
+        self.excluded[first] = true;
+        self.assign(first + 1);
+        self.excluded[first] = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn mcs_of(first: &str, second: &str, options: &McsOptions) -> Mcs {
+        mcs(&atoms_of(first), &atoms_of(second), options)
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            mcs_of("", "CC", &McsOptions::default()),
+            Mcs {
+                complete: true,
+                ..Default::default()
+            }
+        )
+    }
+
+    #[test]
+    fn no_common_bond() {
+        let mcs = mcs_of("O", "CCO", &McsOptions::default());
+
+        assert_eq!((mcs.atoms, mcs.bonds), (vec![(0, 2)], vec![]))
+    }
+
+    #[test]
+    fn identical() {
+        assert_eq!(
+            mcs_of("CCO", "CCO", &McsOptions::default()),
+            Mcs {
+                atoms: vec![(0, 0), (1, 1), (2, 2)],
+                bonds: vec![(0, 1), (1, 2)],
+                complete: true
+            }
+        )
+    }
+
+    #[test]
+    fn benzene_toluene() {
+        let mcs = mcs_of("Cc1ccccc1", "c1ccccc1", &McsOptions::default());
+
+        assert_eq!((mcs.atoms.len(), mcs.bonds.len()), (6, 6))
+    }
+
+    #[test]
+    fn connected() {
+        let mcs = mcs_of("CCOCC", "CCNCC", &McsOptions::default());
+
+        assert_eq!((mcs.atoms.len(), mcs.bonds.len()), (2, 1))
+    }
+
+    #[test]
+    fn disconnected() {
+        let options = McsOptions {
+            connected: false,
+            ..Default::default()
+        };
+        let mcs = mcs_of("CCOCC", "CCNCC", &options);
+
+        assert_eq!((mcs.atoms.len(), mcs.bonds.len()), (4, 2))
+    }
+
+    #[test]
+    fn element_ignores_bond_order() {
+        let options = McsOptions {
+            comparison: Comparison::Element,
+            ..Default::default()
+        };
+        let mcs = mcs_of("C=CO", "CCO", &options);
+
+        assert_eq!((mcs.atoms.len(), mcs.bonds.len()), (3, 2))
+    }
+
+    #[test]
+    fn element_and_bond_order() {
+        let mcs = mcs_of("C=CO", "CCO", &McsOptions::default());
+
+        assert_eq!((mcs.atoms.len(), mcs.bonds.len()), (2, 1))
+    }
+
+    #[test]
+    fn any() {
+        let options = McsOptions {
+            comparison: Comparison::Any,
+            ..Default::default()
+        };
+        let mcs = mcs_of("CCO", "CCN", &options);
+
+        assert_eq!((mcs.atoms.len(), mcs.bonds.len()), (3, 2))
+    }
+
+    #[test]
+    fn step_limit() {
+        let options = McsOptions {
+            max_steps: Some(1),
+            ..Default::default()
+        };
+
+        assert!(!mcs_of("c1ccccc1", "c1ccccc1", &options).complete)
+    }
+
+    #[test]
+    fn to_balsa() {
+        let first = atoms_of("OCC(=O)c1ccccc1");
+        let mcs = mcs(&first, &atoms_of("Cc1ccccc1"), &McsOptions::default());
+
+        assert_eq!(mcs.to_balsa(&first), "Cc(ccccc1)1")
+    }
+}
@@ -0,0 +1,23 @@
+use super::Comparison;
+
+/// Controls maximum common substructure searches.
+#[derive(Debug, PartialEq, Clone)]
+pub struct McsOptions {
+    /// Require the common substructure to be connected.
+    pub connected: bool,
+    pub comparison: Comparison,
+    /// Stop after this many search steps, returning the best substructure
+    /// found so far. Steps don't depend on the speed of the machine, so
+    /// limited searches are reproducible.
+    pub max_steps: Option<usize>,
+}
+
+impl Default for McsOptions {
+    fn default() -> Self {
+        Self {
+            connected: true,
+            comparison: Comparison::default(),
+            max_steps: None,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+mod atom_key;
+mod bond_class;
+mod comparison;
+mod is_isomorphic;
+mod match_options;
+mod matcher;
+mod matches;
+mod mcs;
+mod mcs_options;
+mod molecule_eq;
+mod same_configuration;
+mod symmetry_classes;
+
+pub use bond_class::BondClass;
+pub use comparison::Comparison;
+pub use is_isomorphic::is_isomorphic;
+pub use match_options::MatchOptions;
+pub use matches::matches;
+pub use mcs::{mcs, Mcs};
+pub use mcs_options::McsOptions;
+pub use molecule_eq::MoleculeEq;
+pub use symmetry_classes::symmetry_classes;
+
+pub(crate) use atom_key::AtomKey;
+pub(crate) use matcher::{search, Matcher};
+pub(crate) use same_configuration::{parity, same_configuration};
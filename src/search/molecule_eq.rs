@@ -0,0 +1,128 @@
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    hash::{Hash, Hasher},
+};
+
+use super::{is_isomorphic, AtomKey, BondClass};
+use crate::graph::Atom;
+
+/// Wraps atoms so that equality and hashing ignore atom order, for use as a
+/// key in hashed collections. Equality follows is_isomorphic. Wrappers only
+/// equal each other if both respect stereo or both ignore it.
+#[derive(Debug, Clone)]
+pub struct MoleculeEq {
+    atoms: Vec<Atom>,
+    stereo: bool,
+}
+
+impl MoleculeEq {
+    /// Returns a wrapper that respects tetrahedral parity and double bond
+    /// geometry.
+    pub fn new(atoms: Vec<Atom>) -> Self {
+        Self {
+            atoms,
+            stereo: true,
+        }
+    }
+
+    /// Returns a wrapper that ignores stereo.
+    pub fn without_stereo(atoms: Vec<Atom>) -> Self {
+        Self {
+            atoms,
+            stereo: false,
+        }
+    }
+
+    pub fn atoms_of(&self) -> &[Atom] {
+        &self.atoms
+    }
+
+    pub fn into_atoms(self) -> Vec<Atom> {
+        self.atoms
+    }
+}
+
+impl PartialEq for MoleculeEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.stereo == other.stereo
+            && is_isomorphic(&self.atoms, &other.atoms, self.stereo)
+    }
+}
+
+impl Eq for MoleculeEq {}
+
+/// Hashes the multiset of atoms, each described by its key and the keys and
+/// bond classes of its neighbors. Isomorphic molecules hash alike.
+impl Hash for MoleculeEq {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let keys = self.atoms.iter().map(AtomKey::new).collect::<Vec<_>>();
+        let mut environments = BTreeMap::new();
+
+        for (sid, atom) in self.atoms.iter().enumerate() {
+            let mut neighbors = atom
+                .bonds
+                .iter()
+                .map(|bond| {
+                    (&keys[bond.tid], BondClass::new(&self.atoms, sid, bond))
+                })
+                .collect::<Vec<_>>();
+
+            neighbors.sort_by(|a, b| {
+                a.0.cmp(b.0).then_with(|| (a.1 as u8).cmp(&(b.1 as u8)))
+            });
+
+            let mut hasher = DefaultHasher::new();
+
+            neighbors.hash(&mut hasher);
+            *environments
+                .entry((&keys[sid], hasher.finish()))
+                .or_insert(0usize) += 1;
+        }
+
+        environments.hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+    use std::collections::HashSet;
+
+    #[test]
+    fn eq() {
+        assert_eq!(
+            MoleculeEq::new(atoms_of("OCC")),
+            MoleculeEq::new(atoms_of("CCO"))
+        )
+    }
+
+    #[test]
+    fn ne_stereo_setting() {
+        assert_ne!(
+            MoleculeEq::new(atoms_of("CCO")),
+            MoleculeEq::without_stereo(atoms_of("CCO"))
+        )
+    }
+
+    #[test]
+    fn hash_set() {
+        let set = ["CCO", "OCC", "C(O)C", "COC", "[CH3]CO"]
+            .into_iter()
+            .map(|string| MoleculeEq::without_stereo(atoms_of(string)))
+            .collect::<HashSet<_>>();
+
+        assert_eq!(set.len(), 2)
+    }
+
+    #[test]
+    fn hash_set_stereo() {
+        let set = ["N[C@@H](C)O", "N[C@H](C)O", "O[C@@H](C)N", "NC(C)O"]
+            .into_iter()
+            .map(|string| MoleculeEq::new(atoms_of(string)))
+            .collect::<HashSet<_>>();
+
+        assert_eq!(set.len(), 3)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    feature::{AtomKind, AtomParity},
+    graph::Atom,
+    stereo::{is_odd, ligands, Ligand},
+};
+
+/// Returns the parity of a bracket atom, if any.
+pub(crate) fn parity(kind: &AtomKind) -> Option<&AtomParity> {
+    match kind {
+        AtomKind::Bracket(bracket) => bracket.parity.as_ref(),
+        _ => None,
+    }
+}
+
+/// Returns true if the target atom mapped from the atom at index has parity
+/// once the ligands of atoms are carried over to the target ligand order.
+pub(crate) fn same_configuration(
+    atoms: &[Atom],
+    index: usize,
+    parity: &AtomParity,
+    target: &[Atom],
+    mapping: &[usize],
+) -> bool {
+    let mapped = mapping[index];
+    let target_parity = match self::parity(&target[mapped].kind) {
+        Some(parity) => parity,
+        None => return false,
+    };
+    let expected = ligands(target, mapped);
+    let mut actual = ligands(atoms, index)
+        .into_iter()
+        .map(|ligand| match ligand {
+            Ligand::Atom(ligand) => Ligand::Atom(mapping[ligand]),
+            Ligand::Implicit => Ligand::Implicit,
+        })
+        .collect::<Vec<_>>();
+
+    if actual.len() != expected.len() {
+        return false;
+    }
+
+    let missing = expected
+        .iter()
+        .filter(|ligand| !actual.contains(ligand))
+        .collect::<Vec<_>>();
+
+    if let [missing] = missing[..] {
+        match actual.iter().position(|ligand| *ligand == Ligand::Implicit) {
+            Some(position) => actual[position] = *missing,
+            None => return false,
+        }
+    } else if !missing.is_empty() {
+        return false;
+    }
+
+    match is_odd(&actual, &expected) {
+        Some(odd) => odd != (parity == target_parity),
+        None => false,
+    }
+}
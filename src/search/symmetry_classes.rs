@@ -0,0 +1,135 @@
+use super::{AtomKey, BondClass};
+use crate::graph::Atom;
+
+/// Returns a symmetry class id for each atom. Atoms that are topologically
+/// equivalent share an id. Stereo is ignored.
+///
+/// Atoms are first ranked by element, selection, isotope, charge, hydrogen
+/// count, and degree. Each round then ranks atoms by their class followed by
+/// the sorted classes and bond classes of their neighbors, until the number
+/// of classes stops growing. Ids run from zero in order of these ranks, so
+/// they don't depend on atom order.
+pub fn symmetry_classes(atoms: &[Atom]) -> Vec<usize> {
+    let mut classes = rank(
+        &atoms
+            .iter()
+            .map(|atom| (AtomKey::new(atom), atom.bonds.len()))
+            .collect::<Vec<_>>(),
+    );
+    let mut count = classes.iter().max().map_or(0, |max| max + 1);
+
+    loop {
+        let keys = atoms
+            .iter()
+            .enumerate()
+            .map(|(sid, atom)| {
+                let mut neighbors = atom
+                    .bonds
+                    .iter()
+                    .map(|bond| {
+                        (
+                            classes[bond.tid],
+                            BondClass::new(atoms, sid, bond) as u8,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                neighbors.sort_unstable();
+
+                (classes[sid], neighbors)
+            })
+            .collect::<Vec<_>>();
+        let next = rank(&keys);
+        let next_count = next.iter().max().map_or(0, |max| max + 1);
+
+        if next_count == count {
+            break classes;
+        }
+
+        classes = next;
+        count = next_count;
+    }
+}
+
+fn rank<T: Ord>(keys: &[T]) -> Vec<usize> {
+    let mut sorted = keys.iter().collect::<Vec<_>>();
+
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    keys.iter()
+        .map(|key| sorted.binary_search(&key).expect("key"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn classes_of(string: &str) -> Vec<usize> {
+        symmetry_classes(&atoms_of(string))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(classes_of(""), Vec::<usize>::new())
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(classes_of("CCO"), vec![1, 0, 2])
+    }
+
+    #[test]
+    fn order_independent() {
+        assert_eq!(classes_of("OCC"), vec![2, 0, 1])
+    }
+
+    #[test]
+    fn propane() {
+        assert_eq!(classes_of("CCC"), vec![1, 0, 1])
+    }
+
+    #[test]
+    fn benzene() {
+        assert_eq!(classes_of("c1ccccc1"), vec![0; 6])
+    }
+
+    #[test]
+    fn toluene() {
+        let classes = classes_of("Cc1ccccc1");
+
+        assert_eq!(
+            (
+                classes[2] == classes[6],
+                classes[3] == classes[5],
+                classes[2] == classes[3],
+                classes[4] == classes[3]
+            ),
+            (true, true, false, false)
+        )
+    }
+
+    #[test]
+    fn charge() {
+        let classes = classes_of("C[N+](C)(C)C.C[N](C)C");
+
+        assert_ne!(classes[1], classes[6])
+    }
+
+    #[test]
+    fn isotope() {
+        let classes = classes_of("[13CH3]CC");
+
+        assert_ne!(classes[0], classes[2])
+    }
+
+    #[test]
+    fn stereo_ignored() {
+        let classes = classes_of("C[C@H](O)[C@@H](O)C");
+
+        assert_eq!((classes[1], classes[2]), (classes[3], classes[4]))
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    feature::{BondKind, Element},
+    graph::Atom,
+};
+
+/// Returns the rank of the acid whose proton sits, or would sit, on the
+/// oxygen or sulfur at index. Lower ranks are stronger: sulfur acids, then
+/// phosphorus acids, carboxylic acids, phenols, and thiols. Returns None
+/// for other atoms.
+pub fn acid_strength(atoms: &[Atom], index: usize) -> Option<u8> {
+    let atom = &atoms[index];
+    let element = atom.kind.element()?;
+
+    if !matches!(element, Element::O | Element::S) || atom.bonds.len() != 1 {
+        return None;
+    }
+
+    let bond = &atom.bonds[0];
+
+    if bond.kind.bond_order() != 1 {
+        return None;
+    }
+
+    let center = &atoms[bond.tid];
+    let oxo = center.bonds.iter().any(|bond| {
+        bond.kind == BondKind::Double
+            && matches!(
+                atoms[bond.tid].kind.element(),
+                Some(Element::O | Element::S)
+            )
+    });
+
+    match center.kind.element() {
+        Some(Element::S) if oxo => Some(0),
+        Some(Element::P) if oxo => Some(1),
+        Some(Element::C) if oxo => Some(2),
+        _ if center.kind.is_selected() => Some(3),
+        _ if element == Element::S => Some(4),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn strength(string: &str, index: usize) -> Option<u8> {
+        acid_strength(&atoms_of(string), index)
+    }
+
+    #[test]
+    fn sulfonic() {
+        assert_eq!(strength("CS(=O)(=O)O", 4), Some(0))
+    }
+
+    #[test]
+    fn phosphonic() {
+        assert_eq!(strength("CP(=O)(O)O", 3), Some(1))
+    }
+
+    #[test]
+    fn carboxylate() {
+        assert_eq!(strength("CC(=O)[O-]", 3), Some(2))
+    }
+
+    #[test]
+    fn phenol() {
+        assert_eq!(strength("Oc1ccccc1", 0), Some(3))
+    }
+
+    #[test]
+    fn thiol() {
+        assert_eq!(strength("CCS", 2), Some(4))
+    }
+
+    #[test]
+    fn alcohol() {
+        assert_eq!(strength("CCO", 2), None)
+    }
+
+    #[test]
+    fn carbonyl() {
+        assert_eq!(strength("CC(=O)O", 2), None)
+    }
+}
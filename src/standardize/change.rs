@@ -0,0 +1,22 @@
+use super::Normalization;
+
+/// A change made by standardize. Indexes refer to atoms, which keep their
+/// order throughout.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum Change {
+    /// A group was rewritten. Source is the nitrogen, sulfur, or phosphorus
+    /// and target the oxygen.
+    Normalized {
+        normalization: Normalization,
+        source: usize,
+        target: usize,
+    },
+    /// Hydrogens were added or removed to cancel the charge an atom had.
+    Neutralized { index: usize, charge: i8 },
+    /// A hydrogen moved from acid to base, so that the stronger acid carries
+    /// the negative charge.
+    Reionized { acid: usize, base: usize },
+    /// A Bracket atom was replaced with the Shortcut, Selection, or Star
+    /// atom it is equivalent to.
+    RemovedBracket(usize),
+}
@@ -0,0 +1,23 @@
+mod acid_strength;
+mod change;
+mod neutralize;
+mod normalization;
+mod normalize;
+mod reionize;
+mod remove_hydrogens;
+#[allow(clippy::module_inception)]
+mod standardize;
+mod standardize_options;
+mod standardized;
+
+pub use change::Change;
+pub use normalization::Normalization;
+pub use standardize::standardize;
+pub use standardize_options::StandardizeOptions;
+pub use standardized::Standardized;
+
+pub(crate) use acid_strength::acid_strength;
+pub(crate) use neutralize::{is_separated, neutralize, set_charge, shift};
+pub(crate) use normalize::normalize;
+pub(crate) use reionize::reionize;
+pub(crate) use remove_hydrogens::remove_hydrogens;
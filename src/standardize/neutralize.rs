@@ -0,0 +1,211 @@
+use super::{acid_strength, Change};
+use crate::{
+    feature::{
+        AtomKind, Charge, DefaultValenceModel, ValenceModel, VirtualHydrogen,
+    },
+    graph::Atom,
+};
+
+/// Cancels charges by adding hydrogens to anions and removing them from
+/// cations, where the neutral atom has a full valence. Atoms bonded to an
+/// atom of opposite charge are left alone. Anions are neutralized only as
+/// far as cations that can't be remain, keeping the charge on the strongest
+/// acids, so zwitterions survive.
+pub fn neutralize(atoms: &mut [Atom]) -> Vec<Change> {
+    let mut result = Vec::new();
+    let mut positive = 0;
+
+    for index in 0..atoms.len() {
+        let charge = atoms[index].charge();
+
+        if charge <= 0 || is_separated(atoms, index) {
+            continue;
+        }
+
+        if shift(&mut atoms[index], -charge) {
+            result.push(Change::Neutralized { index, charge })
+        } else {
+            positive += charge as i32
+        }
+    }
+
+    let mut anions = (0..atoms.len())
+        .filter(|index| {
+            atoms[*index].charge() < 0 && !is_separated(atoms, *index)
+        })
+        .collect::<Vec<_>>();
+
+    anions.sort_by_key(|index| acid_strength(atoms, *index).unwrap_or(u8::MAX));
+
+    for index in anions {
+        let charge = atoms[index].charge();
+
+        if positive > 0 {
+            positive += charge as i32;
+
+            continue;
+        }
+
+        if shift(&mut atoms[index], -charge) {
+            result.push(Change::Neutralized { index, charge })
+        }
+    }
+
+    result
+}
+
+/// Returns true if the atom at index is bonded to an atom of opposite
+/// charge.
+pub fn is_separated(atoms: &[Atom], index: usize) -> bool {
+    let sign = atoms[index].charge().signum();
+
+    atoms[index]
+        .bonds
+        .iter()
+        .any(|bond| atoms[bond.tid].charge().signum() == -sign)
+}
+
+/// Sets the charge of a Bracket atom.
+pub fn set_charge(atom: &mut Atom, charge: i8) {
+    if let AtomKind::Bracket(bracket) = &mut atom.kind {
+        bracket.charge = new_charge(charge)
+    }
+}
+
+/// Returns a charge written without a count when it is one.
+fn new_charge(value: i8) -> Option<Charge> {
+    match value {
+        1 => Some(Charge::Plus),
+        -1 => Some(Charge::Minus),
+        value => Charge::new(value),
+    }
+}
+
+/// Adds hydrogens to a Bracket atom, changing its charge by the same
+/// amount, if the result has a full valence. Parity is dropped, because
+/// the hydrogen it referred to may be gone.
+pub fn shift(atom: &mut Atom, hydrogens: i8) -> bool {
+    let element = match atom.kind.element() {
+        Some(element) => element,
+        None => return false,
+    };
+    let (count, charge) = match &atom.kind {
+        AtomKind::Bracket(bracket) => (
+            bracket.hydrogens() as i8 + hydrogens,
+            bracket.charge() + hydrogens,
+        ),
+        _ => return false,
+    };
+    let valence = atom.valence() as i8 + hydrogens;
+
+    if count < 0 || valence < 0 {
+        return false;
+    }
+
+    match DefaultValenceModel.subvalence(&element, charge, valence as u8) {
+        Ok(0) => (),
+        _ => return false,
+    }
+
+    if let AtomKind::Bracket(bracket) = &mut atom.kind {
+        bracket.hydrogens = VirtualHydrogen::new(count as u8);
+        bracket.charge = new_charge(charge);
+        bracket.parity = None
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{follow::Writer, graph::walk, read::atoms_of};
+    use pretty_assertions::assert_eq;
+
+    fn neutralized(string: &str) -> (String, Vec<Change>) {
+        let mut atoms = atoms_of(string);
+        let changes = neutralize(&mut atoms);
+        let mut writer = Writer::new();
+
+        walk(&atoms, &mut writer);
+
+        (writer.write(), changes)
+    }
+
+    fn change(index: usize, charge: i8) -> Change {
+        Change::Neutralized { index, charge }
+    }
+
+    #[test]
+    fn carboxylate() {
+        assert_eq!(
+            neutralized("CC(=O)[O-]"),
+            ("CC(=O)[OH1]".to_string(), vec![change(3, -1)])
+        )
+    }
+
+    #[test]
+    fn ammonium() {
+        assert_eq!(
+            neutralized("C[NH3+]"),
+            ("C[NH2]".to_string(), vec![change(1, 1)])
+        )
+    }
+
+    #[test]
+    fn quaternary_ammonium() {
+        assert_eq!(
+            neutralized("C[N+](C)(C)C.[Cl-]"),
+            ("C[N+](C)(C)C.[Cl-]".to_string(), vec![])
+        )
+    }
+
+    #[test]
+    fn zwitterion() {
+        assert_eq!(
+            neutralized("[NH3+]CC(=O)[O-]"),
+            (
+                "[NH2]CC(=O)[OH1]".to_string(),
+                vec![change(0, 1), change(4, -1)]
+            )
+        )
+    }
+
+    #[test]
+    fn betaine() {
+        assert_eq!(
+            neutralized("C[N+](C)(C)CC(=O)[O-]"),
+            ("C[N+](C)(C)CC(=O)[O-]".to_string(), vec![])
+        )
+    }
+
+    #[test]
+    fn keeps_strongest_acid() {
+        assert_eq!(
+            neutralized("C[N+](C)(C)C.CC[O-].CC(=O)[O-]"),
+            (
+                "C[N+](C)(C)C.CC[OH1].CC(=O)[O-]".to_string(),
+                vec![change(7, -1)]
+            )
+        )
+    }
+
+    #[test]
+    fn nitro() {
+        assert_eq!(
+            neutralized("C[N+](=O)[O-]"),
+            ("C[N+](=O)[O-]".to_string(), vec![])
+        )
+    }
+
+    #[test]
+    fn dianion() {
+        assert_eq!(
+            neutralized("[O-]C(=O)C(=O)[O-]"),
+            (
+                "[OH1]C(=O)C(=O)[OH1]".to_string(),
+                vec![change(0, -1), change(5, -1)]
+            )
+        )
+    }
+}
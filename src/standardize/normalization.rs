@@ -0,0 +1,9 @@
+/// A rewrite applied by the normalize step of standardize.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Normalization {
+    /// N=O on pentavalent nitrogen, as in nitro groups and N-oxides, becomes
+    /// [N+]-[O-].
+    NitrogenOxide,
+    /// [S+]-[O-] and [P+]-[O-] become S=O and P=O.
+    SulfurOxide,
+}
@@ -0,0 +1,176 @@
+use super::{set_charge, Change, Normalization};
+use crate::{
+    feature::{BondKind, Element},
+    graph::{to_bracket, Atom},
+};
+
+/// Rewrites the groups described by Normalization.
+pub fn normalize(atoms: &mut [Atom]) -> Vec<Change> {
+    let mut result = Vec::new();
+
+    for source in 0..atoms.len() {
+        let element = atoms[source].kind.element();
+
+        for index in 0..atoms[source].bonds.len() {
+            let bond = &atoms[source].bonds[index];
+            let target = bond.tid;
+
+            if !is_oxide(&atoms[target]) {
+                continue;
+            }
+
+            let normalization = match (&element, &bond.kind) {
+                (Some(Element::N), BondKind::Double)
+                    if atoms[source].charge() == 0
+                        && atoms[target].charge() == 0
+                        && is_pentavalent(&atoms[source]) =>
+                {
+                    Normalization::NitrogenOxide
+                }
+                (
+                    Some(Element::S | Element::P),
+                    BondKind::Elided | BondKind::Single,
+                ) if atoms[source].charge() == 1
+                    && atoms[target].charge() == -1 =>
+                {
+                    Normalization::SulfurOxide
+                }
+                _ => continue,
+            };
+            let (kind, charge) = match normalization {
+                Normalization::NitrogenOxide => (BondKind::Elided, 1),
+                Normalization::SulfurOxide => (BondKind::Double, 0),
+            };
+
+            to_bracket(&mut atoms[source]);
+            to_bracket(&mut atoms[target]);
+            set_charge(&mut atoms[source], charge);
+            set_charge(&mut atoms[target], -charge);
+            atoms[source].bonds[index].kind = kind.clone();
+            atoms[target].bonds[0].kind = kind;
+            result.push(Change::Normalized {
+                normalization,
+                source,
+                target,
+            })
+        }
+    }
+
+    result
+}
+
+/// Returns true for an oxygen bonded to nothing else and without hydrogens.
+fn is_oxide(atom: &Atom) -> bool {
+    atom.kind.element() == Some(Element::O)
+        && atom.bonds.len() == 1
+        && atom.hydrogens() == 0
+}
+
+/// Returns true if nitrogen has a valence of five, counting the extra bond
+/// implied by selection.
+fn is_pentavalent(atom: &Atom) -> bool {
+    let mut valence = atom.valence() + atom.implicit_hydrogens();
+
+    if atom.kind.is_selected() {
+        valence += 1
+    }
+
+    valence == 5
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{follow::Writer, graph::walk, read::atoms_of};
+    use pretty_assertions::assert_eq;
+
+    fn normalized(string: &str) -> (String, Vec<Change>) {
+        let mut atoms = atoms_of(string);
+        let changes = normalize(&mut atoms);
+        let mut writer = Writer::new();
+
+        walk(&atoms, &mut writer);
+
+        (writer.write(), changes)
+    }
+
+    fn change(
+        normalization: Normalization,
+        source: usize,
+        target: usize,
+    ) -> Change {
+        Change::Normalized {
+            normalization,
+            source,
+            target,
+        }
+    }
+
+    #[test]
+    fn unchanged() {
+        assert_eq!(
+            normalized("C[N+](=O)[O-]"),
+            ("C[N+](=O)[O-]".to_string(), vec![])
+        )
+    }
+
+    #[test]
+    fn nitro() {
+        assert_eq!(
+            normalized("CN(=O)=O"),
+            (
+                "C[N+]([O-])=O".to_string(),
+                vec![change(Normalization::NitrogenOxide, 1, 2)]
+            )
+        )
+    }
+
+    #[test]
+    fn amine_oxide() {
+        assert_eq!(
+            normalized("CN(C)(C)=O"),
+            (
+                "C[N+](C)(C)[O-]".to_string(),
+                vec![change(Normalization::NitrogenOxide, 1, 4)]
+            )
+        )
+    }
+
+    #[test]
+    fn pyridine_oxide() {
+        assert_eq!(
+            normalized("O=n1ccccc1"),
+            (
+                "[O-][n+](ccccc1)1".to_string(),
+                vec![change(Normalization::NitrogenOxide, 1, 0)]
+            )
+        )
+    }
+
+    #[test]
+    fn oxime() {
+        assert_eq!(normalized("CC=NO"), ("CC=NO".to_string(), vec![]))
+    }
+
+    #[test]
+    fn sulfoxide() {
+        assert_eq!(
+            normalized("C[S+](C)[O-]"),
+            (
+                "C[S](C)=[O]".to_string(),
+                vec![change(Normalization::SulfurOxide, 1, 3)]
+            )
+        )
+    }
+
+    #[test]
+    fn phosphine_oxide() {
+        assert_eq!(
+            normalized("C[P+](C)(C)[O-]"),
+            (
+                "C[P](C)(C)=[O]".to_string(),
+                vec![change(Normalization::SulfurOxide, 1, 4)]
+            )
+        )
+    }
+}
@@ -0,0 +1,127 @@
+use super::{acid_strength, is_separated, shift, Change};
+use crate::{
+    feature::AtomKind,
+    graph::{to_bracket, Atom},
+};
+
+/// Moves hydrogens from stronger acids to the anions of weaker ones, one at
+/// a time, until each anion is at least as strong an acid as every acid
+/// still protonated. Anions that aren't acids rank below all acids.
+pub fn reionize(atoms: &mut [Atom]) -> Vec<Change> {
+    let mut result = Vec::new();
+
+    loop {
+        let base = (0..atoms.len())
+            .filter(|index| is_anion(atoms, *index))
+            .max_by_key(|index| {
+                (acid_strength(atoms, *index).unwrap_or(u8::MAX), *index)
+            });
+        let acid = (0..atoms.len())
+            .filter_map(|index| {
+                if is_protonated(atoms, index) {
+                    Some((acid_strength(atoms, index)?, index))
+                } else {
+                    None
+                }
+            })
+            .min();
+        let (base, (strength, acid)) = match (base, acid) {
+            (Some(base), Some(acid)) => (base, acid),
+            _ => break,
+        };
+
+        if strength >= acid_strength(atoms, base).unwrap_or(u8::MAX) {
+            break;
+        }
+
+        let mut changed = atoms.to_vec();
+
+        to_bracket(&mut changed[acid]);
+
+        if shift(&mut changed[base], 1) && shift(&mut changed[acid], -1) {
+            atoms.clone_from_slice(&changed);
+            result.push(Change::Reionized { acid, base })
+        } else {
+            break;
+        }
+    }
+
+    result
+}
+
+fn is_anion(atoms: &[Atom], index: usize) -> bool {
+    match &atoms[index].kind {
+        AtomKind::Bracket(bracket) => {
+            bracket.charge() == -1 && !is_separated(atoms, index)
+        }
+        _ => false,
+    }
+}
+
+fn is_protonated(atoms: &[Atom], index: usize) -> bool {
+    match &atoms[index].kind {
+        AtomKind::Bracket(bracket) => {
+            bracket.charge() == 0 && bracket.hydrogens() > 0
+        }
+        AtomKind::Shortcut(_) => atoms[index].hydrogens() > 0,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{follow::Writer, graph::walk, read::atoms_of};
+    use pretty_assertions::assert_eq;
+
+    fn reionized(string: &str) -> (String, Vec<Change>) {
+        let mut atoms = atoms_of(string);
+        let changes = reionize(&mut atoms);
+        let mut writer = Writer::new();
+
+        walk(&atoms, &mut writer);
+
+        (writer.write(), changes)
+    }
+
+    #[test]
+    fn unchanged() {
+        assert_eq!(
+            reionized("C[N+](C)(C)CC(=O)[O-]"),
+            ("C[N+](C)(C)CC(=O)[O-]".to_string(), vec![])
+        )
+    }
+
+    #[test]
+    fn phenolate() {
+        assert_eq!(
+            reionized("C[N+](C)(C)Cc1ccc([O-])cc1C(=O)O"),
+            (
+                "C[N+](C)(C)Cc(c(cc(cc1)[OH1])C(=O)[O-])1".to_string(),
+                vec![Change::Reionized { acid: 14, base: 9 }]
+            )
+        )
+    }
+
+    #[test]
+    fn sulfonic_acid() {
+        assert_eq!(
+            reionized("[O-]C(=O)CCS(=O)(=O)O.C[N+](C)(C)C"),
+            (
+                "[OH1]C(=O)CCS(=O)(=O)[O-].C[N+](C)(C)C".to_string(),
+                vec![Change::Reionized { acid: 8, base: 0 }]
+            )
+        )
+    }
+
+    #[test]
+    fn alkoxide() {
+        assert_eq!(
+            reionized("CC[O-].CC(=O)O"),
+            (
+                "CC[OH1].CC(=O)[O-]".to_string(),
+                vec![Change::Reionized { acid: 6, base: 2 }]
+            )
+        )
+    }
+}
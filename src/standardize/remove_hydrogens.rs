@@ -0,0 +1,156 @@
+use super::Change;
+use crate::{
+    feature::{AtomKind, DefaultValenceModel, Element, Shortcut, Symbol},
+    graph::Atom,
+};
+
+/// Replaces Bracket atoms with the Shortcut, Selection, or Star atoms they
+/// are equivalent to. A bracket qualifies when it has no isotope, parity,
+/// or charge, and its hydrogen count is the one the replacement would
+/// imply.
+pub fn remove_hydrogens(atoms: &mut [Atom]) -> Vec<Change> {
+    let mut result = Vec::new();
+
+    for (index, atom) in atoms.iter_mut().enumerate() {
+        let bracket = match &atom.kind {
+            AtomKind::Bracket(bracket) => bracket,
+            _ => continue,
+        };
+
+        if bracket.isotope.is_some()
+            || bracket.parity.is_some()
+            || bracket.charge() != 0
+        {
+            continue;
+        }
+
+        let kind = match &bracket.symbol {
+            Symbol::Star => AtomKind::Star,
+            Symbol::Selection(selection) => {
+                AtomKind::Selection(selection.clone())
+            }
+            Symbol::Element(element) => match shortcut(element) {
+                Some(shortcut) => AtomKind::Shortcut(shortcut),
+                None => continue,
+            },
+        };
+        let hydrogens = bracket.hydrogens();
+        let replacement = Atom {
+            kind,
+            bonds: atom.bonds.clone(),
+        };
+
+        if replacement.hydrogens() == hydrogens
+            && replacement.checked_subvalence(&DefaultValenceModel).is_ok()
+        {
+            *atom = replacement;
+            result.push(Change::RemovedBracket(index))
+        }
+    }
+
+    result
+}
+
+fn shortcut(element: &Element) -> Option<Shortcut> {
+    Some(match element {
+        Element::B => Shortcut::B,
+        Element::C => Shortcut::C,
+        Element::N => Shortcut::N,
+        Element::O => Shortcut::O,
+        Element::F => Shortcut::F,
+        Element::Cl => Shortcut::Cl,
+        Element::Br => Shortcut::Br,
+        Element::I => Shortcut::I,
+        Element::P => Shortcut::P,
+        Element::S => Shortcut::S,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{follow::Writer, graph::walk, read::atoms_of};
+    use pretty_assertions::assert_eq;
+
+    fn removed(string: &str) -> (String, Vec<Change>) {
+        let mut atoms = atoms_of(string);
+        let changes = remove_hydrogens(&mut atoms);
+        let mut writer = Writer::new();
+
+        walk(&atoms, &mut writer);
+
+        (writer.write(), changes)
+    }
+
+    #[test]
+    fn methane() {
+        assert_eq!(
+            removed("[CH4]"),
+            ("C".to_string(), vec![Change::RemovedBracket(0)])
+        )
+    }
+
+    #[test]
+    fn ethanol() {
+        assert_eq!(
+            removed("[CH3][CH2]O"),
+            (
+                "CCO".to_string(),
+                vec![Change::RemovedBracket(0), Change::RemovedBracket(1)]
+            )
+        )
+    }
+
+    #[test]
+    fn radical() {
+        assert_eq!(removed("[CH2]C"), ("[CH2]C".to_string(), vec![]))
+    }
+
+    #[test]
+    fn pyrrole() {
+        assert_eq!(
+            removed("[nH]1[cH]ccc1"),
+            ("[nH1](cccc1)1".to_string(), vec![Change::RemovedBracket(1)])
+        )
+    }
+
+    #[test]
+    fn charged() {
+        assert_eq!(removed("C[NH3+]"), ("C[NH3+]".to_string(), vec![]))
+    }
+
+    #[test]
+    fn isotope() {
+        assert_eq!(removed("[13CH4]"), ("[13CH4]".to_string(), vec![]))
+    }
+
+    #[test]
+    fn parity() {
+        assert_eq!(
+            removed("N[C@@H](C)C(=O)O"),
+            ("N[C@@H1](C)C(=O)O".to_string(), vec![])
+        )
+    }
+
+    #[test]
+    fn not_shortcut() {
+        assert_eq!(removed("[SiH4]"), ("[SiH4]".to_string(), vec![]))
+    }
+
+    #[test]
+    fn star() {
+        assert_eq!(
+            removed("[*]C"),
+            ("*C".to_string(), vec![Change::RemovedBracket(0)])
+        )
+    }
+
+    #[test]
+    fn sulfoxide() {
+        assert_eq!(
+            removed("C[S](C)=O"),
+            ("CS(C)=O".to_string(), vec![Change::RemovedBracket(1)])
+        )
+    }
+}
@@ -0,0 +1,152 @@
+use super::{
+    neutralize, normalize, reionize, remove_hydrogens, StandardizeOptions,
+    Standardized,
+};
+use crate::graph::Atom;
+
+/// Applies the steps selected by options, in this order:
+///
+/// 1. normalize: nitrogen oxides become charge-separated, as in
+///    `C[N+](=O)[O-]`, and charge-separated sulfur and phosphorus oxides
+///    become double bonds, as in `CS(C)=O`.
+/// 2. neutralize: charges are cancelled by adding or removing hydrogens
+///    where the neutral atom has a full valence. Cations that can't be
+///    neutralized keep as many anions, chosen from the strongest acids.
+/// 3. reionize: hydrogens move from stronger acids to anions of weaker
+///    ones, so zwitterions carry the charge on their strongest acid.
+/// 4. remove hydrogens: Bracket atoms equivalent to Shortcut, Selection, or
+///    Star atoms are replaced.
+///
+/// Atoms keep their order, and the result records each change.
+pub fn standardize(
+    atoms: &[Atom],
+    options: &StandardizeOptions,
+) -> Standardized {
+    let mut atoms = atoms.to_vec();
+    let mut changes = Vec::new();
+
+    if options.normalize {
+        changes.extend(normalize(&mut atoms))
+    }
+
+    if options.neutralize {
+        changes.extend(neutralize(&mut atoms))
+    }
+
+    if options.reionize {
+        changes.extend(reionize(&mut atoms))
+    }
+
+    if options.remove_hydrogens {
+        changes.extend(remove_hydrogens(&mut atoms))
+    }
+
+    Standardized { atoms, changes }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        read::atoms_of,
+        standardize::{Change, Normalization},
+    };
+    use pretty_assertions::assert_eq;
+
+    fn standardized(
+        string: &str,
+        options: &StandardizeOptions,
+    ) -> (String, Vec<Change>) {
+        let result = standardize(&atoms_of(string), options);
+
+        (result.to_balsa(), result.changes)
+    }
+
+    #[test]
+    fn unchanged() {
+        assert_eq!(
+            standardized("CC(=O)O", &Default::default()),
+            ("CC(=O)O".to_string(), vec![])
+        )
+    }
+
+    #[test]
+    fn nitro_acid() {
+        assert_eq!(
+            standardized("O=N(=O)c1ccccc1C(=O)[O-]", &Default::default()),
+            (
+                "[O-][N+](=O)c(c(cccc1)C(=O)O)1".to_string(),
+                vec![
+                    Change::Normalized {
+                        normalization: Normalization::NitrogenOxide,
+                        source: 1,
+                        target: 0
+                    },
+                    Change::Neutralized {
+                        index: 11,
+                        charge: -1
+                    },
+                    Change::RemovedBracket(11)
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn salt() {
+        assert_eq!(
+            standardized("CC(=O)[O-].[Na+]", &Default::default()),
+            ("CC(=O)[O-].[Na+]".to_string(), vec![])
+        )
+    }
+
+    #[test]
+    fn zwitterion() {
+        assert_eq!(
+            standardized(
+                "C[N+](C)(C)Cc1ccc([O-])cc1C(=O)O",
+                &Default::default()
+            ),
+            (
+                "C[N+](C)(C)Cc(c(cc(cc1)O)C(=O)[O-])1".to_string(),
+                vec![
+                    Change::Reionized { acid: 14, base: 9 },
+                    Change::RemovedBracket(9)
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn steps_off() {
+        let options = StandardizeOptions {
+            normalize: false,
+            neutralize: false,
+            reionize: false,
+            remove_hydrogens: false,
+        };
+
+        assert_eq!(
+            standardized("[CH3]N(=O)=O.[NH4+]", &options),
+            ("[CH3]N(=O)=O.[NH4+]".to_string(), vec![])
+        )
+    }
+
+    #[test]
+    fn only_remove_hydrogens() {
+        let options = StandardizeOptions {
+            normalize: false,
+            neutralize: false,
+            reionize: false,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            standardized("[CH3]N(=O)=O.[NH4+]", &options),
+            (
+                "CN(=O)=O.[NH4+]".to_string(),
+                vec![Change::RemovedBracket(0)]
+            )
+        )
+    }
+}
@@ -0,0 +1,25 @@
+/// Selects the steps of standardize. All are on by default.
+#[derive(Debug, PartialEq, Clone)]
+pub struct StandardizeOptions {
+    /// Rewrite nitrogen oxides as charge-separated and sulfur and
+    /// phosphorus oxides as neutral double bonds.
+    pub normalize: bool,
+    /// Add or remove hydrogens to cancel charges, keeping zwitterions.
+    pub neutralize: bool,
+    /// Move negative charges to the strongest acids.
+    pub reionize: bool,
+    /// Replace Bracket atoms that say no more than a Shortcut or Selection
+    /// atom would.
+    pub remove_hydrogens: bool,
+}
+
+impl Default for StandardizeOptions {
+    fn default() -> Self {
+        Self {
+            normalize: true,
+            neutralize: true,
+            reionize: true,
+            remove_hydrogens: true,
+        }
+    }
+}
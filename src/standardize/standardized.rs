@@ -0,0 +1,24 @@
+use super::Change;
+use crate::{
+    follow::Writer,
+    graph::{walk, Atom},
+};
+
+/// The result of standardize.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Standardized {
+    pub atoms: Vec<Atom>,
+    /// Changes in the order they were made.
+    pub changes: Vec<Change>,
+}
+
+impl Standardized {
+    /// Returns atoms written as a Balsa string.
+    pub fn to_balsa(&self) -> String {
+        let mut writer = Writer::new();
+
+        walk(&self.atoms, &mut writer);
+
+        writer.write()
+    }
+}
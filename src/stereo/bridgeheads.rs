@@ -0,0 +1,155 @@
+use super::{ligands, Ligand};
+use crate::graph::Atom;
+
+/// Returns pairs of centers joined by three bridges of one to three atoms
+/// each, as in norbornane. Bridges that short can't turn inward, so the
+/// configuration of one bridgehead fixes the other's.
+///
+/// Each center comes with its ligands in order: the ligand outside the
+/// bridges, then the first atom of each bridge, taking bridges in the same
+/// order for both centers. Viewed this way, the two centers of a possible
+/// molecule wind in opposite directions.
+pub(crate) fn bridgeheads(
+    atoms: &[Atom],
+    centers: &[usize],
+) -> Vec<[(usize, [Ligand; 4]); 2]> {
+    let mut result = Vec::new();
+
+    for (i, &first) in centers.iter().enumerate() {
+        for &second in &centers[i + 1..] {
+            let bridges = match bridges(atoms, first, second) {
+                Some(bridges) => bridges,
+                None => continue,
+            };
+            let first_ligands = order(atoms, first, &bridges, |path| path[0]);
+            let second_ligands =
+                order(atoms, second, &bridges, |path| path[path.len() - 1]);
+
+            if let (Some(first_ligands), Some(second_ligands)) =
+                (first_ligands, second_ligands)
+            {
+                result.push([(first, first_ligands), (second, second_ligands)])
+            }
+        }
+    }
+
+    result
+}
+
+/// Returns the interior atoms of three disjoint bridges from first to
+/// second, or None if there are no such bridges.
+fn bridges(
+    atoms: &[Atom],
+    first: usize,
+    second: usize,
+) -> Option<[Vec<usize>; 3]> {
+    let mut paths = Vec::new();
+    let mut stack = vec![vec![first]];
+
+    while let Some(path) = stack.pop() {
+        let last = path[path.len() - 1];
+
+        for bond in &atoms[last].bonds {
+            if bond.tid == second && path.len() > 1 {
+                paths.push(path[1..].to_vec())
+            } else if path.len() <= 3
+                && bond.tid != second
+                && !path.contains(&bond.tid)
+            {
+                let mut next = path.clone();
+
+                next.push(bond.tid);
+                stack.push(next)
+            }
+        }
+    }
+
+    let disjoint =
+        |a: &Vec<usize>, b: &Vec<usize>| a.iter().all(|id| !b.contains(id));
+
+    for (i, a) in paths.iter().enumerate() {
+        for (j, b) in paths.iter().enumerate().skip(i + 1) {
+            if !disjoint(a, b) {
+                continue;
+            }
+
+            for c in &paths[j + 1..] {
+                if disjoint(a, c) && disjoint(b, c) {
+                    return Some([a.clone(), b.clone(), c.clone()]);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns the ligands of center with the one outside bridges first, then
+/// the atom that end picks from each bridge.
+fn order<F>(
+    atoms: &[Atom],
+    center: usize,
+    bridges: &[Vec<usize>; 3],
+    end: F,
+) -> Option<[Ligand; 4]>
+where
+    F: Fn(&[usize]) -> usize,
+{
+    let inner = bridges
+        .iter()
+        .map(|bridge| Ligand::Atom(end(bridge)))
+        .collect::<Vec<_>>();
+    let outer = ligands(atoms, center)
+        .into_iter()
+        .filter(|ligand| !inner.contains(ligand))
+        .collect::<Vec<_>>();
+
+    match outer[..] {
+        [outer] => Some([outer, inner[0], inner[1], inner[2]]),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn norbornane() {
+        assert_eq!(
+            bridgeheads(&atoms_of("C1CC2CCC1C2"), &[2, 5]),
+            vec![[
+                (
+                    2,
+                    [
+                        Ligand::Implicit,
+                        Ligand::Atom(3),
+                        Ligand::Atom(6),
+                        Ligand::Atom(1)
+                    ]
+                ),
+                (
+                    5,
+                    [
+                        Ligand::Implicit,
+                        Ligand::Atom(4),
+                        Ligand::Atom(6),
+                        Ligand::Atom(0)
+                    ]
+                )
+            ]]
+        )
+    }
+
+    #[test]
+    fn decalin() {
+        assert!(bridgeheads(&atoms_of("C1CCC2CCCCC2C1"), &[3, 8]).is_empty())
+    }
+
+    #[test]
+    fn long_bridges() {
+        assert!(bridgeheads(&atoms_of("C1CCCC2CCCCC1CCCC2"), &[4, 9]).is_empty())
+    }
+}
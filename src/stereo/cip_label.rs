@@ -0,0 +1,18 @@
+use super::Descriptor;
+
+/// The CIP descriptor of a stereogenic unit. A descriptor of None flags a
+/// unit whose label couldn't be decided, such as a center with two ligands
+/// the implemented rules can't tell apart or a double bond with markers on
+/// one side only.
+#[derive(Debug, PartialEq, Clone)]
+pub enum CipLabel {
+    Tetrahedral {
+        center: usize,
+        descriptor: Option<Descriptor>,
+    },
+    DoubleBond {
+        source: usize,
+        target: usize,
+        descriptor: Option<Descriptor>,
+    },
+}
@@ -0,0 +1,394 @@
+use std::cmp::Ordering;
+
+use super::{
+    digraph::{Digraph, Exhausted, Rule},
+    tetrahedral_centers, CipLabel, Descriptor, Ligand, Side, Tetrahedral,
+};
+use crate::{
+    feature::{AtomParity, BondKind},
+    graph::Atom,
+};
+
+/// Returns Cahn-Ingold-Prelog labels for the tetrahedral centers found by
+/// tetrahedral_centers and for double bonds with Up or Down markers, in that
+/// order.
+///
+/// Ligands are ranked over a hierarchical digraph (see the 2013 IUPAC
+/// recommendations, P-92) by rule 1a, then rule 2. Centers still tied are
+/// ranked again with rules 4a and 5, using the labels of other centers from
+/// the first round, which covers the common pseudoasymmetric cases. Rule 1b
+/// and rules 3, 4b and 4c are not implemented; units they would decide are
+/// flagged with a descriptor of None, as are units whose digraph grows too
+/// large to explore.
+pub fn cip_labels(atoms: &[Atom]) -> Vec<CipLabel> {
+    let centers = tetrahedral_centers(atoms);
+    let mut ranks = vec![0; atoms.len()];
+    let mut descriptors = Vec::new();
+    let empty = vec![0; atoms.len()];
+
+    for center in &centers {
+        let descriptor = tetrahedral(atoms, center, &empty, Rule::Mass);
+
+        if let Ok(Some(descriptor)) = descriptor {
+            ranks[center.center] = rank(descriptor)
+        }
+
+        descriptors.push(descriptor)
+    }
+
+    let mut result = Vec::new();
+
+    for (center, descriptor) in centers.iter().zip(descriptors) {
+        let descriptor = match descriptor {
+            Ok(None) => tetrahedral(atoms, center, &ranks, Rule::Descriptor)
+                .ok()
+                .flatten()
+                .map(|descriptor| match descriptor {
+                    Descriptor::R => Descriptor::PseudoR,
+                    Descriptor::S => Descriptor::PseudoS,
+                    descriptor => descriptor,
+                }),
+            Ok(descriptor) => descriptor,
+            Err(Exhausted) => None,
+        };
+
+        result.push(CipLabel::Tetrahedral {
+            center: center.center,
+            descriptor,
+        })
+    }
+
+    for (source, atom) in atoms.iter().enumerate() {
+        for bond in &atom.bonds {
+            if bond.kind != BondKind::Double || bond.tid < source {
+                continue;
+            }
+
+            if let Some(descriptor) =
+                double_bond(atoms, source, bond.tid, &ranks)
+            {
+                result.push(CipLabel::DoubleBond {
+                    source,
+                    target: bond.tid,
+                    descriptor,
+                })
+            }
+        }
+    }
+
+    result
+}
+
+fn rank(descriptor: Descriptor) -> u8 {
+    match descriptor {
+        Descriptor::R | Descriptor::PseudoR => 2,
+        Descriptor::S | Descriptor::PseudoS => 1,
+        Descriptor::E | Descriptor::Z => 0,
+    }
+}
+
+/// Returns R or S, None on a tie, or Exhausted.
+fn tetrahedral(
+    atoms: &[Atom],
+    center: &Tetrahedral,
+    ranks: &[u8],
+    last: Rule,
+) -> Result<Option<Descriptor>, Exhausted> {
+    let mut digraph = Digraph::new(atoms, ranks);
+    let root = digraph.root(center.center);
+    let children = digraph.children(root)?;
+    let mut nodes = Vec::new();
+
+    for ligand in &center.ligands {
+        let node = children.iter().copied().find(|child| match ligand {
+            Ligand::Atom(index) => digraph.atom(*child) == Some(*index),
+            Ligand::Implicit => digraph.is_hydrogen(*child),
+        });
+
+        nodes.push((*ligand, node))
+    }
+
+    // Implicit ligands without a hydrogen are lone pairs, which come last.
+    let mut sorted: Vec<(Ligand, Option<usize>)> = Vec::new();
+
+    for (ligand, node) in nodes {
+        let mut position = sorted.len();
+
+        for (i, (_, other)) in sorted.iter().enumerate() {
+            let ordering = match (node, other) {
+                (Some(node), Some(other)) => {
+                    digraph.compare(node, *other, last)?
+                }
+                (Some(_), None) => Ordering::Greater,
+                (None, Some(_)) => Ordering::Less,
+                (None, None) => Ordering::Equal,
+            };
+
+            match ordering {
+                Ordering::Greater => {
+                    position = i;
+
+                    break;
+                }
+                Ordering::Equal => return Ok(None),
+                Ordering::Less => (),
+            }
+        }
+
+        sorted.insert(position, (ligand, node))
+    }
+
+    let order = [sorted[3].0, sorted[0].0, sorted[1].0, sorted[2].0];
+
+    Ok(match center.winding_in(&order) {
+        Some(AtomParity::Counterclockwise) => Some(Descriptor::R),
+        Some(AtomParity::Clockwise) => Some(Descriptor::S),
+        None => None,
+    })
+}
+
+/// Returns None if the bond carries no markers, Some(None) if it can't be
+/// labelled, and the label otherwise.
+fn double_bond(
+    atoms: &[Atom],
+    source: usize,
+    target: usize,
+    ranks: &[u8],
+) -> Option<Option<Descriptor>> {
+    let (first, first_kind, second, second_kind) = match (
+        Side::new(atoms, source, target),
+        Side::new(atoms, target, source),
+    ) {
+        (Side::Unmarked, Side::Unmarked) => return None,
+        (
+            Side::Marked(first, first_kind),
+            Side::Marked(second, second_kind),
+        ) => (first, first_kind, second, second_kind),
+        _ => return Some(None),
+    };
+    let first_top = match top(atoms, source, target, ranks) {
+        Some(top) => top,
+        None => return Some(None),
+    };
+    let second_top = match top(atoms, target, source, ranks) {
+        Some(top) => top,
+        None => return Some(None),
+    };
+    let mut cis = first_kind == second_kind;
+
+    if Some(first) != first_top {
+        cis = !cis
+    }
+
+    if Some(second) != second_top {
+        cis = !cis
+    }
+
+    Some(Some(if cis { Descriptor::Z } else { Descriptor::E }))
+}
+
+/// Returns the ligand of source, other than target, of highest precedence:
+/// Some(None) for a hydrogen from Atom::hydrogens, or None on a tie.
+fn top(
+    atoms: &[Atom],
+    source: usize,
+    target: usize,
+    ranks: &[u8],
+) -> Option<Option<usize>> {
+    let mut digraph = Digraph::new(atoms, ranks);
+    let root = digraph.root(source);
+    let children = digraph
+        .children(root)
+        .ok()?
+        .into_iter()
+        .filter(|child| {
+            digraph.atom(*child) != Some(target)
+                && !digraph.is_duplicate_of(*child, target)
+        })
+        .collect::<Vec<_>>();
+
+    match children[..] {
+        [only] => Some(digraph.atom(only)),
+        [first, second] => {
+            match digraph.compare(first, second, Rule::Descriptor).ok()? {
+                Ordering::Greater => Some(digraph.atom(first)),
+                Ordering::Less => Some(digraph.atom(second)),
+                Ordering::Equal => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn labels(string: &str) -> Vec<CipLabel> {
+        cip_labels(&atoms_of(string))
+    }
+
+    fn center(center: usize, descriptor: Option<Descriptor>) -> CipLabel {
+        CipLabel::Tetrahedral { center, descriptor }
+    }
+
+    fn bond(
+        source: usize,
+        target: usize,
+        descriptor: Option<Descriptor>,
+    ) -> CipLabel {
+        CipLabel::DoubleBond {
+            source,
+            target,
+            descriptor,
+        }
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(labels("CC(N)C(=O)O"), vec![])
+    }
+
+    #[test]
+    fn l_alanine() {
+        assert_eq!(
+            labels("N[C@@H](C)C(=O)O"),
+            vec![center(1, Some(Descriptor::S))]
+        )
+    }
+
+    #[test]
+    fn d_alanine() {
+        assert_eq!(
+            labels("N[C@H](C)C(=O)O"),
+            vec![center(1, Some(Descriptor::R))]
+        )
+    }
+
+    #[test]
+    fn l_alanine_reordered() {
+        assert_eq!(
+            labels("C[C@H](N)C(=O)O"),
+            vec![center(1, Some(Descriptor::S))]
+        )
+    }
+
+    #[test]
+    fn r_bromochlorofluoromethane() {
+        assert_eq!(labels("F[C@H](Cl)Br"), vec![center(1, Some(Descriptor::R))])
+    }
+
+    #[test]
+    fn r_glyceraldehyde() {
+        assert_eq!(
+            labels("OC[C@@H](O)C=O"),
+            vec![center(2, Some(Descriptor::R))]
+        )
+    }
+
+    #[test]
+    fn l_cysteine() {
+        assert_eq!(
+            labels("N[C@@H](CS)C(=O)O"),
+            vec![center(1, Some(Descriptor::R))]
+        )
+    }
+
+    #[test]
+    fn deuterium() {
+        assert_eq!(
+            labels("[2H][C@@H](O)C"),
+            vec![center(1, Some(Descriptor::R))]
+        )
+    }
+
+    #[test]
+    fn ring() {
+        assert_eq!(
+            labels("C[C@@H]1CCCC(=O)C1"),
+            vec![center(1, Some(Descriptor::R))]
+        )
+    }
+
+    #[test]
+    fn sulfoxide() {
+        assert_eq!(
+            labels("C[S@](=O)c1ccccc1"),
+            vec![center(1, Some(Descriptor::S))]
+        )
+    }
+
+    #[test]
+    fn tie() {
+        assert_eq!(labels("[C@H](C)(C)O"), vec![center(0, None)])
+    }
+
+    #[test]
+    fn pseudoasymmetric() {
+        assert_eq!(
+            labels("OC(=O)[C@H](O)[C@H](O)[C@H](O)C(=O)O"),
+            vec![
+                center(3, Some(Descriptor::R)),
+                center(5, Some(Descriptor::PseudoR)),
+                center(7, Some(Descriptor::S))
+            ]
+        )
+    }
+
+    #[test]
+    fn not_pseudoasymmetric() {
+        assert_eq!(
+            labels("OC(=O)[C@H](O)[C@H](O)[C@@H](O)C(=O)O"),
+            vec![
+                center(3, Some(Descriptor::R)),
+                center(5, None),
+                center(7, Some(Descriptor::R))
+            ]
+        )
+    }
+
+    #[test]
+    fn e_butene() {
+        assert_eq!(labels("C/C=C/C"), vec![bond(1, 2, Some(Descriptor::E))])
+    }
+
+    #[test]
+    fn z_butene() {
+        assert_eq!(labels("C/C=C\\C"), vec![bond(1, 2, Some(Descriptor::Z))])
+    }
+
+    #[test]
+    fn lower_ranked_marker() {
+        assert_eq!(labels("C/C(Br)=C/C"), vec![bond(1, 3, Some(Descriptor::Z))])
+    }
+
+    #[test]
+    fn branch_marker() {
+        assert_eq!(labels("C(\\F)=C/F"), vec![bond(0, 2, Some(Descriptor::E))])
+    }
+
+    #[test]
+    fn bridge_marker() {
+        assert_eq!(
+            labels("C/1=C/CCCCCC1"),
+            vec![bond(0, 1, Some(Descriptor::Z))]
+        )
+    }
+
+    #[test]
+    fn one_side() {
+        assert_eq!(labels("C/C=CC"), vec![bond(1, 2, None)])
+    }
+
+    #[test]
+    fn identical_substituents() {
+        assert_eq!(labels("C/C=C(/C)C"), vec![bond(1, 2, None)])
+    }
+
+    #[test]
+    fn imine() {
+        assert_eq!(labels("C/C=N/O"), vec![bond(1, 2, Some(Descriptor::E))])
+    }
+}
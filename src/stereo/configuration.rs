@@ -0,0 +1,6 @@
+/// The placement of two neighbors across a double bond.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Configuration {
+    Cis,
+    Trans,
+}
@@ -0,0 +1,38 @@
+use std::fmt;
+
+/// A Cahn-Ingold-Prelog stereodescriptor.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Descriptor {
+    R,
+    S,
+    /// Pseudoasymmetric r, decided by rule 5 between enantiomorphic ligands.
+    PseudoR,
+    /// Pseudoasymmetric s, decided by rule 5 between enantiomorphic ligands.
+    PseudoS,
+    E,
+    Z,
+}
+
+impl fmt::Display for Descriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::R => "R",
+            Self::S => "S",
+            Self::PseudoR => "r",
+            Self::PseudoS => "s",
+            Self::E => "E",
+            Self::Z => "Z",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn pseudo() {
+        assert_eq!(Descriptor::PseudoR.to_string(), "r")
+    }
+}
@@ -0,0 +1,434 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use crate::{
+    feature::{AtomKind, Element},
+    graph::Atom,
+    search::BondClass,
+};
+
+/// Nodes allowed before a comparison gives up.
+const LIMIT: usize = 50_000;
+
+/// Returned when a comparison would grow the digraph past LIMIT.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Exhausted;
+
+/// Sequence rules, applied in order.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub(crate) enum Rule {
+    /// Rule 1a: higher atomic number first.
+    Number,
+    /// Rule 2: higher mass first.
+    Mass,
+    /// Rules 4a and 5: stereogenic before not, R before S.
+    Descriptor,
+}
+
+#[derive(Debug)]
+struct Node {
+    atom: Option<usize>,
+    parent: Option<usize>,
+    duplicate: bool,
+    /// Six times the atomic number, so averages over two or three aromatic
+    /// neighbors stay whole.
+    number: u32,
+    mass: u32,
+}
+
+/// A hierarchical digraph grown lazily from root atoms. Multiple bonds and
+/// bridges back to an ancestor add duplicate atoms, which have no branches
+/// of their own. Aromatic atoms with a free valence get one duplicate with
+/// the average atomic number of their aromatic neighbors. Hydrogens come
+/// from Atom::hydrogens.
+pub(crate) struct Digraph<'a> {
+    atoms: &'a [Atom],
+    ranks: &'a [u8],
+    nodes: Vec<Node>,
+    children: HashMap<usize, Vec<usize>>,
+    sorted: HashMap<(usize, Rule), Vec<usize>>,
+}
+
+impl<'a> Digraph<'a> {
+    /// ranks holds a rule 5 rank for each atom: 2 for R, 1 for S, else 0.
+    pub fn new(atoms: &'a [Atom], ranks: &'a [u8]) -> Self {
+        Self {
+            atoms,
+            ranks,
+            nodes: Vec::new(),
+            children: HashMap::new(),
+            sorted: HashMap::new(),
+        }
+    }
+
+    /// Adds a root at the atom at index and returns its node.
+    pub fn root(&mut self, index: usize) -> usize {
+        self.add(Some(index), None, false)
+    }
+
+    /// Returns the atom of node, or None for hydrogens from
+    /// Atom::hydrogens and duplicates.
+    pub fn atom(&self, node: usize) -> Option<usize> {
+        let node = &self.nodes[node];
+
+        if node.duplicate {
+            None
+        } else {
+            node.atom
+        }
+    }
+
+    /// Returns true if node is a duplicate of the atom at index.
+    pub fn is_duplicate_of(&self, node: usize, index: usize) -> bool {
+        let node = &self.nodes[node];
+
+        node.duplicate && node.atom == Some(index)
+    }
+
+    /// Returns true if node is a hydrogen from Atom::hydrogens.
+    pub fn is_hydrogen(&self, node: usize) -> bool {
+        let node = &self.nodes[node];
+
+        node.atom.is_none() && node.number == 6
+    }
+
+    pub fn children(&mut self, id: usize) -> Result<Vec<usize>, Exhausted> {
+        if let Some(children) = self.children.get(&id) {
+            return Ok(children.clone());
+        }
+
+        if self.nodes.len() > LIMIT {
+            return Err(Exhausted);
+        }
+
+        let mut children = Vec::new();
+        let node = &self.nodes[id];
+        let index = match (node.atom, node.duplicate) {
+            (Some(index), false) => index,
+            _ => {
+                self.children.insert(id, children.clone());
+
+                return Ok(children);
+            }
+        };
+        let parent = node.parent.and_then(|parent| self.nodes[parent].atom);
+        let atoms = self.atoms;
+        let atom = &atoms[index];
+        let mut skipped = false;
+        let mut aromatic = Vec::new();
+
+        for bond in &atom.bonds {
+            let extra = match BondClass::new(atoms, index, bond) {
+                BondClass::Double => 1,
+                BondClass::Triple => 2,
+                BondClass::Aromatic => {
+                    aromatic.push(bond.tid);
+
+                    0
+                }
+                BondClass::Single => 0,
+            };
+
+            if parent == Some(bond.tid) && !skipped {
+                skipped = true
+            } else if self.is_ancestor(id, bond.tid) {
+                children.push(self.add(Some(bond.tid), Some(id), true))
+            } else {
+                children.push(self.add(Some(bond.tid), Some(id), false))
+            }
+
+            for _ in 0..extra {
+                children.push(self.add(Some(bond.tid), Some(id), true))
+            }
+        }
+
+        if aromatic.len() > 1 && atom.subvalence() > 0 {
+            let sum = aromatic
+                .iter()
+                .map(|tid| number(&atoms[*tid].kind))
+                .sum::<u32>();
+
+            children.push(self.nodes.len());
+            self.nodes.push(Node {
+                atom: None,
+                parent: Some(id),
+                duplicate: true,
+                number: sum / aromatic.len() as u32,
+                mass: 0,
+            })
+        }
+
+        for _ in 0..atom.hydrogens() {
+            children.push(self.nodes.len());
+            self.nodes.push(Node {
+                atom: None,
+                parent: Some(id),
+                duplicate: false,
+                number: 6,
+                mass: mass(Element::H, None),
+            })
+        }
+
+        self.children.insert(id, children.clone());
+
+        Ok(children)
+    }
+
+    /// Compares the branches at first and second under rules up to and
+    /// including last. Greater means first takes precedence.
+    pub fn compare(
+        &mut self,
+        first: usize,
+        second: usize,
+        last: Rule,
+    ) -> Result<Ordering, Exhausted> {
+        for rule in [Rule::Number, Rule::Mass, Rule::Descriptor] {
+            if rule > last {
+                break;
+            }
+
+            let ordering = self.compare_by(first, second, rule)?;
+
+            if ordering != Ordering::Equal {
+                return Ok(ordering);
+            }
+        }
+
+        Ok(Ordering::Equal)
+    }
+
+    /// Explores both branches sphere by sphere, comparing at each sphere the
+    /// sets of branches under each pair of nodes in order of precedence.
+    fn compare_by(
+        &mut self,
+        first: usize,
+        second: usize,
+        rule: Rule,
+    ) -> Result<Ordering, Exhausted> {
+        let ordering = self.key(first, rule).cmp(&self.key(second, rule));
+
+        if ordering != Ordering::Equal {
+            return Ok(ordering);
+        }
+
+        let mut firsts = vec![first];
+        let mut seconds = vec![second];
+
+        while !firsts.is_empty() || !seconds.is_empty() {
+            let mut next_firsts = Vec::new();
+            let mut next_seconds = Vec::new();
+
+            for (first, second) in firsts.iter().zip(seconds.iter()) {
+                let first = self.sorted(*first, rule)?;
+                let second = self.sorted(*second, rule)?;
+                let ordering = self.compare_sets(&first, &second, rule);
+
+                if ordering != Ordering::Equal {
+                    return Ok(ordering);
+                }
+
+                next_firsts.extend(first);
+                next_seconds.extend(second);
+            }
+
+            firsts = next_firsts;
+            seconds = next_seconds;
+        }
+
+        Ok(Ordering::Equal)
+    }
+
+    fn compare_sets(
+        &self,
+        first: &[usize],
+        second: &[usize],
+        rule: Rule,
+    ) -> Ordering {
+        let mut first = first
+            .iter()
+            .map(|node| self.key(*node, rule))
+            .collect::<Vec<_>>();
+        let mut second = second
+            .iter()
+            .map(|node| self.key(*node, rule))
+            .collect::<Vec<_>>();
+
+        first.sort_unstable_by(|a, b| b.cmp(a));
+        second.sort_unstable_by(|a, b| b.cmp(a));
+
+        for i in 0..first.len().max(second.len()) {
+            let ordering =
+                first.get(i).unwrap_or(&0).cmp(second.get(i).unwrap_or(&0));
+
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+
+        Ordering::Equal
+    }
+
+    /// Returns the children of id in order of precedence under rules up to
+    /// and including rule.
+    fn sorted(
+        &mut self,
+        id: usize,
+        rule: Rule,
+    ) -> Result<Vec<usize>, Exhausted> {
+        if let Some(sorted) = self.sorted.get(&(id, rule)) {
+            return Ok(sorted.clone());
+        }
+
+        let children = self.children(id)?;
+        let mut sorted: Vec<usize> = Vec::with_capacity(children.len());
+
+        for child in children {
+            let mut position = sorted.len();
+
+            for (i, other) in sorted.iter().enumerate() {
+                if self.compare(child, *other, rule)? == Ordering::Greater {
+                    position = i;
+
+                    break;
+                }
+            }
+
+            sorted.insert(position, child)
+        }
+
+        self.sorted.insert((id, rule), sorted.clone());
+
+        Ok(sorted)
+    }
+
+    fn key(&self, id: usize, rule: Rule) -> u32 {
+        let node = &self.nodes[id];
+
+        match rule {
+            Rule::Number => node.number,
+            Rule::Mass => node.mass,
+            Rule::Descriptor => match (node.atom, node.duplicate) {
+                (Some(index), false) => self.ranks[index] as u32,
+                _ => 0,
+            },
+        }
+    }
+
+    fn is_ancestor(&self, id: usize, index: usize) -> bool {
+        let mut current = Some(id);
+
+        while let Some(id) = current {
+            let node = &self.nodes[id];
+
+            if !node.duplicate && node.atom == Some(index) {
+                return true;
+            }
+
+            current = node.parent
+        }
+
+        false
+    }
+
+    fn add(
+        &mut self,
+        atom: Option<usize>,
+        parent: Option<usize>,
+        duplicate: bool,
+    ) -> usize {
+        let (number, mass) = match atom {
+            Some(index) => {
+                let kind = &self.atoms[index].kind;
+                let isotope = match kind {
+                    AtomKind::Bracket(bracket) => {
+                        bracket.isotope.as_ref().map(u16::from)
+                    }
+                    _ => None,
+                };
+
+                match kind.element() {
+                    Some(element) => (number(kind), mass(element, isotope)),
+                    None => (0, 0),
+                }
+            }
+            None => (0, 0),
+        };
+
+        self.nodes.push(Node {
+            atom,
+            parent,
+            duplicate,
+            number,
+            mass,
+        });
+
+        self.nodes.len() - 1
+    }
+}
+
+fn number(kind: &AtomKind) -> u32 {
+    match kind.element() {
+        Some(element) => element.atomic_number() as u32 * 6,
+        None => 0,
+    }
+}
+
+/// Mass in millidaltons: the mass number of a labelled atom, otherwise the
+/// atomic weight.
+fn mass(element: Element, isotope: Option<u16>) -> u32 {
+    match isotope {
+        Some(isotope) => isotope as u32 * 1000,
+        None => (element.atomic_weight() * 1000.).round() as u32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn compare(string: &str, root: usize, last: Rule) -> Ordering {
+        let atoms = atoms_of(string);
+        let ranks = vec![0; atoms.len()];
+        let mut digraph = Digraph::new(&atoms, &ranks);
+        let root = digraph.root(root);
+        let children = digraph.children(root).unwrap();
+
+        digraph.compare(children[0], children[1], last).unwrap()
+    }
+
+    #[test]
+    fn atomic_number() {
+        assert_eq!(compare("OCN", 1, Rule::Number), Ordering::Greater)
+    }
+
+    #[test]
+    fn second_sphere() {
+        assert_eq!(compare("CCC(C(C)C)O", 2, Rule::Number), Ordering::Less)
+    }
+
+    #[test]
+    fn duplicate_for_double_bond() {
+        assert_eq!(compare("C(C=O)C(C)O", 0, Rule::Number), Ordering::Greater)
+    }
+
+    #[test]
+    fn mass_undecided_by_number() {
+        assert_eq!(compare("[2H]C[H]", 1, Rule::Number), Ordering::Equal)
+    }
+
+    #[test]
+    fn mass() {
+        assert_eq!(compare("[2H]C[H]", 1, Rule::Mass), Ordering::Greater)
+    }
+
+    #[test]
+    fn ring() {
+        assert_eq!(compare("CC1CCCC1", 1, Rule::Number), Ordering::Less)
+    }
+
+    #[test]
+    fn identical() {
+        assert_eq!(compare("CC(C)O", 1, Rule::Descriptor), Ordering::Equal)
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{feature::BondKind, graph::Atom};
+
+/// Returns Up or Down for the bond at index on the atom at sid, as if it
+/// were written with sid first, or None if the bond isn't directional or its
+/// markers contradict each other.
+///
+/// graph::Builder stores a bond under the same kind on both atoms, read from
+/// the lower index to the higher, when it is the first bond of the higher
+/// atom. That covers chain bonds, and ring closures made by root atoms,
+/// which Builder stores alike. Any other bridge keeps on each atom the kind
+/// written next to its own bridge digit, so both ends may carry a marker.
+pub(crate) fn direction(
+    atoms: &[Atom],
+    sid: usize,
+    index: usize,
+) -> Option<BondKind> {
+    let bond = &atoms[sid].bonds[index];
+    let tid = bond.tid;
+    let (low, high) = if sid < tid { (sid, tid) } else { (tid, sid) };
+    let chain = atoms[high]
+        .bonds
+        .first()
+        .is_some_and(|bond| bond.tid == low);
+
+    if chain {
+        return match (&bond.kind, sid < tid) {
+            (BondKind::Up | BondKind::Down, true) => Some(bond.kind.clone()),
+            (BondKind::Up | BondKind::Down, false) => Some(bond.kind.reverse()),
+            _ => None,
+        };
+    }
+
+    let forward = match bond.kind {
+        BondKind::Up | BondKind::Down => Some(bond.kind.clone()),
+        _ => None,
+    };
+    let backward = atoms[tid]
+        .bonds
+        .iter()
+        .find(|bond| bond.tid == sid)
+        .and_then(|bond| match bond.kind {
+            BondKind::Up | BondKind::Down => Some(bond.kind.reverse()),
+            _ => None,
+        });
+
+    match (forward, backward) {
+        (Some(forward), Some(backward)) if forward != backward => None,
+        (Some(kind), _) | (None, Some(kind)) => Some(kind),
+        (None, None) => None,
+    }
+}
+
+/// Sets the bond from sid to tid to kind, Up or Down, as if written with sid
+/// first. Both ends are updated to match, following the storage of
+/// graph::Builder described in direction.
+pub(crate) fn set_direction(
+    atoms: &mut [Atom],
+    sid: usize,
+    tid: usize,
+    kind: &BondKind,
+) {
+    let (low, high) = if sid < tid { (sid, tid) } else { (tid, sid) };
+    let chain = atoms[high]
+        .bonds
+        .first()
+        .is_some_and(|bond| bond.tid == low);
+    let (forward, backward) = match (chain, sid < tid) {
+        (true, true) => (kind.clone(), kind.clone()),
+        (true, false) => (kind.reverse(), kind.reverse()),
+        (false, _) => (kind.clone(), kind.reverse()),
+    };
+
+    for (from, to, kind) in [(sid, tid, forward), (tid, sid, backward)] {
+        if let Some(bond) =
+            atoms[from].bonds.iter_mut().find(|bond| bond.tid == to)
+        {
+            bond.kind = kind
+        }
+    }
+}
+
+/// Removes Up and Down from the bonds of end other than the one to other,
+/// on both of their ends, and returns the neighbors whose bonds were cleared.
+pub(crate) fn clear_directions(
+    atoms: &mut [Atom],
+    end: usize,
+    other: usize,
+) -> Vec<usize> {
+    let mut result = Vec::new();
+
+    for index in 0..atoms[end].bonds.len() {
+        let tid = atoms[end].bonds[index].tid;
+
+        if tid == other || !is_directional(atoms, end, index) {
+            continue;
+        }
+
+        atoms[end].bonds[index].kind = BondKind::Elided;
+
+        for bond in atoms[tid].bonds.iter_mut() {
+            if bond.tid == end {
+                bond.kind = BondKind::Elided
+            }
+        }
+
+        result.push(tid)
+    }
+
+    result
+}
+
+/// Returns true if the bond at index on the atom at sid carries Up or Down
+/// on either of its ends.
+pub(crate) fn is_directional(atoms: &[Atom], sid: usize, index: usize) -> bool {
+    let bond = &atoms[sid].bonds[index];
+    let directional =
+        |kind: &BondKind| matches!(kind, BondKind::Up | BondKind::Down);
+
+    directional(&bond.kind)
+        || atoms[bond.tid]
+            .bonds
+            .iter()
+            .any(|other| other.tid == sid && directional(&other.kind))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn direction_of(
+        string: &str,
+        sid: usize,
+        index: usize,
+    ) -> Option<BondKind> {
+        direction(&atoms_of(string), sid, index)
+    }
+
+    #[test]
+    fn directional() {
+        let atoms = atoms_of("C/1=C/CCCCCC1");
+
+        assert_eq!(
+            (0..atoms[7].bonds.len())
+                .map(|index| is_directional(&atoms, 7, index))
+                .collect::<Vec<_>>(),
+            vec![false, true]
+        )
+    }
+
+    #[test]
+    fn set_chain() {
+        let mut atoms = atoms_of("FC=CF");
+
+        set_direction(&mut atoms, 1, 0, &BondKind::Up);
+
+        assert_eq!(direction(&atoms, 0, 0), Some(BondKind::Down))
+    }
+
+    #[test]
+    fn set_bridge() {
+        let mut atoms = atoms_of("C1=CCCCCCC1");
+
+        set_direction(&mut atoms, 7, 0, &BondKind::Up);
+
+        assert_eq!(
+            (direction(&atoms, 0, 0), atoms[0].bonds[0].kind.clone()),
+            (Some(BondKind::Down), BondKind::Down)
+        )
+    }
+
+    #[test]
+    fn elided() {
+        assert_eq!(direction_of("CC=CC", 0, 0), None)
+    }
+
+    #[test]
+    fn chain_forward() {
+        assert_eq!(direction_of("F/C=C/F", 0, 0), Some(BondKind::Up))
+    }
+
+    #[test]
+    fn chain_backward() {
+        assert_eq!(direction_of("F/C=C/F", 1, 0), Some(BondKind::Down))
+    }
+
+    #[test]
+    fn branch() {
+        assert_eq!(direction_of("C(\\F)=C/F", 0, 0), Some(BondKind::Down))
+    }
+
+    #[test]
+    fn bridge_opening() {
+        assert_eq!(direction_of("C/1=C/CCCCCC1", 0, 0), Some(BondKind::Up))
+    }
+
+    #[test]
+    fn bridge_closing() {
+        assert_eq!(direction_of("C1=C/CCCCCC/1", 7, 1), Some(BondKind::Up))
+    }
+
+    #[test]
+    fn bridge_from_other_end() {
+        assert_eq!(direction_of("C/1=C/CCCCCC1", 7, 1), Some(BondKind::Down))
+    }
+
+    #[test]
+    fn root_closing_bridge() {
+        assert_eq!(direction_of("F/C=C/1.C1", 3, 0), Some(BondKind::Down))
+    }
+
+    #[test]
+    fn root_closing_bridge_with_marker() {
+        assert_eq!(direction_of("F/C=C1.C/1", 3, 0), Some(BondKind::Up))
+    }
+
+    #[test]
+    fn bridge_conflict() {
+        assert_eq!(direction_of("C/1=C/CCCCCC/1", 0, 0), None)
+    }
+
+    #[test]
+    fn clear() {
+        let mut atoms = atoms_of("F/C(/Cl)=C/F");
+
+        assert_eq!(clear_directions(&mut atoms, 1, 3), vec![0, 2]);
+        assert_eq!(atoms, atoms_of("FC(Cl)=C/F"))
+    }
+}
@@ -0,0 +1,10 @@
+/// A problem with Up and Down markers.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum DirectionIssue {
+    /// The markers around the double bond between source and target
+    /// contradict each other.
+    Conflict { source: usize, target: usize },
+    /// The directional bond between source and target flanks no double
+    /// bond.
+    Meaningless { source: usize, target: usize },
+}
@@ -0,0 +1,119 @@
+use super::{is_directional, DirectionIssue, Side};
+use crate::{feature::BondKind, graph::Atom};
+
+/// Returns the problems with Up and Down markers: double bonds whose
+/// markers conflict, then directional bonds that flank no double bond, each
+/// in order of source.
+pub fn direction_issues(atoms: &[Atom]) -> Vec<DirectionIssue> {
+    let mut result = Vec::new();
+
+    for (source, atom) in atoms.iter().enumerate() {
+        for bond in &atom.bonds {
+            if bond.kind != BondKind::Double || bond.tid < source {
+                continue;
+            }
+
+            if Side::new(atoms, source, bond.tid) == Side::Conflict
+                || Side::new(atoms, bond.tid, source) == Side::Conflict
+            {
+                result.push(DirectionIssue::Conflict {
+                    source,
+                    target: bond.tid,
+                })
+            }
+        }
+    }
+
+    for (source, atom) in atoms.iter().enumerate() {
+        for (index, bond) in atom.bonds.iter().enumerate() {
+            if bond.tid < source || !is_directional(atoms, source, index) {
+                continue;
+            }
+
+            if !flanks(atoms, source, bond.tid)
+                && !flanks(atoms, bond.tid, source)
+            {
+                result.push(DirectionIssue::Meaningless {
+                    source,
+                    target: bond.tid,
+                })
+            }
+        }
+    }
+
+    result
+}
+
+/// Returns true if the atom at index has a double bond to an atom other
+/// than other.
+fn flanks(atoms: &[Atom], index: usize, other: usize) -> bool {
+    atoms[index]
+        .bonds
+        .iter()
+        .any(|bond| bond.kind == BondKind::Double && bond.tid != other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn issues(string: &str) -> Vec<DirectionIssue> {
+        direction_issues(&atoms_of(string))
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(issues("F/C=C/F"), vec![])
+    }
+
+    #[test]
+    fn conflict() {
+        assert_eq!(
+            issues("C/C(\\F)=C/C"),
+            vec![DirectionIssue::Conflict {
+                source: 1,
+                target: 3
+            }]
+        )
+    }
+
+    #[test]
+    fn bridge_conflict() {
+        assert_eq!(
+            issues("C/1=C/CCCCCC/1"),
+            vec![DirectionIssue::Conflict {
+                source: 0,
+                target: 1
+            }]
+        )
+    }
+
+    #[test]
+    fn meaningless() {
+        assert_eq!(
+            issues("C/CC"),
+            vec![DirectionIssue::Meaningless {
+                source: 0,
+                target: 1
+            }]
+        )
+    }
+
+    #[test]
+    fn meaningless_bridge() {
+        assert_eq!(
+            issues("C/1CCCC1"),
+            vec![DirectionIssue::Meaningless {
+                source: 0,
+                target: 4
+            }]
+        )
+    }
+
+    #[test]
+    fn beside_double_bond() {
+        assert_eq!(issues("C=C/C"), vec![])
+    }
+}
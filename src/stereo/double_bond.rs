@@ -0,0 +1,94 @@
+use super::Configuration;
+
+/// The geometry of the double bond between source and target, given as the
+/// configuration of first, a neighbor of source, and second, a neighbor of
+/// target.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct DoubleBond {
+    pub source: usize,
+    pub target: usize,
+    pub first: usize,
+    pub second: usize,
+    pub configuration: Configuration,
+}
+
+impl DoubleBond {
+    /// Returns the configuration of neighbor, bonded to end, and other,
+    /// bonded to the far end, or None if end is neither source nor target.
+    pub fn configuration_between(
+        &self,
+        end: usize,
+        neighbor: usize,
+        other: usize,
+    ) -> Option<Configuration> {
+        let (near, far) = if end == self.source {
+            (self.first, self.second)
+        } else if end == self.target {
+            (self.second, self.first)
+        } else {
+            return None;
+        };
+
+        if (neighbor == near) == (other == far) {
+            Some(self.configuration)
+        } else {
+            Some(match self.configuration {
+                Configuration::Cis => Configuration::Trans,
+                Configuration::Trans => Configuration::Cis,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn butene() -> DoubleBond {
+        DoubleBond {
+            source: 1,
+            target: 2,
+            first: 0,
+            second: 3,
+            configuration: Configuration::Trans,
+        }
+    }
+
+    #[test]
+    fn same_neighbors() {
+        assert_eq!(
+            butene().configuration_between(1, 0, 3),
+            Some(Configuration::Trans)
+        )
+    }
+
+    #[test]
+    fn from_target() {
+        assert_eq!(
+            butene().configuration_between(2, 3, 0),
+            Some(Configuration::Trans)
+        )
+    }
+
+    #[test]
+    fn other_neighbor() {
+        assert_eq!(
+            butene().configuration_between(1, 4, 3),
+            Some(Configuration::Cis)
+        )
+    }
+
+    #[test]
+    fn both_other_neighbors() {
+        assert_eq!(
+            butene().configuration_between(2, 5, 4),
+            Some(Configuration::Trans)
+        )
+    }
+
+    #[test]
+    fn not_an_end() {
+        assert_eq!(butene().configuration_between(0, 1, 2), None)
+    }
+}
@@ -0,0 +1,140 @@
+use super::{Configuration, DoubleBond, Side};
+use crate::{feature::BondKind, graph::Atom};
+
+/// Returns the geometry of each double bond with consistent Up or Down
+/// markers at both ends, including markers written on bridges. Double bonds
+/// marked at one end only or with conflicting markers are left out; see
+/// direction_issues.
+pub fn double_bonds(atoms: &[Atom]) -> Vec<DoubleBond> {
+    let mut result = Vec::new();
+
+    for (source, atom) in atoms.iter().enumerate() {
+        for bond in &atom.bonds {
+            if bond.kind != BondKind::Double || bond.tid < source {
+                continue;
+            }
+
+            let first = Side::new(atoms, source, bond.tid);
+            let second = Side::new(atoms, bond.tid, source);
+
+            if let (
+                Side::Marked(first, first_kind),
+                Side::Marked(second, second_kind),
+            ) = (first, second)
+            {
+                result.push(DoubleBond {
+                    source,
+                    target: bond.tid,
+                    first,
+                    second,
+                    configuration: if first_kind == second_kind {
+                        Configuration::Cis
+                    } else {
+                        Configuration::Trans
+                    },
+                })
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn bonds(string: &str) -> Vec<DoubleBond> {
+        double_bonds(&atoms_of(string))
+    }
+
+    fn bond(
+        (source, target): (usize, usize),
+        (first, second): (usize, usize),
+        configuration: Configuration,
+    ) -> DoubleBond {
+        DoubleBond {
+            source,
+            target,
+            first,
+            second,
+            configuration,
+        }
+    }
+
+    #[test]
+    fn unmarked() {
+        assert_eq!(bonds("CC=CC"), vec![])
+    }
+
+    #[test]
+    fn trans() {
+        assert_eq!(
+            bonds("F/C=C/F"),
+            vec![bond((1, 2), (0, 3), Configuration::Trans)]
+        )
+    }
+
+    #[test]
+    fn cis() {
+        assert_eq!(
+            bonds("F/C=C\\F"),
+            vec![bond((1, 2), (0, 3), Configuration::Cis)]
+        )
+    }
+
+    #[test]
+    fn branch() {
+        assert_eq!(
+            bonds("C(\\F)=C/F"),
+            vec![bond((0, 2), (1, 3), Configuration::Trans)]
+        )
+    }
+
+    #[test]
+    fn two_markers() {
+        assert_eq!(
+            bonds("C/C(/F)=C/C"),
+            vec![bond((1, 3), (0, 4), Configuration::Trans)]
+        )
+    }
+
+    #[test]
+    fn bridge() {
+        assert_eq!(
+            bonds("C/1=C/CCCCCC1"),
+            vec![bond((0, 1), (7, 2), Configuration::Cis)]
+        )
+    }
+
+    #[test]
+    fn bridge_closing() {
+        assert_eq!(
+            bonds("C1CCC/C=C/CC1"),
+            vec![bond((4, 5), (3, 6), Configuration::Trans)]
+        )
+    }
+
+    #[test]
+    fn conjugated() {
+        assert_eq!(
+            bonds("C/C=C/C=C/C"),
+            vec![
+                bond((1, 2), (0, 3), Configuration::Trans),
+                bond((3, 4), (2, 5), Configuration::Trans)
+            ]
+        )
+    }
+
+    #[test]
+    fn one_end() {
+        assert_eq!(bonds("C/C=CC"), vec![])
+    }
+
+    #[test]
+    fn conflict() {
+        assert_eq!(bonds("C/C(\\F)=C/C"), vec![])
+    }
+}
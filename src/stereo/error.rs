@@ -0,0 +1,8 @@
+#[derive(Debug, PartialEq, Clone)]
+pub enum Error {
+    /// The center at an index isn't a bracket atom, so it can't hold parity.
+    NotBracket(usize),
+    /// The neighbors of the center at an index no longer match the ligands
+    /// of the record.
+    Ligands(usize),
+}
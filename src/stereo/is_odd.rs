@@ -0,0 +1,77 @@
+use super::Ligand;
+
+/// Returns true if reordering from into to takes an odd number of swaps, or
+/// None if to isn't a reordering of from.
+pub(crate) fn is_odd(from: &[Ligand], to: &[Ligand]) -> Option<bool> {
+    if from.len() != to.len() {
+        return None;
+    }
+
+    let mut used = vec![false; to.len()];
+    let mut positions = Vec::with_capacity(from.len());
+
+    for ligand in from {
+        let position = (0..to.len()).find(|&i| !used[i] && to[i] == *ligand)?;
+
+        used[position] = true;
+        positions.push(position)
+    }
+
+    let mut inversions = 0;
+
+    for i in 0..positions.len() {
+        for j in i + 1..positions.len() {
+            if positions[i] > positions[j] {
+                inversions += 1
+            }
+        }
+    }
+
+    Some(inversions % 2 == 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn identity() {
+        let ligands = [Ligand::Atom(0), Ligand::Implicit, Ligand::Atom(2)];
+
+        assert_eq!(is_odd(&ligands, &ligands), Some(false))
+    }
+
+    #[test]
+    fn swap() {
+        assert_eq!(
+            is_odd(
+                &[Ligand::Atom(0), Ligand::Implicit, Ligand::Atom(2)],
+                &[Ligand::Implicit, Ligand::Atom(0), Ligand::Atom(2)]
+            ),
+            Some(true)
+        )
+    }
+
+    #[test]
+    fn rotation() {
+        assert_eq!(
+            is_odd(
+                &[Ligand::Atom(0), Ligand::Atom(1), Ligand::Atom(2)],
+                &[Ligand::Atom(1), Ligand::Atom(2), Ligand::Atom(0)]
+            ),
+            Some(false)
+        )
+    }
+
+    #[test]
+    fn mismatch() {
+        assert_eq!(
+            is_odd(
+                &[Ligand::Atom(0), Ligand::Atom(1)],
+                &[Ligand::Atom(0), Ligand::Atom(2)]
+            ),
+            None
+        )
+    }
+}
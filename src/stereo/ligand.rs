@@ -0,0 +1,9 @@
+/// A position around a stereocenter.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Ligand {
+    /// The atom at an index.
+    Atom(usize),
+    /// A virtual hydrogen or the implicit ligand of a three-coordinate
+    /// center, such as a lone pair.
+    Implicit,
+}
@@ -0,0 +1,128 @@
+use super::Ligand;
+use crate::graph::Atom;
+
+/// Returns the neighbors of the atom at index in the order its parity refers
+/// to. This is the parity convention of graph::Atom: ligands follow bond
+/// order, and Ligand::Implicit follows the first bond if that bond leads to
+/// a lower index, and otherwise comes first.
+///
+/// graph::Builder meets the convention by placing the bond to the preceding
+/// atom first and bridge bonds where their bridge appeared. A root atom has
+/// no preceding atom, so Builder inverts its parity when a ring closure to a
+/// lower index comes first. Atoms whose bonds were reordered may not meet
+/// it; see Tetrahedral::apply.
+pub fn ligands(atoms: &[Atom], index: usize) -> Vec<Ligand> {
+    let atom = &atoms[index];
+    let mut result = atom
+        .bonds
+        .iter()
+        .map(|bond| Ligand::Atom(bond.tid))
+        .collect::<Vec<_>>();
+    let implicit = match atom.kind.virtual_hydrogens() {
+        0 => usize::from(result.len() == 3),
+        hydrogens => hydrogens as usize,
+    };
+    let position = match atom.bonds.first() {
+        Some(bond) if bond.tid < index => 1,
+        _ => 0,
+    };
+
+    for _ in 0..implicit {
+        result.insert(position, Ligand::Implicit)
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read::atoms_of, search::is_isomorphic};
+    use pretty_assertions::assert_eq;
+
+    fn ligands_of(string: &str, index: usize) -> Vec<Ligand> {
+        ligands(&atoms_of(string), index)
+    }
+
+    #[test]
+    fn four_neighbors() {
+        assert_eq!(
+            ligands_of("N[C@](C)(O)F", 1),
+            vec![
+                Ligand::Atom(0),
+                Ligand::Atom(2),
+                Ligand::Atom(3),
+                Ligand::Atom(4)
+            ]
+        )
+    }
+
+    #[test]
+    fn virtual_hydrogen_after_preceding() {
+        assert_eq!(
+            ligands_of("N[C@H](C)O", 1),
+            vec![
+                Ligand::Atom(0),
+                Ligand::Implicit,
+                Ligand::Atom(2),
+                Ligand::Atom(3)
+            ]
+        )
+    }
+
+    #[test]
+    fn virtual_hydrogen_on_root() {
+        assert_eq!(
+            ligands_of("[C@H](N)(C)O", 0),
+            vec![
+                Ligand::Implicit,
+                Ligand::Atom(1),
+                Ligand::Atom(2),
+                Ligand::Atom(3)
+            ]
+        )
+    }
+
+    #[test]
+    fn implicit_ligand() {
+        assert_eq!(
+            ligands_of("C[S@](=O)CC", 1),
+            vec![
+                Ligand::Atom(0),
+                Ligand::Implicit,
+                Ligand::Atom(2),
+                Ligand::Atom(3)
+            ]
+        )
+    }
+
+    #[test]
+    fn bridge() {
+        assert_eq!(
+            ligands_of("N[C@]1(C)CO1", 1),
+            vec![
+                Ligand::Atom(0),
+                Ligand::Atom(4),
+                Ligand::Atom(2),
+                Ligand::Atom(3)
+            ]
+        )
+    }
+
+    #[test]
+    fn root_closing_bridge_to_lower_index() {
+        let atoms = atoms_of("C1.[C@H]1(F)Cl");
+
+        assert_eq!(
+            ligands(&atoms, 1),
+            vec![
+                Ligand::Atom(0),
+                Ligand::Implicit,
+                Ligand::Atom(2),
+                Ligand::Atom(3)
+            ]
+        );
+        assert!(is_isomorphic(&atoms, &atoms_of("[C@H](C)(F)Cl"), true));
+        assert!(!is_isomorphic(&atoms, &atoms_of("[C@@H](C)(F)Cl"), true))
+    }
+}
@@ -0,0 +1,50 @@
+mod bridgeheads;
+mod cip_label;
+mod cip_labels;
+mod configuration;
+mod descriptor;
+mod digraph;
+mod direction;
+mod direction_issue;
+mod direction_issues;
+mod double_bond;
+mod double_bonds;
+mod error;
+mod is_odd;
+mod ligand;
+mod ligands;
+mod side;
+mod stereo_unit;
+mod stereo_units;
+mod stereoisomer_options;
+mod stereoisomers;
+mod strip_invalid_parity;
+mod tetrahedral;
+mod tetrahedral_centers;
+
+pub use cip_label::CipLabel;
+pub use cip_labels::cip_labels;
+pub use configuration::Configuration;
+pub use descriptor::Descriptor;
+pub use direction_issue::DirectionIssue;
+pub use direction_issues::direction_issues;
+pub use double_bond::DoubleBond;
+pub use double_bonds::double_bonds;
+pub use error::Error;
+pub use ligand::Ligand;
+pub use ligands::ligands;
+pub use stereo_unit::StereoUnit;
+pub use stereo_units::stereo_units;
+pub use stereoisomer_options::StereoisomerOptions;
+pub use stereoisomers::stereoisomers;
+pub use strip_invalid_parity::strip_invalid_parity;
+pub use tetrahedral::Tetrahedral;
+pub use tetrahedral_centers::tetrahedral_centers;
+
+pub(crate) use bridgeheads::bridgeheads;
+pub(crate) use direction::{
+    clear_directions, direction, is_directional, set_direction,
+};
+pub(crate) use is_odd::is_odd;
+pub(crate) use side::Side;
+pub(crate) use stereo_units::potential_centers;
@@ -0,0 +1,76 @@
+use super::{direction, is_directional};
+use crate::{feature::BondKind, graph::Atom};
+
+/// The markers at one end of a double bond.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum Side {
+    Unmarked,
+    /// A neighbor and the direction of its bond as written from the end.
+    Marked(usize, BondKind),
+    /// Markers that contradict each other, such as two neighbors pointing
+    /// the same way.
+    Conflict,
+}
+
+impl Side {
+    /// Reads the markers of source, the end of a double bond to target.
+    pub fn new(atoms: &[Atom], source: usize, target: usize) -> Self {
+        let mut marked = Vec::new();
+
+        for (index, bond) in atoms[source].bonds.iter().enumerate() {
+            if bond.tid == target || !is_directional(atoms, source, index) {
+                continue;
+            }
+
+            match direction(atoms, source, index) {
+                Some(kind) => marked.push((bond.tid, kind)),
+                None => return Self::Conflict,
+            }
+        }
+
+        match &marked[..] {
+            [] => Self::Unmarked,
+            [(tid, kind)] => Self::Marked(*tid, kind.clone()),
+            [(tid, kind), (_, other)] if kind != other => {
+                Self::Marked(*tid, kind.clone())
+            }
+            _ => Self::Conflict,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn side(string: &str, source: usize, target: usize) -> Side {
+        Side::new(&atoms_of(string), source, target)
+    }
+
+    #[test]
+    fn unmarked() {
+        assert_eq!(side("CC=CC", 1, 2), Side::Unmarked)
+    }
+
+    #[test]
+    fn marked() {
+        assert_eq!(side("C/C=C/C", 1, 2), Side::Marked(0, BondKind::Down))
+    }
+
+    #[test]
+    fn two_markers() {
+        assert_eq!(side("C/C(/F)=C/C", 1, 3), Side::Marked(0, BondKind::Down))
+    }
+
+    #[test]
+    fn two_markers_conflict() {
+        assert_eq!(side("C/C(\\F)=C/C", 1, 3), Side::Conflict)
+    }
+
+    #[test]
+    fn bridge_conflict() {
+        assert_eq!(side("C/1=C/CCCCCC/1", 0, 1), Side::Conflict)
+    }
+}
@@ -0,0 +1,12 @@
+/// A place where stereo could be specified, and whether it is.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum StereoUnit {
+    /// A tetrahedral center, specified through Bracket::parity.
+    Tetrahedral { center: usize, specified: bool },
+    /// A double bond, specified through Up and Down bonds at both ends.
+    DoubleBond {
+        source: usize,
+        target: usize,
+        specified: bool,
+    },
+}
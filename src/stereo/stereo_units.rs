@@ -0,0 +1,400 @@
+use std::collections::VecDeque;
+
+use super::{double_bonds, StereoUnit};
+use crate::{
+    feature::{AtomKind, BondKind, Element},
+    graph::{ring_bonds, Atom},
+    search::symmetry_classes,
+};
+
+/// Returns the tetrahedral centers and double bonds of atoms that could
+/// carry stereo, centers first, each marked as specified or not.
+///
+/// A potential center has four ligands, counting at most one hydrogen, whose
+/// neighbors belong to distinct symmetry classes. Sulfur, selenium,
+/// phosphorus and arsenic with three neighbors and no hydrogen also qualify,
+/// their lone pair serving as the fourth ligand. A ring atom whose only tied
+/// neighbors are its two ring neighbors qualifies when its ring system holds
+/// another potential center, as in 1,4-dimethylcyclohexane. An atom whose
+/// only tied neighbors start two branches, each holding a potential center,
+/// qualifies as a pseudoasymmetric center, as in pentane-2,3,4-triol.
+///
+/// A potential double bond is an explicit double bond outside rings of
+/// fewer than eight atoms whose ends each carry two distinct substituents,
+/// counting a hydrogen or, on nitrogen, a lone pair.
+pub fn stereo_units(atoms: &[Atom]) -> Vec<StereoUnit> {
+    let mut result = Vec::new();
+
+    for (index, potential) in potential_centers(atoms).into_iter().enumerate() {
+        if potential {
+            result.push(StereoUnit::Tetrahedral {
+                center: index,
+                specified: match &atoms[index].kind {
+                    AtomKind::Bracket(bracket) => bracket.parity.is_some(),
+                    _ => false,
+                },
+            })
+        }
+    }
+
+    let classes = symmetry_classes(atoms);
+    let specified = double_bonds(atoms);
+
+    for (source, atom) in atoms.iter().enumerate() {
+        for bond in &atom.bonds {
+            if bond.kind != BondKind::Double
+                || bond.tid < source
+                || !is_potential_end(atoms, source, bond.tid, &classes)
+                || !is_potential_end(atoms, bond.tid, source, &classes)
+                || ring_size(atoms, source, bond.tid)
+                    .is_some_and(|size| size < 8)
+            {
+                continue;
+            }
+
+            result.push(StereoUnit::DoubleBond {
+                source,
+                target: bond.tid,
+                specified: specified.iter().any(|double_bond| {
+                    double_bond.source == source
+                        && double_bond.target == bond.tid
+                }),
+            })
+        }
+    }
+
+    result
+}
+
+/// Returns, for each atom, whether it could be a tetrahedral center. See
+/// stereo_units.
+pub(crate) fn potential_centers(atoms: &[Atom]) -> Vec<bool> {
+    let classes = symmetry_classes(atoms);
+    let ring = ring_bonds(atoms);
+    let mut result = vec![false; atoms.len()];
+    let mut ring_centers = Vec::new();
+    let mut branch_centers = Vec::new();
+
+    for (index, atom) in atoms.iter().enumerate() {
+        if !has_tetrahedral_shape(atom) {
+            continue;
+        }
+
+        let mut tied = Vec::new();
+
+        for (i, bond) in atom.bonds.iter().enumerate() {
+            for (j, other) in atom.bonds.iter().enumerate().skip(i + 1) {
+                if classes[bond.tid] == classes[other.tid] {
+                    tied.push((i, j))
+                }
+            }
+        }
+
+        match tied[..] {
+            [] => result[index] = true,
+            [(i, j)] if ring[index][i] && ring[index][j] => {
+                ring_centers.push(index)
+            }
+            [(i, j)] => branch_centers.push((
+                index,
+                atom.bonds[i].tid,
+                atom.bonds[j].tid,
+            )),
+            _ => (),
+        }
+    }
+
+    let systems = ring_systems(atoms, &ring);
+    let is_candidate = |other: usize| {
+        ring_centers.contains(&other)
+            || branch_centers.iter().any(|(center, _, _)| *center == other)
+    };
+    let mut centers = result.clone();
+
+    for &index in &ring_centers {
+        centers[index] = (0..atoms.len()).any(|other| {
+            other != index
+                && systems[other] == systems[index]
+                && (result[other] || is_candidate(other))
+        })
+    }
+
+    for &(index, first, second) in &branch_centers {
+        centers[index] = [first, second].into_iter().all(|start| {
+            branch(atoms, index, start)
+                .into_iter()
+                .any(|other| result[other] || is_candidate(other))
+        })
+    }
+
+    centers
+}
+
+/// Returns the atoms reached from start without passing through center.
+fn branch(atoms: &[Atom], center: usize, start: usize) -> Vec<usize> {
+    let mut visited = vec![false; atoms.len()];
+    let mut stack = vec![start];
+    let mut result = Vec::new();
+
+    visited[center] = true;
+    visited[start] = true;
+
+    while let Some(id) = stack.pop() {
+        result.push(id);
+
+        for bond in &atoms[id].bonds {
+            if !visited[bond.tid] {
+                visited[bond.tid] = true;
+                stack.push(bond.tid)
+            }
+        }
+    }
+
+    result
+}
+
+fn has_tetrahedral_shape(atom: &Atom) -> bool {
+    if atom.kind.is_selected() {
+        return false;
+    }
+
+    let element = match atom.kind.element() {
+        Some(element) => element,
+        None => return false,
+    };
+    let hydrogens = atom.hydrogens() as usize;
+
+    if hydrogens > 1 {
+        return false;
+    }
+
+    match atom.bonds.len() + hydrogens {
+        4 => atom.bonds.iter().all(|bond| {
+            bond.kind.bond_order() == 1
+                || !matches!(element, Element::C | Element::Si)
+        }),
+        3 => {
+            hydrogens == 0
+                && matches!(
+                    element,
+                    Element::S | Element::Se | Element::P | Element::As
+                )
+        }
+        _ => false,
+    }
+}
+
+fn ring_systems(atoms: &[Atom], ring: &[Vec<bool>]) -> Vec<usize> {
+    let mut result = vec![usize::MAX; atoms.len()];
+
+    for root in 0..atoms.len() {
+        if result[root] != usize::MAX {
+            continue;
+        }
+
+        let mut stack = vec![root];
+
+        result[root] = root;
+
+        while let Some(id) = stack.pop() {
+            for (bond, ring) in atoms[id].bonds.iter().zip(&ring[id]) {
+                if *ring && result[bond.tid] == usize::MAX {
+                    result[bond.tid] = root;
+                    stack.push(bond.tid)
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn is_potential_end(
+    atoms: &[Atom],
+    end: usize,
+    other: usize,
+    classes: &[usize],
+) -> bool {
+    let atom = &atoms[end];
+
+    if atom.kind.is_selected()
+        || atom
+            .bonds
+            .iter()
+            .any(|bond| bond.tid != other && bond.kind.bond_order() > 1)
+    {
+        return false;
+    }
+
+    let neighbors = atom
+        .bonds
+        .iter()
+        .filter(|bond| bond.tid != other)
+        .map(|bond| bond.tid)
+        .collect::<Vec<_>>();
+
+    match (&neighbors[..], atom.hydrogens()) {
+        ([_], 0) => atom.kind.element() == Some(Element::N),
+        ([_], 1) => true,
+        ([first, second], 0) => classes[*first] != classes[*second],
+        _ => false,
+    }
+}
+
+/// Returns the size of the smallest ring holding the bond between source
+/// and target, if any.
+fn ring_size(atoms: &[Atom], source: usize, target: usize) -> Option<usize> {
+    let mut distances = vec![usize::MAX; atoms.len()];
+    let mut queue = VecDeque::from([source]);
+
+    distances[source] = 0;
+
+    while let Some(id) = queue.pop_front() {
+        for bond in &atoms[id].bonds {
+            if id == source && bond.tid == target {
+                continue;
+            }
+
+            if distances[bond.tid] == usize::MAX {
+                distances[bond.tid] = distances[id] + 1;
+
+                if bond.tid == target {
+                    return Some(distances[bond.tid] + 1);
+                }
+
+                queue.push_back(bond.tid)
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn units(string: &str) -> Vec<StereoUnit> {
+        stereo_units(&atoms_of(string))
+    }
+
+    fn center(center: usize, specified: bool) -> StereoUnit {
+        StereoUnit::Tetrahedral { center, specified }
+    }
+
+    fn bond(source: usize, target: usize, specified: bool) -> StereoUnit {
+        StereoUnit::DoubleBond {
+            source,
+            target,
+            specified,
+        }
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(units("CC(C)O"), vec![])
+    }
+
+    #[test]
+    fn unspecified_center() {
+        assert_eq!(units("CC(N)C(=O)O"), vec![center(1, false)])
+    }
+
+    #[test]
+    fn specified_center() {
+        assert_eq!(units("N[C@@H](C)C(=O)O"), vec![center(1, true)])
+    }
+
+    #[test]
+    fn quaternary_center() {
+        assert_eq!(units("CC(F)(Cl)Br"), vec![center(1, false)])
+    }
+
+    #[test]
+    fn sulfoxide() {
+        assert_eq!(units("CS(=O)c1ccccc1"), vec![center(1, false)])
+    }
+
+    #[test]
+    fn amine() {
+        assert_eq!(units("CN(CC)CCC"), vec![])
+    }
+
+    #[test]
+    fn methylcyclohexane() {
+        assert_eq!(units("CC1CCCCC1"), vec![])
+    }
+
+    #[test]
+    fn dimethylcyclohexane() {
+        assert_eq!(
+            units("CC1CCC(C)CC1"),
+            vec![center(1, false), center(4, false)]
+        )
+    }
+
+    #[test]
+    fn unspecified_double_bond() {
+        assert_eq!(units("CC=CC"), vec![bond(1, 2, false)])
+    }
+
+    #[test]
+    fn specified_double_bond() {
+        assert_eq!(units("C/C=C/C"), vec![bond(1, 2, true)])
+    }
+
+    #[test]
+    fn half_specified_double_bond() {
+        assert_eq!(units("C/C=CC"), vec![bond(1, 2, false)])
+    }
+
+    #[test]
+    fn terminal_double_bond() {
+        assert_eq!(units("C=CC"), vec![])
+    }
+
+    #[test]
+    fn tied_double_bond() {
+        assert_eq!(units("CC=C(C)C"), vec![])
+    }
+
+    #[test]
+    fn oxime() {
+        assert_eq!(units("CC=NO"), vec![bond(1, 2, false)])
+    }
+
+    #[test]
+    fn small_ring() {
+        assert_eq!(units("C1CCC=CC1"), vec![])
+    }
+
+    #[test]
+    fn large_ring() {
+        assert_eq!(units("C1CCCC=CCC1"), vec![bond(4, 5, false)])
+    }
+
+    #[test]
+    fn allene() {
+        assert_eq!(units("CC=C=CC"), vec![])
+    }
+
+    #[test]
+    fn aromatic() {
+        assert_eq!(units("Cc1ccccc1"), vec![])
+    }
+
+    #[test]
+    fn pseudoasymmetric() {
+        assert_eq!(
+            units("CC(O)C(O)C(O)C"),
+            vec![center(1, false), center(3, false), center(5, false)]
+        )
+    }
+
+    #[test]
+    fn tied_branches_without_centers() {
+        assert_eq!(units("CCC(O)CC"), vec![])
+    }
+}
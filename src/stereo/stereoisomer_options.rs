@@ -0,0 +1,18 @@
+/// Controls stereoisomer enumeration.
+#[derive(Debug, PartialEq, Clone)]
+pub struct StereoisomerOptions {
+    /// Enumerate specified centers and double bonds as well, rather than
+    /// keeping their configuration.
+    pub enumerate_specified: bool,
+    /// Stop after this many stereoisomers.
+    pub max_isomers: usize,
+}
+
+impl Default for StereoisomerOptions {
+    fn default() -> Self {
+        Self {
+            enumerate_specified: false,
+            max_isomers: 1024,
+        }
+    }
+}
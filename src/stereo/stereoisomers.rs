@@ -0,0 +1,342 @@
+use super::{
+    bridgeheads, clear_directions, ligands, potential_centers, set_direction,
+    stereo_units, Configuration, Ligand, Side, StereoUnit, StereoisomerOptions,
+    Tetrahedral,
+};
+use crate::{
+    feature::{AtomKind, AtomParity, BondKind},
+    graph::{to_bracket, Atom},
+    hash::{molecule_hash, HashLayer},
+    search::is_isomorphic,
+};
+
+/// Returns the stereoisomers of atoms over the units found by stereo_units,
+/// without duplicates. Centers get a parity, turning Shortcut atoms into
+/// Bracket atoms, and double bonds get Up and Down bonds. Specified units
+/// keep their configuration unless options say otherwise. Forms that are the
+/// same molecule, such as the two halves of a meso pair, appear once. Forms
+/// whose bridgeheads can't both be realized, as in norbornane, are left out.
+/// Write results with graph::walk and follow::Writer for Balsa strings.
+pub fn stereoisomers(
+    atoms: &[Atom],
+    options: &StereoisomerOptions,
+) -> Vec<Vec<Atom>> {
+    let units = stereo_units(atoms)
+        .into_iter()
+        .filter(|unit| {
+            options.enumerate_specified
+                || !matches!(
+                    unit,
+                    StereoUnit::Tetrahedral {
+                        specified: true,
+                        ..
+                    } | StereoUnit::DoubleBond {
+                        specified: true,
+                        ..
+                    }
+                )
+        })
+        .collect::<Vec<_>>();
+    let mut base = atoms.to_vec();
+
+    for unit in &units {
+        match unit {
+            StereoUnit::Tetrahedral { center, .. } => {
+                to_bracket(&mut base[*center]);
+            }
+            StereoUnit::DoubleBond { source, target, .. } => {
+                let conflict = Side::new(&base, *source, *target)
+                    == Side::Conflict
+                    || Side::new(&base, *target, *source) == Side::Conflict;
+
+                if options.enumerate_specified || conflict {
+                    clear_directions(&mut base, *source, *target);
+                    clear_directions(&mut base, *target, *source);
+                }
+            }
+        }
+    }
+
+    let centers = potential_centers(&base)
+        .into_iter()
+        .enumerate()
+        .filter(|(_, potential)| *potential)
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    let bridgeheads = bridgeheads(&base, &centers);
+    let mut result: Vec<Vec<Atom>> = Vec::new();
+    let mut hashes = Vec::new();
+    let total = 1u128.checked_shl(units.len() as u32).unwrap_or(u128::MAX);
+    let mut combination = 0;
+
+    while combination < total && result.len() < options.max_isomers {
+        let mut isomer = base.clone();
+        let mut realized = true;
+
+        for (bit, unit) in units.iter().enumerate() {
+            let flag = combination >> bit & 1 == 1;
+
+            match unit {
+                StereoUnit::Tetrahedral { center, .. } => {
+                    if let AtomKind::Bracket(bracket) =
+                        &mut isomer[*center].kind
+                    {
+                        bracket.parity = Some(if flag {
+                            AtomParity::Clockwise
+                        } else {
+                            AtomParity::Counterclockwise
+                        })
+                    }
+                }
+                StereoUnit::DoubleBond { source, target, .. } => {
+                    let configuration = if flag {
+                        Configuration::Cis
+                    } else {
+                        Configuration::Trans
+                    };
+
+                    realized &=
+                        assign(&mut isomer, *source, *target, configuration)
+                }
+            }
+        }
+
+        combination += 1;
+
+        if !realized || !fits_bridges(&isomer, &bridgeheads) {
+            continue;
+        }
+
+        let hash = molecule_hash(&isomer, HashLayer::Stereo);
+        let duplicate =
+            result.iter().zip(&hashes).any(|(other, other_hash)| {
+                *other_hash == hash && is_isomorphic(other, &isomer, true)
+            });
+
+        if !duplicate {
+            result.push(isomer);
+            hashes.push(hash)
+        }
+    }
+
+    result
+}
+
+/// Returns false if a pair of bridgeheads wind in the same direction, as
+/// viewed by bridgeheads.
+fn fits_bridges(atoms: &[Atom], pairs: &[[(usize, [Ligand; 4]); 2]]) -> bool {
+    pairs.iter().all(|pair| {
+        let windings = pair
+            .iter()
+            .map(|(center, order)| {
+                let parity = match &atoms[*center].kind {
+                    AtomKind::Bracket(bracket) => bracket.parity.clone()?,
+                    _ => return None,
+                };
+                let ligands = ligands(atoms, *center).try_into().ok()?;
+                let tetrahedral = Tetrahedral {
+                    center: *center,
+                    ligands,
+                    winding: parity,
+                };
+
+                tetrahedral.winding_in(order)
+            })
+            .collect::<Option<Vec<_>>>();
+
+        match windings.as_deref() {
+            Some([first, second]) => first != second,
+            _ => true,
+        }
+    })
+}
+
+/// Marks the double bond between source and target with configuration,
+/// building on markers already present. Returns false if they disagree.
+fn assign(
+    atoms: &mut [Atom],
+    source: usize,
+    target: usize,
+    configuration: Configuration,
+) -> bool {
+    let cis = configuration == Configuration::Cis;
+
+    match (
+        Side::new(atoms, source, target),
+        Side::new(atoms, target, source),
+    ) {
+        (Side::Marked(_, first), Side::Marked(_, second)) => {
+            (first == second) == cis
+        }
+        (Side::Marked(_, kind), Side::Unmarked) => {
+            mark(atoms, target, source, &kind, cis)
+        }
+        (Side::Unmarked, Side::Marked(_, kind)) => {
+            mark(atoms, source, target, &kind, cis)
+        }
+        (Side::Unmarked, Side::Unmarked) => {
+            mark(atoms, source, target, &BondKind::Down, true)
+                && mark(atoms, target, source, &BondKind::Down, cis)
+        }
+        _ => false,
+    }
+}
+
+/// Marks the first neighbor of end other than other with kind, or its
+/// reverse unless same.
+fn mark(
+    atoms: &mut [Atom],
+    end: usize,
+    other: usize,
+    kind: &BondKind,
+    same: bool,
+) -> bool {
+    let neighbor = match atoms[end].bonds.iter().find(|bond| bond.tid != other)
+    {
+        Some(bond) => bond.tid,
+        None => return false,
+    };
+    let kind = if same { kind.clone() } else { kind.reverse() };
+
+    set_direction(atoms, end, neighbor, &kind);
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{follow::Writer, graph::walk, read::atoms_of};
+    use pretty_assertions::assert_eq;
+
+    fn isomers(string: &str, options: &StereoisomerOptions) -> Vec<String> {
+        stereoisomers(&atoms_of(string), options)
+            .iter()
+            .map(|atoms| {
+                let mut writer = Writer::new();
+
+                walk(atoms, &mut writer);
+
+                writer.write()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(isomers("CCO", &Default::default()), vec!["CCO"])
+    }
+
+    #[test]
+    fn one_center() {
+        assert_eq!(
+            isomers("CC(O)CC", &Default::default()),
+            vec!["C[C@H1](O)CC", "C[C@@H1](O)CC"]
+        )
+    }
+
+    #[test]
+    fn meso() {
+        assert_eq!(
+            isomers("CC(O)C(O)C", &Default::default()),
+            vec![
+                "C[C@H1](O)[C@H1](O)C",
+                "C[C@@H1](O)[C@H1](O)C",
+                "C[C@H1](O)[C@@H1](O)C"
+            ]
+        )
+    }
+
+    #[test]
+    fn pseudoasymmetric() {
+        assert_eq!(
+            isomers("CC(O)C(O)C(O)C", &Default::default()),
+            vec![
+                "C[C@H1](O)[C@H1](O)[C@H1](O)C",
+                "C[C@@H1](O)[C@H1](O)[C@H1](O)C",
+                "C[C@H1](O)[C@@H1](O)[C@H1](O)C",
+                "C[C@H1](O)[C@H1](O)[C@@H1](O)C"
+            ]
+        )
+    }
+
+    #[test]
+    fn norbornane() {
+        assert_eq!(isomers("C1CC2CCC1C2", &Default::default()).len(), 1)
+    }
+
+    #[test]
+    fn camphor() {
+        assert_eq!(
+            isomers("CC1(C)C2CCC1(C)C(=O)C2", &Default::default()).len(),
+            2
+        )
+    }
+
+    #[test]
+    fn double_bond() {
+        assert_eq!(
+            isomers("CC=CC", &Default::default()),
+            vec!["C/C=C/C", "C/C=C\\C"]
+        )
+    }
+
+    #[test]
+    fn conjugated() {
+        assert_eq!(isomers("CC=CC=CC", &Default::default()).len(), 3)
+    }
+
+    #[test]
+    fn center_and_double_bond() {
+        assert_eq!(isomers("CC(O)C=CC", &Default::default()).len(), 4)
+    }
+
+    #[test]
+    fn keeps_specified() {
+        assert_eq!(
+            isomers("C[C@H](O)C(O)CC", &Default::default()),
+            vec!["C[C@H1](O)[C@H1](O)CC", "C[C@H1](O)[C@@H1](O)CC"]
+        )
+    }
+
+    #[test]
+    fn keeps_half_specified_marker() {
+        assert_eq!(
+            isomers("C/C=CC", &Default::default()),
+            vec!["C/C=C/C", "C/C=C\\C"]
+        )
+    }
+
+    #[test]
+    fn enumerate_specified() {
+        let options = StereoisomerOptions {
+            enumerate_specified: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            isomers("N[C@@H](C)C(=O)O", &options),
+            vec!["N[C@H1](C)C(=O)O", "N[C@@H1](C)C(=O)O"]
+        )
+    }
+
+    #[test]
+    fn enumerate_specified_double_bond() {
+        let options = StereoisomerOptions {
+            enumerate_specified: true,
+            ..Default::default()
+        };
+
+        assert_eq!(isomers("C/C=C/C", &options), vec!["C/C=C/C", "C/C=C\\C"])
+    }
+
+    #[test]
+    fn cap() {
+        let options = StereoisomerOptions {
+            max_isomers: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(isomers("CC(O)C(O)C(O)CC", &options).len(), 2)
+    }
+}
@@ -0,0 +1,75 @@
+use super::potential_centers;
+use crate::{feature::AtomKind, graph::Atom};
+
+/// Removes parity from bracket atoms that can't be tetrahedral centers, as
+/// judged by stereo_units, and returns their indices.
+pub fn strip_invalid_parity(atoms: &mut [Atom]) -> Vec<usize> {
+    let potential = potential_centers(atoms);
+    let mut result = Vec::new();
+
+    for (index, atom) in atoms.iter_mut().enumerate() {
+        if let AtomKind::Bracket(bracket) = &mut atom.kind {
+            if bracket.parity.is_some() && !potential[index] {
+                bracket.parity = None;
+                result.push(index)
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{follow::Writer, graph::walk, read::atoms_of};
+    use pretty_assertions::assert_eq;
+
+    fn strip(string: &str) -> (Vec<usize>, String) {
+        let mut atoms = atoms_of(string);
+        let stripped = strip_invalid_parity(&mut atoms);
+        let mut writer = Writer::new();
+
+        walk(&atoms, &mut writer);
+
+        (stripped, writer.write())
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            strip("N[C@@H](C)C(=O)O"),
+            (vec![], "N[C@@H1](C)C(=O)O".to_string())
+        )
+    }
+
+    #[test]
+    fn identical_substituents() {
+        assert_eq!(strip("[C@H](C)(C)C"), (vec![0], "[CH1](C)(C)C".to_string()))
+    }
+
+    #[test]
+    fn too_many_hydrogens() {
+        assert_eq!(strip("F[C@H2]Cl"), (vec![1], "F[CH2]Cl".to_string()))
+    }
+
+    #[test]
+    fn ring_pair() {
+        assert_eq!(
+            strip("C[C@H]1CC[C@@H](C)CC1"),
+            (vec![], "C[C@H1](CC[C@@H1](CC1)C)1".to_string())
+        )
+    }
+
+    #[test]
+    fn pseudoasymmetric_pair() {
+        assert_eq!(
+            strip("C[C@@H](O)[C@H](O)[C@@H](O)C"),
+            (vec![], "C[C@@H1](O)[C@H1](O)[C@@H1](O)C".to_string())
+        );
+        assert_eq!(
+            strip("C[C@@H](O)[C@@H](O)[C@@H](O)C"),
+            (vec![], "C[C@@H1](O)[C@@H1](O)[C@@H1](O)C".to_string())
+        )
+    }
+}
@@ -0,0 +1,196 @@
+use super::{is_odd, ligands, Error, Ligand};
+use crate::{
+    feature::{AtomKind, AtomParity},
+    graph::Atom,
+};
+
+/// A tetrahedral stereocenter. Looking from the first ligand toward center,
+/// the other three wind counterclockwise (`@`) or clockwise (`@@`) as given
+/// by winding. Ligands refer to atoms by index, so the record holds whatever
+/// order graph::Atom::bonds comes in.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Tetrahedral {
+    pub center: usize,
+    pub ligands: [Ligand; 4],
+    pub winding: AtomParity,
+}
+
+impl Tetrahedral {
+    /// Returns the winding of this configuration with ligands given in
+    /// order, or None if order isn't a reordering of the ligands.
+    pub fn winding_in(&self, order: &[Ligand]) -> Option<AtomParity> {
+        if is_odd(&self.ligands, order)? {
+            Some(match self.winding {
+                AtomParity::Counterclockwise => AtomParity::Clockwise,
+                AtomParity::Clockwise => AtomParity::Counterclockwise,
+            })
+        } else {
+            Some(self.winding.clone())
+        }
+    }
+
+    /// Sets the parity of center so that it describes this configuration
+    /// under the order of stereo::ligands. Call it after reordering bonds
+    /// and before graph::walk.
+    pub fn apply(&self, atoms: &mut [Atom]) -> Result<(), Error> {
+        let winding = match self.winding_in(&ligands(atoms, self.center)) {
+            Some(winding) => winding,
+            None => return Err(Error::Ligands(self.center)),
+        };
+
+        match &mut atoms[self.center].kind {
+            AtomKind::Bracket(bracket) => {
+                bracket.parity.replace(winding);
+
+                Ok(())
+            }
+            _ => Err(Error::NotBracket(self.center)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        follow::Writer, graph::walk, read::atoms_of, search::is_isomorphic,
+        stereo::tetrahedral_centers,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn write(atoms: &[Atom]) -> String {
+        let mut writer = Writer::new();
+
+        walk(atoms, &mut writer);
+
+        writer.write()
+    }
+
+    #[test]
+    fn winding_in_same_order() {
+        let tetrahedral = Tetrahedral {
+            center: 1,
+            ligands: [
+                Ligand::Atom(0),
+                Ligand::Implicit,
+                Ligand::Atom(2),
+                Ligand::Atom(3),
+            ],
+            winding: AtomParity::Counterclockwise,
+        };
+
+        assert_eq!(
+            tetrahedral.winding_in(&tetrahedral.ligands),
+            Some(AtomParity::Counterclockwise)
+        )
+    }
+
+    #[test]
+    fn winding_in_swapped_order() {
+        let tetrahedral = Tetrahedral {
+            center: 1,
+            ligands: [
+                Ligand::Atom(0),
+                Ligand::Implicit,
+                Ligand::Atom(2),
+                Ligand::Atom(3),
+            ],
+            winding: AtomParity::Counterclockwise,
+        };
+
+        assert_eq!(
+            tetrahedral.winding_in(&[
+                Ligand::Implicit,
+                Ligand::Atom(0),
+                Ligand::Atom(2),
+                Ligand::Atom(3),
+            ]),
+            Some(AtomParity::Clockwise)
+        )
+    }
+
+    #[test]
+    fn winding_in_other_ligands() {
+        let tetrahedral = Tetrahedral {
+            center: 1,
+            ligands: [
+                Ligand::Atom(0),
+                Ligand::Implicit,
+                Ligand::Atom(2),
+                Ligand::Atom(3),
+            ],
+            winding: AtomParity::Counterclockwise,
+        };
+
+        assert_eq!(
+            tetrahedral.winding_in(&[
+                Ligand::Atom(0),
+                Ligand::Atom(4),
+                Ligand::Atom(2),
+                Ligand::Atom(3),
+            ]),
+            None
+        )
+    }
+
+    #[test]
+    fn apply_after_reversing_bonds() {
+        let original = atoms_of("N[C@@H](C)C(=O)O");
+        let mut atoms = original.clone();
+        let records = tetrahedral_centers(&atoms);
+
+        for atom in atoms.iter_mut() {
+            atom.bonds.reverse()
+        }
+
+        for record in &records {
+            record.apply(&mut atoms).unwrap()
+        }
+
+        assert!(is_isomorphic(&atoms_of(&write(&atoms)), &original, true))
+    }
+
+    #[test]
+    fn apply_keeps_records() {
+        let mut atoms = atoms_of("F[C@](Cl)(Br)I");
+        let records = tetrahedral_centers(&atoms);
+
+        atoms[1].bonds.rotate_left(1);
+
+        for record in &records {
+            record.apply(&mut atoms).unwrap()
+        }
+
+        assert_eq!(
+            tetrahedral_centers(&atoms)[0].winding_in(&records[0].ligands),
+            Some(AtomParity::Counterclockwise)
+        )
+    }
+
+    #[test]
+    fn apply_not_bracket() {
+        let mut atoms = atoms_of("CC(C)O");
+        let tetrahedral = Tetrahedral {
+            center: 1,
+            ligands: [
+                Ligand::Atom(0),
+                Ligand::Atom(2),
+                Ligand::Atom(3),
+                Ligand::Implicit,
+            ],
+            winding: AtomParity::Clockwise,
+        };
+
+        assert_eq!(tetrahedral.apply(&mut atoms), Err(Error::NotBracket(1)))
+    }
+
+    #[test]
+    fn apply_lost_ligand() {
+        let mut atoms = atoms_of("N[C@@H](C)O");
+        let records = tetrahedral_centers(&atoms);
+
+        atoms[1].bonds.pop();
+
+        assert_eq!(records[0].apply(&mut atoms), Err(Error::Ligands(1)))
+    }
+}
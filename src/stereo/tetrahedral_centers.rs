@@ -0,0 +1,98 @@
+use super::{ligands, Ligand, Tetrahedral};
+use crate::{feature::AtomKind, graph::Atom};
+
+/// Returns a record for each atom whose parity describes a tetrahedral
+/// stereocenter, read under the order of stereo::ligands. Parity on atoms
+/// without four ligands, or with more than one implicit ligand, is skipped.
+pub fn tetrahedral_centers(atoms: &[Atom]) -> Vec<Tetrahedral> {
+    let mut result = Vec::new();
+
+    for (index, atom) in atoms.iter().enumerate() {
+        let winding = match &atom.kind {
+            AtomKind::Bracket(bracket) => match &bracket.parity {
+                Some(parity) => parity,
+                None => continue,
+            },
+            _ => continue,
+        };
+        let ligands = ligands(atoms, index);
+        let implicit = ligands
+            .iter()
+            .filter(|ligand| **ligand == Ligand::Implicit)
+            .count();
+
+        if implicit > 1 {
+            continue;
+        }
+
+        if let Ok(ligands) = ligands.try_into() {
+            result.push(Tetrahedral {
+                center: index,
+                ligands,
+                winding: winding.clone(),
+            })
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{feature::AtomParity, read::atoms_of};
+    use pretty_assertions::assert_eq;
+
+    fn centers(string: &str) -> Vec<Tetrahedral> {
+        tetrahedral_centers(&atoms_of(string))
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(centers("CC(N)C(=O)O"), vec![])
+    }
+
+    #[test]
+    fn virtual_hydrogen() {
+        assert_eq!(
+            centers("N[C@@H](C)C(=O)O"),
+            vec![Tetrahedral {
+                center: 1,
+                ligands: [
+                    Ligand::Atom(0),
+                    Ligand::Implicit,
+                    Ligand::Atom(2),
+                    Ligand::Atom(3)
+                ],
+                winding: AtomParity::Clockwise
+            }]
+        )
+    }
+
+    #[test]
+    fn bridge() {
+        assert_eq!(
+            centers("N[C@]1(C)CO1"),
+            vec![Tetrahedral {
+                center: 1,
+                ligands: [
+                    Ligand::Atom(0),
+                    Ligand::Atom(4),
+                    Ligand::Atom(2),
+                    Ligand::Atom(3)
+                ],
+                winding: AtomParity::Counterclockwise
+            }]
+        )
+    }
+
+    #[test]
+    fn two_virtual_hydrogens() {
+        assert_eq!(centers("F[C@H2]Cl"), vec![])
+    }
+
+    #[test]
+    fn too_few_ligands() {
+        assert_eq!(centers("F[C@]Cl"), vec![])
+    }
+}
@@ -0,0 +1,448 @@
+use std::collections::HashSet;
+
+use super::kekulize;
+use crate::{
+    feature::{
+        AtomKind, BondKind, Bracket, Element, Selection, Symbol,
+        VirtualHydrogen,
+    },
+    graph::{ring_bonds, Atom},
+    search::BondClass,
+    validate::kekule_failures,
+};
+
+/// Returns atoms in a form that doesn't depend on where their double bonds
+/// were placed. Atoms are kekulized, then the bonds of each ring of up to
+/// seven atoms meeting Hückel's rule, and each bond whose order differs
+/// between Kekulé structures, become aromatic bonds between selected atoms.
+/// Atoms are returned kekulized if they can't be written this way.
+pub fn aromatize(atoms: &[Atom]) -> Vec<Atom> {
+    let kekule = kekulize(atoms);
+
+    if kekule.iter().any(|atom| atom.kind.is_selected()) {
+        return kekule;
+    }
+
+    let ring = ring_bonds(&kekule);
+    let mut aromatic = HashSet::new();
+
+    for cycle in cycles(&kekule, &ring) {
+        if electrons(&kekule, &ring, &cycle).is_some_and(|sum| sum % 4 == 2) {
+            for (i, &sid) in cycle.iter().enumerate() {
+                aromatic.insert(key(sid, cycle[(i + 1) % cycle.len()]));
+            }
+        }
+    }
+
+    alternating(&kekule, &mut aromatic);
+
+    let mut selected = vec![false; kekule.len()];
+
+    for &(sid, tid) in &aromatic {
+        selected[sid] = true;
+        selected[tid] = true
+    }
+
+    let mut result = kekule.clone();
+
+    for (sid, atom) in result.iter_mut().enumerate() {
+        for bond in atom.bonds.iter_mut() {
+            if aromatic.contains(&key(sid, bond.tid)) {
+                bond.kind = BondKind::Elided
+            } else if selected[sid]
+                && selected[bond.tid]
+                && bond.kind == BondKind::Elided
+            {
+                bond.kind = BondKind::Single
+            }
+        }
+
+        if selected[sid] {
+            select(&mut atom.kind)
+        }
+    }
+
+    for (atom, original) in result.iter_mut().zip(&kekule) {
+        if let AtomKind::Selection(selection) = &atom.kind {
+            if atom.hydrogens() != original.hydrogens() {
+                atom.kind = AtomKind::Bracket(Bracket {
+                    symbol: Symbol::Selection(selection.clone()),
+                    hydrogens: VirtualHydrogen::new(original.hydrogens()),
+                    ..Default::default()
+                })
+            }
+        }
+    }
+
+    if kekule_failures(&result).is_empty() {
+        result
+    } else {
+        kekule
+    }
+}
+
+fn key(sid: usize, tid: usize) -> (usize, usize) {
+    (sid.min(tid), sid.max(tid))
+}
+
+fn selection(element: &Element) -> Option<Selection> {
+    match element {
+        Element::B => Some(Selection::B),
+        Element::C => Some(Selection::C),
+        Element::N => Some(Selection::N),
+        Element::O => Some(Selection::O),
+        Element::P => Some(Selection::P),
+        Element::S => Some(Selection::S),
+        _ => None,
+    }
+}
+
+fn is_selectable(atom: &Atom) -> bool {
+    atom.kind.element().as_ref().and_then(selection).is_some()
+}
+
+/// Replaces the element of a Shortcut or Bracket kind with its selection.
+fn select(kind: &mut AtomKind) {
+    let selection = match kind.element().as_ref().and_then(selection) {
+        Some(selection) => selection,
+        None => return,
+    };
+
+    match kind {
+        AtomKind::Shortcut(_) => *kind = AtomKind::Selection(selection),
+        AtomKind::Bracket(bracket) => {
+            bracket.symbol = Symbol::Selection(selection)
+        }
+        _ => (),
+    }
+}
+
+/// Returns the rings of three to seven atoms, each starting from its lowest
+/// index.
+fn cycles(atoms: &[Atom], ring: &[Vec<bool>]) -> Vec<Vec<usize>> {
+    let mut result = Vec::new();
+
+    for start in 0..atoms.len() {
+        let mut stack = vec![vec![start]];
+
+        while let Some(path) = stack.pop() {
+            let last = path[path.len() - 1];
+
+            for (bond, &in_ring) in atoms[last].bonds.iter().zip(&ring[last]) {
+                if !in_ring {
+                    continue;
+                }
+
+                if bond.tid == start {
+                    if path.len() > 2 && path[1] < last {
+                        result.push(path.clone())
+                    }
+                } else if bond.tid > start
+                    && path.len() < 7
+                    && !path.contains(&bond.tid)
+                {
+                    let mut next = path.clone();
+
+                    next.push(bond.tid);
+                    stack.push(next)
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Returns the pi electrons cycle contributes in Kekulé form, or None if an
+/// atom of it can't take part in an aromatic ring.
+fn electrons(
+    atoms: &[Atom],
+    ring: &[Vec<bool>],
+    cycle: &[usize],
+) -> Option<u8> {
+    let mut result = 0;
+
+    for &sid in cycle {
+        let atom = &atoms[sid];
+
+        if !is_selectable(atom) {
+            return None;
+        }
+
+        let mut doubles = Vec::new();
+
+        for (bond, &in_ring) in atom.bonds.iter().zip(&ring[sid]) {
+            match BondClass::new(atoms, sid, bond) {
+                BondClass::Double => doubles.push((bond.tid, in_ring)),
+                BondClass::Triple => return None,
+                _ => (),
+            }
+        }
+
+        result += match doubles[..] {
+            [(tid, in_ring)] => {
+                if in_ring || cycle.contains(&tid) {
+                    1
+                } else if matches!(
+                    atoms[tid].kind.element(),
+                    Some(Element::N | Element::O | Element::S)
+                ) {
+                    0
+                } else {
+                    return None;
+                }
+            }
+            [] => {
+                if atom.lone_pairs()? > 0 {
+                    2
+                } else if atom.charge() > 0
+                    && atom.kind.element() == Some(Element::C)
+                    && atom.bonds.len() + atom.hydrogens() as usize == 3
+                {
+                    0
+                } else {
+                    return None;
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    Some(result)
+}
+
+/// Adds to aromatic each bond lying on a cycle of alternating single and
+/// double bonds, whose order therefore differs between Kekulé structures.
+fn alternating(atoms: &[Atom], aromatic: &mut HashSet<(usize, usize)>) {
+    let mates = mates(atoms);
+
+    for (sid, atom) in atoms.iter().enumerate() {
+        for bond in &atom.bonds {
+            let tid = bond.tid;
+
+            if tid < sid
+                || aromatic.contains(&key(sid, tid))
+                || !is_pairable(atoms, &mates, sid, bond.tid)
+            {
+                continue;
+            }
+
+            let system = system(atoms, &mates, sid);
+            let mut open = vec![false; atoms.len()];
+            let mut partners = vec![None; atoms.len()];
+            let banned = if mates[sid] == Some(tid) {
+                Some(key(sid, tid))
+            } else {
+                partners[sid] = Some(tid);
+                partners[tid] = Some(sid);
+
+                None
+            };
+
+            for &id in &system {
+                open[id] = partners[id].is_none()
+            }
+
+            if !pair(atoms, &mates, &system, banned, &mut open, &mut partners) {
+                continue;
+            }
+
+            for &id in &system {
+                if partners[id] != mates[id] {
+                    for other in [partners[id], mates[id]].into_iter().flatten()
+                    {
+                        aromatic.insert(key(id, other));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns, for each atom, the neighbor it shares its only double bond
+/// with, when that neighbor's only double bond is the same.
+fn mates(atoms: &[Atom]) -> Vec<Option<usize>> {
+    let partner = |sid: usize| {
+        let atom = &atoms[sid];
+        let mut result = None;
+
+        if !is_selectable(atom) {
+            return None;
+        }
+
+        for bond in &atom.bonds {
+            match BondClass::new(atoms, sid, bond) {
+                BondClass::Double if result.is_none() => {
+                    result = Some(bond.tid)
+                }
+                BondClass::Double | BondClass::Triple => return None,
+                _ => (),
+            }
+        }
+
+        result
+    };
+
+    (0..atoms.len())
+        .map(|sid| partner(sid).filter(|&tid| partner(tid) == Some(sid)))
+        .collect()
+}
+
+fn is_pairable(
+    atoms: &[Atom],
+    mates: &[Option<usize>],
+    sid: usize,
+    tid: usize,
+) -> bool {
+    mates[sid].is_some()
+        && mates[tid].is_some()
+        && atoms[sid].bonds.iter().any(|bond| {
+            bond.tid == tid
+                && matches!(
+                    BondClass::new(atoms, sid, bond),
+                    BondClass::Single | BondClass::Double
+                )
+        })
+}
+
+/// Returns the atoms with a mate reachable from root over pairable bonds.
+fn system(atoms: &[Atom], mates: &[Option<usize>], root: usize) -> Vec<usize> {
+    let mut result = vec![root];
+    let mut stack = vec![root];
+
+    while let Some(sid) = stack.pop() {
+        for bond in &atoms[sid].bonds {
+            if !result.contains(&bond.tid)
+                && is_pairable(atoms, mates, sid, bond.tid)
+            {
+                result.push(bond.tid);
+                stack.push(bond.tid)
+            }
+        }
+    }
+
+    result
+}
+
+/// Pairs the open atom with the fewest candidates first, over pairable bonds
+/// other than banned, backtracking on failure.
+fn pair(
+    atoms: &[Atom],
+    mates: &[Option<usize>],
+    system: &[usize],
+    banned: Option<(usize, usize)>,
+    open: &mut [bool],
+    partners: &mut [Option<usize>],
+) -> bool {
+    let mut best: Option<Vec<usize>> = None;
+
+    for &sid in system {
+        if !open[sid] {
+            continue;
+        }
+
+        let candidates = atoms[sid]
+            .bonds
+            .iter()
+            .map(|bond| bond.tid)
+            .filter(|&tid| {
+                open[tid]
+                    && banned != Some(key(sid, tid))
+                    && is_pairable(atoms, mates, sid, tid)
+            })
+            .collect::<Vec<_>>();
+
+        if candidates.is_empty() {
+            return false;
+        }
+
+        match &best {
+            Some(best) if best.len() - 1 <= candidates.len() => (),
+            _ => best = Some([vec![sid], candidates].concat()),
+        }
+    }
+
+    let best = match best {
+        Some(best) => best,
+        None => return true,
+    };
+    let sid = best[0];
+
+    for &tid in &best[1..] {
+        partners[sid] = Some(tid);
+        partners[tid] = Some(sid);
+        open[sid] = false;
+        open[tid] = false;
+
+        if pair(atoms, mates, system, banned, open, partners) {
+            return true;
+        }
+
+        partners[sid] = None;
+        partners[tid] = None;
+        open[sid] = true;
+        open[tid] = true
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{follow::Writer, graph::walk, read::atoms_of};
+    use pretty_assertions::assert_eq;
+
+    fn write(string: &str) -> String {
+        let mut writer = Writer::new();
+
+        walk(&aromatize(&atoms_of(string)), &mut writer);
+
+        writer.write()
+    }
+
+    #[test]
+    fn aliphatic() {
+        assert_eq!(write("CC=O"), "CC=O")
+    }
+
+    #[test]
+    fn benzene() {
+        assert_eq!(write("C1=CC=CC=C1"), "c(ccccc1)1")
+    }
+
+    #[test]
+    fn pyrrole() {
+        assert_eq!(write("N1C=CC=C1"), "[nH1](cccc1)1")
+    }
+
+    #[test]
+    fn pyridone() {
+        assert_eq!(write("O=C1C=CC=CN1"), "O=c([nH1]cccc1)1")
+    }
+
+    #[test]
+    fn biphenyl() {
+        assert_eq!(write("C1=CC=CC=C1C1=CC=CC=C1"), "c(c(cccc1)-c(ccccc2)2)1")
+    }
+
+    #[test]
+    fn azulene() {
+        assert_eq!(write("C1=CC2=CC=CC=CC2=C1"), "c(cc(cccccc(c1)-2)-2)1")
+    }
+
+    #[test]
+    fn cyclohexadiene() {
+        assert_eq!(write("C1=CCC=CC1"), "C(CC=CCC=1)=1")
+    }
+
+    #[test]
+    fn methylenecyclopentadiene() {
+        assert_eq!(write("C=C1C=CC=C1"), "C=C(C=CC=C1)1")
+    }
+
+    #[test]
+    fn indole() {
+        assert_eq!(write("C1=CC=C2NC=CC2=C1"), write("c1ccc2[nH]ccc2c1"))
+    }
+}
@@ -0,0 +1,142 @@
+use super::{tautomer_score, tautomers, Tautomer, TautomerOptions};
+use crate::{
+    graph::Atom,
+    hash::{molecule_hash, HashLayer},
+};
+
+/// Returns the tautomer of atoms with the highest tautomer_score, breaking
+/// ties by the lowest molecule_hash at the ChargesAndIsotopes layer. Unless
+/// enumeration stops at max_tautomers, the choice doesn't depend on atom
+/// order.
+pub fn canonical_tautomer(
+    atoms: &[Atom],
+    options: &TautomerOptions,
+) -> Tautomer {
+    tautomers(atoms, options)
+        .into_iter()
+        .map(|tautomer| {
+            let score = tautomer_score(&tautomer.atoms);
+            let hash =
+                molecule_hash(&tautomer.atoms, HashLayer::ChargesAndIsotopes);
+
+            (score, hash, tautomer)
+        })
+        .min_by(|(score, hash, _), (other_score, other_hash, _)| {
+            other_score.cmp(score).then(hash.cmp(other_hash))
+        })
+        .map(|(_, _, tautomer)| tautomer)
+        .expect("tautomer")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        follow::Writer, graph::walk, read::atoms_of, search::is_isomorphic,
+        tautomer::LostStereo,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn canonical(string: &str) -> (String, Vec<LostStereo>) {
+        let tautomer =
+            canonical_tautomer(&atoms_of(string), &Default::default());
+        let mut writer = Writer::new();
+
+        walk(&tautomer.atoms, &mut writer);
+
+        (writer.write(), tautomer.lost_stereo)
+    }
+
+    #[test]
+    fn keto() {
+        assert_eq!(canonical("C=C(C)O"), ("CC(C)=O".to_string(), vec![]))
+    }
+
+    #[test]
+    fn lactam() {
+        assert_eq!(canonical("CC(O)=NC"), ("CC(=O)NC".to_string(), vec![]))
+    }
+
+    #[test]
+    fn imine() {
+        assert_eq!(canonical("C=CNC"), ("CC=NC".to_string(), vec![]))
+    }
+
+    #[test]
+    fn pyridone() {
+        assert_eq!(
+            canonical("O=C1C=CC=CN1"),
+            ("O=c([nH1]cccc1)1".to_string(), vec![])
+        )
+    }
+
+    fn same_canonical(first: &str, second: &str) -> bool {
+        let options = Default::default();
+
+        is_isomorphic(
+            &canonical_tautomer(&atoms_of(first), &options).atoms,
+            &canonical_tautomer(&atoms_of(second), &options).atoms,
+            false,
+        )
+    }
+
+    #[test]
+    fn order_independent() {
+        assert!(same_canonical("OC(C)=CC", "CC=C(O)C"))
+    }
+
+    #[test]
+    fn methylimidazole() {
+        assert!(same_canonical("Cc1c[nH]cn1", "Cc1cnc[nH]1"))
+    }
+
+    #[test]
+    fn hydroxypyridine() {
+        assert!(same_canonical("Oc1ccccn1", "O=c1cccc[nH]1"))
+    }
+
+    #[test]
+    fn kekule_indole() {
+        assert!(same_canonical("c1ccc2[nH]ccc2c1", "C1=CC=C2NC=CC2=C1"))
+    }
+
+    #[test]
+    fn guanine() {
+        assert!(same_canonical(
+            "Nc1nc2[nH]cnc2c(=O)[nH]1",
+            "NC1=NC2=C(N=CN2)C(=O)N1"
+        ))
+    }
+
+    #[test]
+    fn guanine_enol() {
+        assert!(same_canonical(
+            "Nc1nc2[nH]cnc2c(=O)[nH]1",
+            "N=C1NC2=C(N=CN2)C(O)=N1"
+        ))
+    }
+
+    #[test]
+    fn guanine_amino_oxo() {
+        assert_eq!(
+            canonical("N=C1NC2=C(N=CN2)C(O)=N1").0,
+            "Nc([nH1]c(c(c([n]1)[nH1]cn2)2)=O)1"
+        )
+    }
+
+    #[test]
+    fn keeps_stereo() {
+        assert_eq!(
+            canonical("C[C@H](F)C(C)=O"),
+            ("C[C@H1](F)C(C)=O".to_string(), vec![])
+        )
+    }
+
+    #[test]
+    fn keeps_distant_stereo() {
+        assert_eq!(
+            canonical("CC(F)=C(O)[C@H](F)Cl"),
+            ("CC(F)C(=O)[C@H1](F)Cl".to_string(), vec![])
+        )
+    }
+}
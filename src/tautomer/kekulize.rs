@@ -0,0 +1,89 @@
+use crate::{
+    feature::{AtomKind, BondKind, Selection, Shortcut, Symbol},
+    graph::Atom,
+    search::BondClass,
+    validate::{kekule_failures, kekule_partners},
+};
+
+/// Returns atoms with selected atoms and aromatic bonds replaced by a Kekulé
+/// structure, or atoms unchanged if a ring system can't be kekulized.
+pub fn kekulize(atoms: &[Atom]) -> Vec<Atom> {
+    if !kekule_failures(atoms).is_empty() {
+        return atoms.to_vec();
+    }
+
+    let partners = kekule_partners(atoms);
+    let mut result = atoms.to_vec();
+
+    for (sid, atom) in result.iter_mut().enumerate() {
+        for bond in atom.bonds.iter_mut() {
+            if BondClass::new(atoms, sid, bond) == BondClass::Aromatic {
+                bond.kind = if partners[sid] == Some(bond.tid) {
+                    BondKind::Double
+                } else {
+                    BondKind::Elided
+                }
+            }
+        }
+
+        match &mut atom.kind {
+            AtomKind::Selection(selection) => {
+                atom.kind = AtomKind::Shortcut(shortcut(selection))
+            }
+            AtomKind::Bracket(bracket) => {
+                if let Symbol::Selection(selection) = &bracket.symbol {
+                    bracket.symbol = Symbol::Element(selection.into())
+                }
+            }
+            _ => (),
+        }
+    }
+
+    result
+}
+
+fn shortcut(selection: &Selection) -> Shortcut {
+    match selection {
+        Selection::B => Shortcut::B,
+        Selection::C => Shortcut::C,
+        Selection::N => Shortcut::N,
+        Selection::O => Shortcut::O,
+        Selection::P => Shortcut::P,
+        Selection::S => Shortcut::S,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{follow::Writer, graph::walk, read::atoms_of};
+    use pretty_assertions::assert_eq;
+
+    fn write(string: &str) -> String {
+        let mut writer = Writer::new();
+
+        walk(&kekulize(&atoms_of(string)), &mut writer);
+
+        writer.write()
+    }
+
+    #[test]
+    fn aliphatic() {
+        assert_eq!(write("CC=O"), "CC=O")
+    }
+
+    #[test]
+    fn benzene() {
+        assert_eq!(write("c1ccccc1"), "C(=CC=CC=C1)1")
+    }
+
+    #[test]
+    fn pyrrole() {
+        assert_eq!(write("[nH]1cccc1"), "[NH1](C=CC=C1)1")
+    }
+
+    #[test]
+    fn failure() {
+        assert_eq!(write("n1cccc1"), "n(cccc1)1")
+    }
+}
@@ -0,0 +1,8 @@
+/// Stereo removed because a hydrogen shift changed the atoms it described.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum LostStereo {
+    /// The parity of the atom at an index.
+    Parity(usize),
+    /// The Up or Down marker on the bond between source and target.
+    Direction { source: usize, target: usize },
+}
@@ -0,0 +1,21 @@
+mod canonical_tautomer;
+mod aromatize;
+mod kekulize;
+mod lost_stereo;
+#[allow(clippy::module_inception)]
+mod tautomer;
+mod tautomer_options;
+mod tautomer_rule;
+mod tautomer_score;
+mod tautomers;
+
+pub use canonical_tautomer::canonical_tautomer;
+pub use lost_stereo::LostStereo;
+pub use tautomer::Tautomer;
+pub use tautomer_options::TautomerOptions;
+pub use tautomer_rule::TautomerRule;
+pub use tautomer_score::tautomer_score;
+pub use tautomers::tautomers;
+
+pub(crate) use aromatize::aromatize;
+pub(crate) use kekulize::kekulize;
@@ -0,0 +1,9 @@
+use super::LostStereo;
+use crate::graph::Atom;
+
+/// A tautomer, with the stereo lost on the way from the original molecule.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Tautomer {
+    pub atoms: Vec<Atom>,
+    pub lost_stereo: Vec<LostStereo>,
+}
@@ -0,0 +1,18 @@
+use super::TautomerRule;
+
+/// Controls tautomer enumeration.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TautomerOptions {
+    pub rules: Vec<TautomerRule>,
+    /// Stop after this many tautomers.
+    pub max_tautomers: usize,
+}
+
+impl Default for TautomerOptions {
+    fn default() -> Self {
+        Self {
+            rules: TautomerRule::all(),
+            max_tautomers: 1000,
+        }
+    }
+}
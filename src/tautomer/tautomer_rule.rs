@@ -0,0 +1,108 @@
+use crate::feature::Element;
+
+/// A hydrogen shift. Each rule moves a hydrogen from a donor to an acceptor
+/// across an alternating path of single and double bonds, and applies in
+/// both directions.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum TautomerRule {
+    /// 1,3 shift between carbon and oxygen through carbon:
+    /// `CC=O` to `C=CO`.
+    KetoEnol,
+    /// 1,3 shift between nitrogen and oxygen through carbon:
+    /// `NC=O` to `N=CO`.
+    LactamLactim,
+    /// 1,3 shift between carbon and nitrogen through carbon:
+    /// `CC=N` to `C=CN`.
+    ImineEnamine,
+    /// 1,3 shift between any two of nitrogen, oxygen and sulfur:
+    /// `NC=N` to `N=CN`.
+    Heteroatom13,
+    /// 1,5 shift between any two of nitrogen, oxygen and sulfur:
+    /// `NC=CC=O` to `N=CC=CO`.
+    Heteroatom15,
+}
+
+impl TautomerRule {
+    /// Returns every rule.
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::KetoEnol,
+            Self::LactamLactim,
+            Self::ImineEnamine,
+            Self::Heteroatom13,
+            Self::Heteroatom15,
+        ]
+    }
+
+    /// Returns the number of atoms on the path, donor and acceptor included.
+    pub fn length(&self) -> usize {
+        match self {
+            Self::Heteroatom15 => 5,
+            _ => 3,
+        }
+    }
+
+    /// Returns true if the rule moves a hydrogen between elements at the
+    /// ends of a path whose inner atoms are middle.
+    pub fn allows(
+        &self,
+        first: Element,
+        second: Element,
+        middle: &[Element],
+    ) -> bool {
+        let ends = |a: Element, b: Element| {
+            (first == a && second == b) || (first == b && second == a)
+        };
+        let hetero = |element: Element| {
+            matches!(element, Element::N | Element::O | Element::S)
+        };
+        let carbon = middle.iter().all(|element| *element == Element::C);
+
+        match self {
+            Self::KetoEnol => carbon && ends(Element::C, Element::O),
+            Self::LactamLactim => carbon && ends(Element::N, Element::O),
+            Self::ImineEnamine => carbon && ends(Element::C, Element::N),
+            Self::Heteroatom13 | Self::Heteroatom15 => {
+                hetero(first) && hetero(second)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keto_enol_both_ways() {
+        assert!(
+            TautomerRule::KetoEnol.allows(
+                Element::C,
+                Element::O,
+                &[Element::C]
+            ) && TautomerRule::KetoEnol.allows(
+                Element::O,
+                Element::C,
+                &[Element::C]
+            )
+        )
+    }
+
+    #[test]
+    fn keto_enol_through_nitrogen() {
+        assert!(!TautomerRule::KetoEnol.allows(
+            Element::C,
+            Element::O,
+            &[Element::N]
+        ))
+    }
+
+    #[test]
+    fn heteroatom13_carbon() {
+        assert!(!TautomerRule::Heteroatom13.allows(
+            Element::C,
+            Element::O,
+            &[Element::C]
+        ))
+    }
+}
@@ -0,0 +1,137 @@
+use super::aromatize;
+use crate::{
+    feature::Element,
+    graph::{components, ring_bonds, Atom},
+    search::BondClass,
+};
+
+/// Scores atoms as a tautomer, higher being preferred. Atoms are scored in
+/// the form given by aromatize. Each aromatic ring adds 4. Each double bond
+/// from carbon, nitrogen, phosphorus or sulfur to oxygen adds 2. Each double
+/// bond from carbon to nitrogen or sulfur adds 1. Each double bond from a
+/// ring carbon to carbon or nitrogen outside the ring subtracts 2. Each
+/// hydrogen on phosphorus, sulfur, selenium or tellurium subtracts 1.
+pub fn tautomer_score(atoms: &[Atom]) -> i32 {
+    let atoms = &aromatize(atoms);
+    let ring = ring_bonds(atoms);
+    let (bonds, parts) = components(atoms, |sid, bond| {
+        BondClass::new(atoms, sid, bond) == BondClass::Aromatic
+    });
+    let mut result = 4 * (bonds + parts - atoms.len()) as i32;
+
+    for (sid, atom) in atoms.iter().enumerate() {
+        let element = atom.kind.element();
+
+        for (bond, &in_ring) in atom.bonds.iter().zip(&ring[sid]) {
+            if bond.tid < sid
+                || BondClass::new(atoms, sid, bond) != BondClass::Double
+            {
+                continue;
+            }
+
+            let other = atoms[bond.tid].kind.element();
+
+            result += match (element.clone(), other.clone()) {
+                (
+                    Some(Element::C | Element::N | Element::P | Element::S),
+                    Some(Element::O),
+                )
+                | (
+                    Some(Element::O),
+                    Some(Element::C | Element::N | Element::P | Element::S),
+                ) => 2,
+                (Some(Element::C), Some(Element::N | Element::S))
+                | (Some(Element::N | Element::S), Some(Element::C)) => 1,
+                _ => 0,
+            };
+
+            if !in_ring
+                && (is_ring_carbon(atoms, &ring, sid)
+                    && matches!(other, Some(Element::C | Element::N))
+                    || is_ring_carbon(atoms, &ring, bond.tid)
+                        && matches!(element, Some(Element::C | Element::N)))
+            {
+                result -= 2
+            }
+        }
+
+        if matches!(
+            element,
+            Some(Element::P | Element::S | Element::Se | Element::Te)
+        ) {
+            result -= atom.hydrogens() as i32
+        }
+    }
+
+    result
+}
+
+fn is_ring_carbon(atoms: &[Atom], ring: &[Vec<bool>], id: usize) -> bool {
+    atoms[id].kind.element() == Some(Element::C) && ring[id].contains(&true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn score(string: &str) -> i32 {
+        tautomer_score(&atoms_of(string))
+    }
+
+    #[test]
+    fn keto() {
+        assert_eq!(score("CC(C)=O"), 2)
+    }
+
+    #[test]
+    fn enol() {
+        assert_eq!(score("C=C(C)O"), 0)
+    }
+
+    #[test]
+    fn imine() {
+        assert_eq!(score("CC=NC"), 1)
+    }
+
+    #[test]
+    fn thiol() {
+        assert_eq!(score("CC(S)=C"), -1)
+    }
+
+    #[test]
+    fn pyridone() {
+        assert_eq!(score("O=C1C=CC=CN1"), 6)
+    }
+
+    #[test]
+    fn hydroxypyridine() {
+        assert_eq!(score("Oc1ccccn1"), 4)
+    }
+
+    #[test]
+    fn broken_ring() {
+        assert_eq!(score("O=C1N=CCC=C1"), 3)
+    }
+
+    #[test]
+    fn naphthalene() {
+        assert_eq!(score("C1=CC=C2C=CC=CC2=C1"), 8)
+    }
+
+    #[test]
+    fn exocyclic_methylene() {
+        assert_eq!(score("C=C1CCCCC1"), -2)
+    }
+
+    #[test]
+    fn exocyclic_imine() {
+        assert_eq!(score("N=C1NC=CC=C1"), 3)
+    }
+
+    #[test]
+    fn saturated_ring() {
+        assert_eq!(score("O=C1CCCCC1"), 2)
+    }
+}
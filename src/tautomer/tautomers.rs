@@ -0,0 +1,372 @@
+use std::collections::HashSet;
+
+use super::{aromatize, kekulize, LostStereo, Tautomer, TautomerOptions};
+use crate::{
+    feature::{AtomKind, BondKind, Element, VirtualHydrogen},
+    graph::Atom,
+    search::MoleculeEq,
+    stereo::{clear_directions, is_directional},
+};
+
+/// Returns the tautomers of atoms reachable through the rules of options,
+/// starting with atoms themselves, in breadth-first order and without
+/// duplicates. Hydrogens move by changing the bond orders around Shortcut
+/// atoms and the hydrogen count of Bracket atoms.
+///
+/// Parity on any atom of a shifted path is removed, as are Up and Down
+/// markers on a bond that becomes double or that flank a double bond that
+/// becomes single. Each tautomer lists what it lost relative to atoms.
+///
+/// Every tautomer, the first included, is written in the form given by
+/// aromatize, so forms differing only in where their double bonds fall
+/// come out alike and count as one. Charged atoms take no part in shifts.
+pub fn tautomers(atoms: &[Atom], options: &TautomerOptions) -> Vec<Tautomer> {
+    let first = aromatize(atoms);
+    let mut seen = HashSet::from([MoleculeEq::without_stereo(first.clone())]);
+    let mut result = vec![Tautomer {
+        atoms: first,
+        lost_stereo: Vec::new(),
+    }];
+    let mut next = 0;
+
+    while next < result.len() && result.len() < options.max_tautomers {
+        let current = Tautomer {
+            atoms: kekulize(&result[next].atoms),
+            lost_stereo: result[next].lost_stereo.clone(),
+        };
+
+        next += 1;
+
+        for path in paths(&current.atoms, options) {
+            if result.len() >= options.max_tautomers {
+                break;
+            }
+
+            let mut tautomer = shift(&current, &path);
+
+            tautomer.atoms = aromatize(&tautomer.atoms);
+
+            if seen.insert(MoleculeEq::without_stereo(tautomer.atoms.clone())) {
+                result.push(tautomer)
+            }
+        }
+    }
+
+    result
+}
+
+/// Returns the element of an atom that can take part in a shift.
+fn element(atom: &Atom) -> Option<Element> {
+    if atom.kind.is_selected() {
+        return None;
+    }
+
+    match &atom.kind {
+        AtomKind::Bracket(bracket) if bracket.charge() != 0 => None,
+        kind => kind.element(),
+    }
+}
+
+/// Returns paths from a donor with a hydrogen and only single bonds to an
+/// acceptor over bonds alternating single and double, as allowed by a rule.
+fn paths(atoms: &[Atom], options: &TautomerOptions) -> Vec<Vec<usize>> {
+    let mut result = Vec::new();
+    let longest = options
+        .rules
+        .iter()
+        .map(|rule| rule.length())
+        .max()
+        .unwrap_or(0);
+
+    for (donor, atom) in atoms.iter().enumerate() {
+        if atom.hydrogens() == 0
+            || element(atom).is_none()
+            || atom.bonds.iter().any(|bond| bond.kind.bond_order() > 1)
+        {
+            continue;
+        }
+
+        let mut stack = vec![vec![donor]];
+
+        while let Some(path) = stack.pop() {
+            let last = *path.last().expect("path");
+            let order = if path.len() % 2 == 1 { 1 } else { 2 };
+
+            if path.len() > 1 && path.len() % 2 == 1 {
+                let elements = path
+                    .iter()
+                    .map(|id| element(&atoms[*id]).expect("element"))
+                    .collect::<Vec<_>>();
+                let middle = &elements[1..elements.len() - 1];
+                let allowed = options.rules.iter().any(|rule| {
+                    rule.length() == path.len()
+                        && rule.allows(
+                            elements[0].clone(),
+                            elements[elements.len() - 1].clone(),
+                            middle,
+                        )
+                });
+
+                if allowed {
+                    result.push(path.clone())
+                }
+            }
+
+            if path.len() == longest {
+                continue;
+            }
+
+            for bond in atoms[last].bonds.iter().rev() {
+                if bond.kind.bond_order() == order
+                    && !path.contains(&bond.tid)
+                    && element(&atoms[bond.tid]).is_some()
+                {
+                    let mut next = path.clone();
+
+                    next.push(bond.tid);
+                    stack.push(next)
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn shift(tautomer: &Tautomer, path: &[usize]) -> Tautomer {
+    let mut atoms = tautomer.atoms.clone();
+    let mut lost = tautomer.lost_stereo.clone();
+
+    for (i, pair) in path.windows(2).enumerate() {
+        let (first, second) = (pair[0], pair[1]);
+
+        if i % 2 == 0 {
+            if let Some(index) = index(&atoms, first, second) {
+                if is_directional(&atoms, first, index) {
+                    report(&mut lost, first, second)
+                }
+            }
+
+            set_kind(&mut atoms, first, second, BondKind::Double)
+        } else {
+            set_kind(&mut atoms, first, second, BondKind::Elided);
+
+            for (end, other) in [(first, second), (second, first)] {
+                for tid in clear_directions(&mut atoms, end, other) {
+                    report(&mut lost, end, tid)
+                }
+            }
+        }
+    }
+
+    add_hydrogens(&mut atoms[path[0]], -1);
+    add_hydrogens(&mut atoms[path[path.len() - 1]], 1);
+
+    for id in path {
+        if let AtomKind::Bracket(bracket) = &mut atoms[*id].kind {
+            if bracket.parity.take().is_some() {
+                let parity = LostStereo::Parity(*id);
+
+                if !lost.contains(&parity) {
+                    lost.push(parity)
+                }
+            }
+        }
+    }
+
+    Tautomer {
+        atoms,
+        lost_stereo: lost,
+    }
+}
+
+fn index(atoms: &[Atom], sid: usize, tid: usize) -> Option<usize> {
+    atoms[sid].bonds.iter().position(|bond| bond.tid == tid)
+}
+
+fn set_kind(atoms: &mut [Atom], first: usize, second: usize, kind: BondKind) {
+    for (sid, tid) in [(first, second), (second, first)] {
+        if let Some(index) = index(atoms, sid, tid) {
+            atoms[sid].bonds[index].kind = kind.clone()
+        }
+    }
+}
+
+fn report(lost: &mut Vec<LostStereo>, first: usize, second: usize) {
+    let direction = LostStereo::Direction {
+        source: first.min(second),
+        target: first.max(second),
+    };
+
+    if !lost.contains(&direction) {
+        lost.push(direction)
+    }
+}
+
+/// Adjusts the hydrogen count of a Bracket atom. Shortcut atoms follow
+/// their bond orders.
+fn add_hydrogens(atom: &mut Atom, delta: i8) {
+    if let AtomKind::Bracket(bracket) = &mut atom.kind {
+        let count = (bracket.hydrogens() as i8 + delta).max(0) as u8;
+
+        bracket.hydrogens = VirtualHydrogen::new(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        follow::Writer, graph::walk, read::atoms_of, tautomer::TautomerRule,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn enumerate(
+        string: &str,
+        options: &TautomerOptions,
+    ) -> Vec<(String, Vec<LostStereo>)> {
+        tautomers(&atoms_of(string), options)
+            .into_iter()
+            .map(|tautomer| {
+                let mut writer = Writer::new();
+
+                walk(&tautomer.atoms, &mut writer);
+
+                (writer.write(), tautomer.lost_stereo)
+            })
+            .collect()
+    }
+
+    fn strings(string: &str, options: &TautomerOptions) -> Vec<String> {
+        enumerate(string, options)
+            .into_iter()
+            .map(|(string, _)| string)
+            .collect()
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(strings("CCO", &Default::default()), vec!["CCO"])
+    }
+
+    #[test]
+    fn keto_enol() {
+        assert_eq!(
+            strings("CC(C)=O", &Default::default()),
+            vec!["CC(C)=O", "C=C(C)O"]
+        )
+    }
+
+    #[test]
+    fn enol_keto() {
+        assert_eq!(
+            strings("C=C(C)O", &Default::default()),
+            vec!["C=C(C)O", "CC(C)=O"]
+        )
+    }
+
+    #[test]
+    fn lactam_lactim() {
+        assert_eq!(
+            strings("CC(=O)NC", &Default::default()),
+            vec!["CC(=O)NC", "C=C(O)NC", "CC(O)=NC"]
+        )
+    }
+
+    #[test]
+    fn imine_enamine() {
+        assert_eq!(
+            strings("CC=NC", &Default::default()),
+            vec!["CC=NC", "C=CNC"]
+        )
+    }
+
+    #[test]
+    fn heteroatom15() {
+        let options = TautomerOptions {
+            rules: vec![TautomerRule::Heteroatom15],
+            ..Default::default()
+        };
+
+        assert_eq!(strings("NC=CC=O", &options), vec!["NC=CC=O", "N=CC=CO"])
+    }
+
+    #[test]
+    fn rules() {
+        let options = TautomerOptions {
+            rules: vec![TautomerRule::LactamLactim],
+            ..Default::default()
+        };
+
+        assert_eq!(strings("CC(=O)NC", &options), vec!["CC(=O)NC", "CC(O)=NC"])
+    }
+
+    #[test]
+    fn bracket_hydrogens() {
+        assert_eq!(
+            strings("[CH3]C=O", &Default::default()),
+            vec!["[CH3]C=O", "[CH2]=CO"]
+        )
+    }
+
+    #[test]
+    fn aromatic() {
+        assert_eq!(
+            strings("Oc1ccccn1", &Default::default()),
+            vec!["Oc(ncccc1)1", "O=c([nH1]cccc1)1", "O=C([N]=CCC=C1)1"]
+        )
+    }
+
+    #[test]
+    fn charged() {
+        assert_eq!(
+            strings("C[CH2+]C=O", &Default::default()),
+            vec!["C[CH2+]C=O"]
+        )
+    }
+
+    #[test]
+    fn lost_parity() {
+        assert_eq!(
+            enumerate("C[C@H](F)C(C)=O", &Default::default()),
+            vec![
+                ("C[C@H1](F)C(C)=O".to_string(), vec![]),
+                ("C[C](F)=C(C)O".to_string(), vec![LostStereo::Parity(1)]),
+                ("C[C@H1](F)C(=C)O".to_string(), vec![])
+            ]
+        )
+    }
+
+    #[test]
+    fn lost_direction() {
+        assert_eq!(
+            enumerate("C/C=C/NC", &Default::default()),
+            vec![
+                ("C/C=C/NC".to_string(), vec![]),
+                (
+                    "CCC=NC".to_string(),
+                    vec![
+                        LostStereo::Direction {
+                            source: 2,
+                            target: 3
+                        },
+                        LostStereo::Direction {
+                            source: 0,
+                            target: 1
+                        }
+                    ]
+                )
+            ]
+        )
+    }
+
+    #[test]
+    fn cap() {
+        let options = TautomerOptions {
+            max_tautomers: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(strings("CC(C)=O", &options), vec!["CC(C)=O"])
+    }
+}
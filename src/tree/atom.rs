@@ -1,4 +1,7 @@
-use crate::feature::{AtomKind, BondKind, Bracket, Selection, Shortcut};
+use crate::feature::{
+    AtomKind, BondKind, Bracket, Selection, Shortcut, ValenceError,
+    ValenceModel,
+};
 
 use super::Edge;
 
@@ -60,6 +63,14 @@ impl Atom {
         self.kind.subvalence(self.valence(input))
     }
 
+    pub fn checked_subvalence(
+        &self,
+        input: Option<&BondKind>,
+        model: &impl ValenceModel,
+    ) -> Result<u8, ValenceError> {
+        self.kind.checked_subvalence(self.valence(input), model)
+    }
+
     pub fn implicit_hydrogens(&self, input: Option<&BondKind>) -> u8 {
         match &self.kind {
             AtomKind::Star => 0,
@@ -194,6 +205,47 @@ mod subvalence {
     }
 }
 
+#[cfg(test)]
+mod checked_subvalence {
+    use crate::feature::{Charge, DefaultValenceModel, Element, Symbol};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn oxide() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::O),
+                charge: Some(Charge::Minus),
+                ..Default::default()
+            },
+            vec![],
+        );
+        let input = Some(&BondKind::Single);
+
+        assert_eq!(atom.checked_subvalence(input, &DefaultValenceModel), Ok(0))
+    }
+
+    #[test]
+    fn oxide_supervalent() {
+        let atom = Atom::bracket(
+            Bracket {
+                symbol: Symbol::Element(Element::O),
+                charge: Some(Charge::Minus),
+                ..Default::default()
+            },
+            vec![],
+        );
+        let input = Some(&BondKind::Double);
+
+        assert_eq!(
+            atom.checked_subvalence(input, &DefaultValenceModel),
+            Err(ValenceError::Supervalent)
+        )
+    }
+}
+
 #[cfg(test)]
 mod implicit_hydrogens {
     use crate::feature;
@@ -65,6 +65,12 @@ impl Builder {
     }
 }
 
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Follower for Builder {
     fn root(&mut self, kind: &AtomKind) {
         if self.root.is_none() {
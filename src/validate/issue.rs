@@ -0,0 +1,86 @@
+use super::Severity;
+use crate::{feature::ValenceError, stereo::DirectionIssue};
+
+/// A problem found by validate.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Issue {
+    /// The atom at index exceeds every allowed valence, or its charge
+    /// leaves it none.
+    Valence { index: usize, error: ValenceError },
+    /// The selected atoms of a ring system can't all be given a double
+    /// bond.
+    Kekulization(Vec<usize>),
+    /// The isotope of the atom at index isn't known for its element.
+    UnknownIsotope(usize),
+    /// The atom at index has parity but can't be a tetrahedral center.
+    InvalidParity(usize),
+    /// Up and Down markers that conflict or flank no double bond.
+    Direction(DirectionIssue),
+    /// The bond from source to target has no counterpart from target to
+    /// source, points to the atom itself or past the last atom, or repeats
+    /// another bond between the same atoms.
+    UnmatchedBond { source: usize, target: usize },
+    /// The atom at index opens a bridge that is never closed.
+    UnmatchedBridge(usize),
+    /// The charge of the atom at index is outside the range plausible for
+    /// its element.
+    ImplausibleCharge(usize),
+}
+
+impl Issue {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::Valence { .. }
+            | Self::Kekulization(_)
+            | Self::UnknownIsotope(_)
+            | Self::UnmatchedBond { .. }
+            | Self::UnmatchedBridge(_) => Severity::Error,
+            Self::Direction(DirectionIssue::Conflict { .. }) => Severity::Error,
+            Self::InvalidParity(_)
+            | Self::Direction(DirectionIssue::Meaningless { .. })
+            | Self::ImplausibleCharge(_) => Severity::Warning,
+        }
+    }
+
+    /// Returns the indexes of the atoms involved, in ascending order.
+    pub fn atoms(&self) -> Vec<usize> {
+        match self {
+            Self::Valence { index, .. }
+            | Self::UnknownIsotope(index)
+            | Self::InvalidParity(index)
+            | Self::UnmatchedBridge(index)
+            | Self::ImplausibleCharge(index) => vec![*index],
+            Self::Kekulization(atoms) => atoms.clone(),
+            Self::Direction(
+                DirectionIssue::Conflict { source, target }
+                | DirectionIssue::Meaningless { source, target },
+            )
+            | Self::UnmatchedBond { source, target } => {
+                vec![*source.min(target), *source.max(target)]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn severity() {
+        assert_eq!(Issue::InvalidParity(0).severity(), Severity::Warning)
+    }
+
+    #[test]
+    fn atoms() {
+        assert_eq!(
+            Issue::UnmatchedBond {
+                source: 3,
+                target: 1
+            }
+            .atoms(),
+            vec![1, 3]
+        )
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{graph::Atom, search::BondClass};
+
+/// Returns the selected atoms, grouped by ring system, of each system whose
+/// selected atoms with subvalence can't all be paired over aromatic bonds.
+pub fn kekule_failures(atoms: &[Atom]) -> Vec<Vec<usize>> {
+    pair_systems(atoms).1
+}
+
+/// Returns, for each atom, the neighbor it shares a double bond with in a
+/// Kekulé structure of its ring system. Atoms of systems listed by
+/// kekule_failures have none.
+pub fn kekule_partners(atoms: &[Atom]) -> Vec<Option<usize>> {
+    pair_systems(atoms).0
+}
+
+/// Pairs each system of selected atoms, returning the partners of systems
+/// that could be paired and the atoms of those that couldn't.
+fn pair_systems(atoms: &[Atom]) -> (Vec<Option<usize>>, Vec<Vec<usize>>) {
+    let needs = atoms
+        .iter()
+        .map(|atom| atom.kind.is_selected() && atom.subvalence() > 0)
+        .collect::<Vec<_>>();
+    let mut visited = vec![false; atoms.len()];
+    let mut result = vec![None; atoms.len()];
+    let mut failures = Vec::new();
+
+    for root in 0..atoms.len() {
+        if visited[root] || !atoms[root].kind.is_selected() {
+            continue;
+        }
+
+        let system = system(atoms, root, &mut visited);
+        let mut partners = vec![None; atoms.len()];
+
+        if pair(atoms, &system, &needs, &mut partners) {
+            for &id in &system {
+                result[id] = partners[id]
+            }
+        } else {
+            failures.push(system)
+        }
+    }
+
+    (result, failures)
+}
+
+/// Returns the selected atoms reachable from root over aromatic bonds, in
+/// ascending order.
+fn system(atoms: &[Atom], root: usize, visited: &mut [bool]) -> Vec<usize> {
+    let mut result = Vec::new();
+    let mut stack = vec![root];
+
+    visited[root] = true;
+
+    while let Some(sid) = stack.pop() {
+        result.push(sid);
+
+        for bond in &atoms[sid].bonds {
+            if !visited[bond.tid]
+                && BondClass::new(atoms, sid, bond) == BondClass::Aromatic
+            {
+                visited[bond.tid] = true;
+                stack.push(bond.tid)
+            }
+        }
+    }
+
+    result.sort_unstable();
+
+    result
+}
+
+/// Pairs the unpaired atom in need with the fewest candidates first,
+/// backtracking on failure.
+fn pair(
+    atoms: &[Atom],
+    system: &[usize],
+    needs: &[bool],
+    partners: &mut [Option<usize>],
+) -> bool {
+    let mut best: Option<Vec<usize>> = None;
+
+    for &sid in system {
+        if !needs[sid] || partners[sid].is_some() {
+            continue;
+        }
+
+        let candidates = atoms[sid]
+            .bonds
+            .iter()
+            .filter(|bond| {
+                needs[bond.tid]
+                    && partners[bond.tid].is_none()
+                    && BondClass::new(atoms, sid, bond) == BondClass::Aromatic
+            })
+            .map(|bond| bond.tid)
+            .collect::<Vec<_>>();
+
+        if candidates.is_empty() {
+            return false;
+        }
+
+        match &best {
+            Some(best) if best.len() - 1 <= candidates.len() => (),
+            _ => best = Some([vec![sid], candidates].concat()),
+        }
+    }
+
+    let best = match best {
+        Some(best) => best,
+        None => return true,
+    };
+    let sid = best[0];
+
+    for &tid in &best[1..] {
+        partners[sid] = Some(tid);
+        partners[tid] = Some(sid);
+
+        if pair(atoms, system, needs, partners) {
+            return true;
+        }
+
+        partners[sid] = None;
+        partners[tid] = None
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::atoms_of;
+    use pretty_assertions::assert_eq;
+
+    fn failures(string: &str) -> Vec<Vec<usize>> {
+        kekule_failures(&atoms_of(string))
+    }
+
+    #[test]
+    fn benzene() {
+        assert_eq!(failures("c1ccccc1"), Vec::<Vec<usize>>::new())
+    }
+
+    #[test]
+    fn naphthalene() {
+        assert_eq!(failures("c1ccc2ccccc2c1"), Vec::<Vec<usize>>::new())
+    }
+
+    #[test]
+    fn pyrrole() {
+        assert_eq!(failures("[nH]1cccc1"), Vec::<Vec<usize>>::new())
+    }
+
+    #[test]
+    fn pyrrole_without_hydrogen() {
+        assert_eq!(failures("n1cccc1"), vec![vec![0, 1, 2, 3, 4]])
+    }
+
+    #[test]
+    fn pyridone() {
+        assert_eq!(failures("O=c1cccc[nH]1"), Vec::<Vec<usize>>::new())
+    }
+
+    #[test]
+    fn two_systems() {
+        assert_eq!(failures("c1ccccc1.c1cccc1"), vec![vec![6, 7, 8, 9, 10]])
+    }
+
+    #[test]
+    fn biphenyl() {
+        assert_eq!(failures("c1ccccc1-c1ccccc1"), Vec::<Vec<usize>>::new())
+    }
+
+    #[test]
+    fn partners() {
+        assert_eq!(
+            kekule_partners(&atoms_of("Oc1ccccn1")),
+            vec![None, Some(6), Some(3), Some(2), Some(5), Some(4), Some(1)]
+        )
+    }
+
+    #[test]
+    fn partners_of_failure() {
+        assert_eq!(kekule_partners(&atoms_of("n1cccc1")), vec![None; 5])
+    }
+}
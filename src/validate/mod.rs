@@ -0,0 +1,13 @@
+mod issue;
+mod kekule_failures;
+mod severity;
+#[allow(clippy::module_inception)]
+mod validate;
+mod validate_builder;
+
+pub use issue::Issue;
+pub use severity::Severity;
+pub use validate::validate;
+pub use validate_builder::validate_builder;
+
+pub(crate) use kekule_failures::{kekule_failures, kekule_partners};
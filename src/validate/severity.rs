@@ -0,0 +1,8 @@
+/// How seriously to take an Issue.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Severity {
+    /// The molecule is valid but likely not what was meant.
+    Warning,
+    /// The molecule can't be interpreted as written.
+    Error,
+}
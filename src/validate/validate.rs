@@ -0,0 +1,252 @@
+use std::ops::RangeInclusive;
+
+use super::{kekule_failures, Issue};
+use crate::{
+    feature::{AtomKind, DefaultValenceModel, Element},
+    graph::Atom,
+    stereo::{direction_issues, potential_centers},
+};
+
+/// Returns the problems with atoms, grouped by check in this order:
+///
+/// - bonds without a counterpart, which make the other checks unreliable
+/// - valences exceeding those allowed by DefaultValenceModel
+/// - ring systems of selected atoms that can't be kekulized
+/// - isotopes not known for their element
+/// - parity on atoms that can't be tetrahedral centers
+/// - conflicting or meaningless Up and Down markers
+/// - implausible charges
+///
+/// Within a check, issues follow atom order. Other checks are skipped when
+/// bonds are unmatched. Bridges left open in a string never reach atoms;
+/// see validate_builder.
+pub fn validate(atoms: &[Atom]) -> Vec<Issue> {
+    let mut result = unmatched_bonds(atoms);
+
+    if !result.is_empty() {
+        return result;
+    }
+
+    for (index, atom) in atoms.iter().enumerate() {
+        if let Err(error) = atom.checked_subvalence(&DefaultValenceModel) {
+            result.push(Issue::Valence { index, error })
+        }
+    }
+
+    for system in kekule_failures(atoms) {
+        result.push(Issue::Kekulization(system))
+    }
+
+    for (index, atom) in atoms.iter().enumerate() {
+        if let AtomKind::Bracket(bracket) = &atom.kind {
+            let isotope = match &bracket.isotope {
+                Some(isotope) => u16::from(isotope),
+                None => continue,
+            };
+
+            match atom.kind.element() {
+                Some(element) if element.isotope(isotope).is_none() => {
+                    result.push(Issue::UnknownIsotope(index))
+                }
+                _ => (),
+            }
+        }
+    }
+
+    let potential = potential_centers(atoms);
+
+    for (index, atom) in atoms.iter().enumerate() {
+        if let AtomKind::Bracket(bracket) = &atom.kind {
+            if bracket.parity.is_some() && !potential[index] {
+                result.push(Issue::InvalidParity(index))
+            }
+        }
+    }
+
+    for issue in direction_issues(atoms) {
+        result.push(Issue::Direction(issue))
+    }
+
+    for (index, atom) in atoms.iter().enumerate() {
+        if let (AtomKind::Bracket(bracket), Some(element)) =
+            (&atom.kind, atom.kind.element())
+        {
+            if !plausible_charges(&element).contains(&bracket.charge()) {
+                result.push(Issue::ImplausibleCharge(index))
+            }
+        }
+    }
+
+    result
+}
+
+fn unmatched_bonds(atoms: &[Atom]) -> Vec<Issue> {
+    let mut result = Vec::new();
+
+    for (source, atom) in atoms.iter().enumerate() {
+        for (index, bond) in atom.bonds.iter().enumerate() {
+            let target = bond.tid;
+
+            if atom.bonds[..index].iter().any(|other| other.tid == target) {
+                continue;
+            }
+
+            let matched = target != source
+                && target < atoms.len()
+                && count(atom, target) == 1
+                && count(&atoms[target], source) == 1;
+
+            if !matched {
+                result.push(Issue::UnmatchedBond { source, target })
+            }
+        }
+    }
+
+    result
+}
+
+fn count(atom: &Atom, tid: usize) -> usize {
+    atom.bonds.iter().filter(|bond| bond.tid == tid).count()
+}
+
+/// Charges plausible for element. Main group elements with up to three
+/// valence electrons range from -1 to the count of valence electrons.
+/// Those with more range from the charge completing the octet, capped at
+/// -3, to +1. Others range from -2 to +8.
+fn plausible_charges(element: &Element) -> RangeInclusive<i8> {
+    match element.valence_electrons() {
+        Some(electrons @ 0..=3) => -1..=electrons as i8,
+        Some(electrons) => -(8 - electrons as i8).min(3)..=1,
+        None => -2..=8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        feature::ValenceError, graph::Bond, read::atoms_of,
+        stereo::DirectionIssue,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn issues(string: &str) -> Vec<Issue> {
+        validate(&atoms_of(string))
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(issues("N[C@@H](C)C(=O)O"), vec![])
+    }
+
+    #[test]
+    fn valid_pseudoasymmetric() {
+        assert_eq!(issues("C[C@@H](O)[C@H](O)[C@@H](O)C"), vec![])
+    }
+
+    #[test]
+    fn valid_aromatic() {
+        assert_eq!(issues("c1ccc2[nH]ccc2c1"), vec![])
+    }
+
+    #[test]
+    fn supervalent() {
+        assert_eq!(
+            issues("CC(C)(C)(C)C"),
+            vec![Issue::Valence {
+                index: 1,
+                error: ValenceError::Supervalent
+            }]
+        )
+    }
+
+    #[test]
+    fn impossible_charge() {
+        assert_eq!(
+            issues("[H+2]"),
+            vec![
+                Issue::Valence {
+                    index: 0,
+                    error: ValenceError::ImpossibleCharge
+                },
+                Issue::ImplausibleCharge(0)
+            ]
+        )
+    }
+
+    #[test]
+    fn kekulization() {
+        assert_eq!(
+            issues("Cn1cccc1.n1cccc1"),
+            vec![Issue::Kekulization(vec![6, 7, 8, 9, 10])]
+        )
+    }
+
+    #[test]
+    fn unknown_isotope() {
+        assert_eq!(issues("[13C]C[99C]"), vec![Issue::UnknownIsotope(2)])
+    }
+
+    #[test]
+    fn invalid_parity() {
+        assert_eq!(issues("C[C@H](C)O"), vec![Issue::InvalidParity(1)])
+    }
+
+    #[test]
+    fn meaningless_direction() {
+        assert_eq!(
+            issues("C/CC"),
+            vec![Issue::Direction(DirectionIssue::Meaningless {
+                source: 0,
+                target: 1
+            })]
+        )
+    }
+
+    #[test]
+    fn implausible_charge() {
+        assert_eq!(issues("C[O+2]C"), vec![Issue::ImplausibleCharge(1)])
+    }
+
+    #[test]
+    fn plausible_charges() {
+        assert_eq!(issues("C[N+](C)(C)C.[Cl-].[Ca+2].[O-2]"), vec![])
+    }
+
+    #[test]
+    fn plausible_metal_charge() {
+        assert_eq!(issues("[Fe+3]"), vec![])
+    }
+
+    #[test]
+    fn unmatched_bond() {
+        let mut atoms = atoms_of("CCC");
+
+        atoms[2].bonds.push(Bond::elided(0));
+
+        assert_eq!(
+            validate(&atoms),
+            vec![Issue::UnmatchedBond {
+                source: 2,
+                target: 0
+            }]
+        )
+    }
+
+    #[test]
+    fn duplicate_bond() {
+        assert_eq!(
+            issues("C1C1"),
+            vec![
+                Issue::UnmatchedBond {
+                    source: 0,
+                    target: 1
+                },
+                Issue::UnmatchedBond {
+                    source: 1,
+                    target: 0
+                }
+            ]
+        )
+    }
+}
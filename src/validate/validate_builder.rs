@@ -0,0 +1,53 @@
+use super::{validate, Issue};
+use crate::graph::{Atom, Builder};
+
+/// Builds atoms from builder and returns them with their problems: bridges
+/// that were never closed, then the issues of validate.
+pub fn validate_builder(builder: Builder) -> (Vec<Atom>, Vec<Issue>) {
+    let mut issues = builder
+        .unmatched_bridges()
+        .into_iter()
+        .map(Issue::UnmatchedBridge)
+        .collect::<Vec<_>>();
+    let atoms = builder.build();
+
+    issues.append(&mut validate(&atoms));
+
+    (atoms, issues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::read;
+    use pretty_assertions::assert_eq;
+
+    fn issues(string: &str) -> Vec<Issue> {
+        let mut builder = Builder::new();
+
+        read(string, &mut builder).unwrap();
+
+        validate_builder(builder).1
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(issues("C1CC1"), vec![])
+    }
+
+    #[test]
+    fn unmatched_bridges() {
+        assert_eq!(
+            issues("C1CC2CC1C3"),
+            vec![Issue::UnmatchedBridge(2), Issue::UnmatchedBridge(5)]
+        )
+    }
+
+    #[test]
+    fn unmatched_bridge_and_invalid_parity() {
+        assert_eq!(
+            issues("C1C[C@H](C)C"),
+            vec![Issue::UnmatchedBridge(0), Issue::InvalidParity(2)]
+        )
+    }
+}